@@ -0,0 +1,648 @@
+//! Describe widgets to assistive technologies, like screen readers.
+use crate::mouse;
+use crate::widget::operation::{Focusable, Operation, Outcome, Scrollable};
+use crate::widget::Id;
+use crate::{Event, Point, Rectangle, Vector};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The role of an accessible widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A group of widgets.
+    Group,
+    /// A button that can be pressed.
+    Button,
+    /// A box that can be checked.
+    CheckBox,
+    /// A switch that can be toggled.
+    Switch,
+    /// A button in a group of exclusive options.
+    RadioButton,
+    /// A control to pick a number in a range.
+    Slider,
+    /// A single-line text input.
+    TextInput,
+    /// A multi-line text input.
+    MultilineTextInput,
+    /// A list of options to pick from.
+    ComboBox,
+    /// A viewport of content that can be scrolled.
+    ScrollView,
+    /// Some static text.
+    Label,
+    /// A picture.
+    Image,
+    /// An indicator of the progress of some task.
+    ProgressIndicator,
+    /// An entry of a menu that can be selected.
+    MenuItem,
+}
+
+impl Role {
+    /// Returns true if a node with this [`Role`] contains the nodes of the
+    /// widgets inside of it.
+    pub fn is_container(self) -> bool {
+        matches!(self, Role::Group | Role::Button | Role::ScrollView)
+    }
+}
+
+/// The value of an accessible widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+    /// A number in a range.
+    Number {
+        /// The current number.
+        value: f64,
+        /// The minimum number.
+        min: f64,
+        /// The maximum number.
+        max: f64,
+        /// The step between numbers.
+        step: f64,
+    },
+}
+
+/// The description of an accessible widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+    /// The [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+    /// Whether the widget is toggled or not, if it can be toggled.
+    pub toggled: Option<bool>,
+    /// Whether the widget can be interacted with or not.
+    pub is_disabled: bool,
+    /// Whether the widget is focused or not.
+    pub is_focused: bool,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            toggled: None,
+            is_disabled: false,
+            is_focused: false,
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Node`].
+    pub fn value(mut self, value: Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled or not.
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled or not.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused or not.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+}
+
+/// The identifier of a [`Node`] in a [`Tree`].
+///
+/// It is derived from the widget [`Id`], if any, or from the position of
+/// the widget in the tree otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub u64);
+
+/// A [`Node`] placed in a [`Tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// The [`NodeId`] of the entry.
+    pub id: NodeId,
+    /// The absolute bounds of the widget.
+    pub bounds: Rectangle,
+    /// The description of the widget.
+    pub node: Node,
+    /// The children of the entry.
+    pub children: Vec<NodeId>,
+}
+
+/// The accessibility tree of a user interface.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tree {
+    entries: Vec<Entry>,
+    roots: Vec<NodeId>,
+    focus: Option<NodeId>,
+}
+
+impl Tree {
+    /// Returns the [`NodeId`] of the top-level nodes of the [`Tree`].
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Returns the [`NodeId`] of the focused node of the [`Tree`], if any.
+    pub fn focus(&self) -> Option<NodeId> {
+        self.focus
+    }
+
+    /// Returns the [`Entry`] with the given [`NodeId`], if any.
+    pub fn get(&self, id: NodeId) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// Returns an iterator over all the entries of the [`Tree`].
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Returns the cursor position and the events that perform the given
+    /// [`Request`] on the widgets of the user interface.
+    ///
+    /// Returns `None` if the target of the [`Request`] does not exist or
+    /// cannot perform the [`Action`] through events. An [`Action::Focus`]
+    /// must be performed with the [`focus`] operation instead, since
+    /// clicking a widget could activate it.
+    pub fn events(&self, request: &Request) -> Option<(Point, Vec<Event>)> {
+        let entry = self.get(request.target)?;
+
+        if entry.node.is_disabled {
+            return None;
+        }
+
+        let bounds = entry.bounds;
+        let center = bounds.center();
+
+        let click = |position| {
+            Some((
+                position,
+                vec![
+                    Event::Mouse(mouse::Event::CursorMoved { position }),
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left,
+                    )),
+                ],
+            ))
+        };
+
+        match &request.action {
+            Action::Click => click(center),
+            Action::Focus => None,
+            Action::Increment | Action::Decrement => {
+                let Some(Value::Number {
+                    value,
+                    min,
+                    max,
+                    step,
+                }) = entry.node.value
+                else {
+                    return None;
+                };
+
+                if max <= min {
+                    return None;
+                }
+
+                let value = if request.action == Action::Increment {
+                    value + step
+                } else {
+                    value - step
+                }
+                .clamp(min, max);
+
+                let percent = ((value - min) / (max - min)) as f32;
+
+                click(Point::new(bounds.x + bounds.width * percent, center.y))
+            }
+            Action::ScrollBy(lines) => Some((
+                center,
+                vec![
+                    Event::Mouse(mouse::Event::CursorMoved {
+                        position: center,
+                    }),
+                    Event::Mouse(mouse::Event::WheelScrolled {
+                        delta: mouse::ScrollDelta::Lines {
+                            x: lines.x,
+                            y: lines.y,
+                        },
+                    }),
+                ],
+            )),
+        }
+    }
+}
+
+/// An action requested by an assistive technology.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Perform the default action of the widget (e.g. press a button).
+    Click,
+    /// Focus the widget.
+    Focus,
+    /// Increment the value of the widget.
+    Increment,
+    /// Decrement the value of the widget.
+    Decrement,
+    /// Scroll the contents of the widget by the given amount of lines.
+    ScrollBy(Vector),
+}
+
+/// An [`Action`] targeting a specific [`Node`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// The [`NodeId`] of the target.
+    pub target: NodeId,
+    /// The [`Action`] to perform.
+    pub action: Action,
+}
+
+/// Produces an [`Operation`] that builds the accessibility [`Tree`] of
+/// a user interface.
+pub fn tree() -> impl Operation<Tree> {
+    struct Builder {
+        tree: Tree,
+        ids: Ids,
+        offset: Vector,
+        translation: Option<Vector>,
+    }
+
+    impl Operation<Tree> for Builder {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Tree>),
+        ) {
+            let is_parent = self.ids.enter();
+            let translation = self.translation.take();
+
+            if let Some(translation) = translation {
+                self.offset = self.offset - translation;
+            }
+
+            operate_on_children(self);
+
+            if let Some(translation) = translation {
+                self.offset = self.offset + translation;
+            }
+
+            self.ids.exit(is_parent);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.translation = Some(translation);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &Node,
+        ) {
+            let (id, parent) = self.ids.next(id, node);
+
+            match parent {
+                Some(parent) => {
+                    if let Some(entry) = self
+                        .tree
+                        .entries
+                        .iter_mut()
+                        .rev()
+                        .find(|entry| entry.id == parent)
+                    {
+                        entry.children.push(id);
+                    }
+                }
+                None => {
+                    self.tree.roots.push(id);
+                }
+            }
+
+            if node.is_focused {
+                self.tree.focus = Some(id);
+            }
+
+            self.tree.entries.push(Entry {
+                id,
+                bounds: bounds + self.offset,
+                node: node.clone(),
+                children: Vec::new(),
+            });
+        }
+
+        fn finish(&self) -> Outcome<Tree> {
+            Outcome::Some(self.tree.clone())
+        }
+    }
+
+    Builder {
+        tree: Tree::default(),
+        ids: Ids::new(),
+        offset: Vector::ZERO,
+        translation: None,
+    }
+}
+
+/// Produces an [`Operation`] that focuses the widget of the [`Node`] with
+/// the given [`NodeId`], unfocusing any other widget.
+pub fn focus<T>(target: NodeId) -> impl Operation<T> {
+    struct Focus {
+        target: NodeId,
+        ids: Ids,
+        is_target: bool,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.is_target = false;
+
+            let is_parent = self.ids.enter();
+            operate_on_children(self);
+            self.ids.exit(is_parent);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            node: &Node,
+        ) {
+            let (id, _parent) = self.ids.next(id, node);
+
+            self.is_target = id == self.target;
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            // Widgets describe themselves right before exposing their
+            // focusable state.
+            if self.is_target {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+
+            self.is_target = false;
+        }
+    }
+
+    Focus {
+        target,
+        ids: Ids::new(),
+        is_target: false,
+    }
+}
+
+/// Derives the [`NodeId`] of every [`Node`] while traversing a widget tree.
+struct Ids {
+    parents: Vec<Parent>,
+    pending: Option<NodeId>,
+}
+
+struct Parent {
+    id: Option<NodeId>,
+    children: usize,
+}
+
+impl Ids {
+    fn new() -> Self {
+        Self {
+            parents: vec![Parent {
+                id: None,
+                children: 0,
+            }],
+            pending: None,
+        }
+    }
+
+    /// Enters a container, returning true if it is the parent of the last
+    /// [`Node`].
+    fn enter(&mut self) -> bool {
+        let Some(id) = self.pending.take() else {
+            return false;
+        };
+
+        self.parents.push(Parent {
+            id: Some(id),
+            children: 0,
+        });
+
+        true
+    }
+
+    fn exit(&mut self, is_parent: bool) {
+        if is_parent {
+            let _ = self.parents.pop();
+        }
+    }
+
+    /// Returns the [`NodeId`] of the next [`Node`] and the [`NodeId`] of its
+    /// parent, if any.
+    fn next(
+        &mut self,
+        id: Option<&Id>,
+        node: &Node,
+    ) -> (NodeId, Option<NodeId>) {
+        let parent = self.parents.last_mut().expect("Root parent must exist");
+        let index = parent.children;
+        parent.children += 1;
+
+        let mut hasher = DefaultHasher::new();
+
+        match id {
+            Some(id) => id.hash(&mut hasher),
+            None => {
+                parent.id.hash(&mut hasher);
+                index.hash(&mut hasher);
+                node.role.hash(&mut hasher);
+            }
+        }
+
+        let id = NodeId(hasher.finish());
+
+        self.pending = node.role.is_container().then_some(id);
+
+        (id, parent.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_nodes_are_children_of_containers() {
+        let mut operation = tree();
+
+        operation.accessible(
+            None,
+            Rectangle::new(Point::ORIGIN, crate::Size::new(100.0, 50.0)),
+            &Node::new(Role::Button),
+        );
+
+        operation.container(None, Rectangle::default(), &mut |operation| {
+            operation.accessible(
+                None,
+                Rectangle::default(),
+                &Node::new(Role::Label).label("Press me"),
+            );
+        });
+
+        operation.accessible(
+            None,
+            Rectangle::default(),
+            &Node::new(Role::TextInput).focused(true),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("Tree operation must produce a tree");
+        };
+
+        let [button, input] = tree.roots() else {
+            panic!("Tree must have two roots");
+        };
+
+        let button = tree.get(*button).expect("Button entry");
+        let label = tree.get(button.children[0]).expect("Label entry");
+
+        assert_eq!(label.node.label.as_deref(), Some("Press me"));
+        assert_eq!(tree.focus(), Some(*input));
+
+        let (position, events) = tree
+            .events(&Request {
+                target: button.id,
+                action: Action::Click,
+            })
+            .expect("Button must be clickable");
+
+        assert_eq!(position, Point::new(50.0, 25.0));
+        assert_eq!(events.len(), 3);
+
+        assert_eq!(
+            tree.events(&Request {
+                target: button.id,
+                action: Action::Focus,
+            }),
+            None,
+            "Focusing must not click"
+        );
+    }
+
+    #[test]
+    fn focus_only_focuses_the_target() {
+        #[derive(Default)]
+        struct Input(bool);
+
+        impl Focusable for Input {
+            fn is_focused(&self) -> bool {
+                self.0
+            }
+
+            fn focus(&mut self) {
+                self.0 = true;
+            }
+
+            fn unfocus(&mut self) {
+                self.0 = false;
+            }
+        }
+
+        fn traverse<T>(operation: &mut dyn Operation<T>, inputs: &mut [Input]) {
+            operation.accessible(
+                None,
+                Rectangle::default(),
+                &Node::new(Role::Button),
+            );
+
+            for input in inputs {
+                operation.accessible(
+                    None,
+                    Rectangle::default(),
+                    &Node::new(Role::TextInput),
+                );
+                operation.focusable(input, None);
+            }
+        }
+
+        let mut inputs = [Input(true), Input(false)];
+
+        let mut operation = tree();
+        traverse(&mut operation, &mut inputs);
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("Tree operation must produce a tree");
+        };
+
+        let [_button, _first, second] = tree.roots() else {
+            panic!("Tree must have three roots");
+        };
+
+        traverse(&mut focus::<()>(*second), &mut inputs);
+
+        assert!(!inputs[0].is_focused());
+        assert!(inputs[1].is_focused());
+    }
+
+    #[test]
+    fn sliders_are_incremented_by_step() {
+        let mut operation = tree();
+
+        operation.accessible(
+            None,
+            Rectangle::new(Point::ORIGIN, crate::Size::new(100.0, 10.0)),
+            &Node::new(Role::Slider).value(Value::Number {
+                value: 50.0,
+                min: 0.0,
+                max: 200.0,
+                step: 20.0,
+            }),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("Tree operation must produce a tree");
+        };
+
+        let (position, _) = tree
+            .events(&Request {
+                target: tree.roots()[0],
+                action: Action::Increment,
+            })
+            .expect("Slider must be incrementable");
+
+        assert_eq!(position, Point::new(35.0, 5.0));
+    }
+}
@@ -0,0 +1,702 @@
+//! Animate values over time with easing curves and springs.
+use crate::border;
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{
+    Background, Border, Color, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/// A transition between two states of some value over time.
+///
+/// An [`Animation`] does not keep track of time by itself. Instead, every
+/// query takes the current [`Instant`]—normally the one provided by a
+/// [`window::Event::RedrawRequested`].
+///
+/// # Example
+/// ```
+/// use iced_core::animation::{Animation, Easing};
+/// use iced_core::time::{Duration, Instant};
+///
+/// let now = Instant::now();
+///
+/// let opacity = Animation::new(0.0)
+///     .duration(Duration::from_millis(300))
+///     .easing(Easing::EaseInOut)
+///     .go(1.0, now);
+///
+/// assert_eq!(opacity.value(now), 0.0);
+/// assert_eq!(opacity.value(now + Duration::from_millis(300)), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    origin: T,
+    target: T,
+    started_at: Option<Instant>,
+    delay: Duration,
+    motion: Motion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Motion {
+    Curve {
+        duration: Duration,
+        easing: Easing,
+    },
+    Spring {
+        spring: Spring,
+        settles_after: Duration,
+    },
+}
+
+impl<T> Animation<T>
+where
+    T: Clone + PartialEq,
+{
+    /// The default duration of an [`Animation`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Animation`] resting at the given state.
+    pub fn new(state: T) -> Self {
+        Self {
+            origin: state.clone(),
+            target: state,
+            started_at: None,
+            delay: Duration::ZERO,
+            motion: Motion::Curve {
+                duration: Self::DEFAULT_DURATION,
+                easing: Easing::default(),
+            },
+        }
+    }
+
+    /// Sets the duration of the [`Animation`].
+    ///
+    /// This replaces any [`Spring`] previously set.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        let easing = match self.motion {
+            Motion::Curve { easing, .. } => easing,
+            Motion::Spring { .. } => Easing::default(),
+        };
+
+        self.motion = Motion::Curve { duration, easing };
+        self
+    }
+
+    /// Sets the [`Easing`] curve of the [`Animation`].
+    ///
+    /// This replaces any [`Spring`] previously set.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        let duration = match self.motion {
+            Motion::Curve { duration, .. } => duration,
+            Motion::Spring { .. } => Self::DEFAULT_DURATION,
+        };
+
+        self.motion = Motion::Curve { duration, easing };
+        self
+    }
+
+    /// Drives the [`Animation`] with the given [`Spring`] instead of
+    /// an [`Easing`] curve.
+    ///
+    /// A spring decides its own duration, and it may overshoot its target.
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.motion = Motion::Spring {
+            spring,
+            settles_after: spring.settles_after(),
+        };
+        self
+    }
+
+    /// Sets the time the [`Animation`] waits before starting a transition.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Transitions the [`Animation`] to the given state, starting at `now`.
+    pub fn go(mut self, target: T, now: Instant) -> Self {
+        self.go_mut(target, now);
+        self
+    }
+
+    /// Transitions the [`Animation`] to the given state, starting at `now`.
+    ///
+    /// Nothing happens if the [`Animation`] is already heading to the state.
+    ///
+    /// If a transition is interrupted by going back to its origin, the
+    /// [`Animation`] reverses from its current position. Otherwise, the
+    /// new transition starts from the previous target.
+    pub fn go_mut(&mut self, target: T, now: Instant) {
+        if self.target == target {
+            return;
+        }
+
+        let started_at = if target == self.origin && self.is_animating(now) {
+            let elapsed = match self.motion {
+                Motion::Curve { duration, .. } => {
+                    duration.mul_f32(1.0 - self.raw_progress(now))
+                }
+                Motion::Spring { spring, .. } => {
+                    spring.reached(1.0 - self.progress(now))
+                }
+            };
+
+            now.checked_sub(self.delay + elapsed).unwrap_or(now)
+        } else {
+            now
+        };
+
+        self.origin = std::mem::replace(&mut self.target, target);
+        self.started_at = Some(started_at);
+    }
+
+    /// Returns the state the current transition started from.
+    pub fn origin(&self) -> &T {
+        &self.origin
+    }
+
+    /// Returns the state the [`Animation`] is heading to.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns the eased progress of the current transition at the given
+    /// [`Instant`], from `0.0` at its origin to `1.0` at its target.
+    ///
+    /// Springs and some [`Easing`] curves may produce values outside of
+    /// this range.
+    pub fn progress(&self, now: Instant) -> f32 {
+        let Some(elapsed) = self.elapsed(now) else {
+            return 1.0;
+        };
+
+        match self.motion {
+            Motion::Curve { duration, easing } => {
+                easing.apply(progress_of(elapsed, duration))
+            }
+            Motion::Spring {
+                spring,
+                settles_after,
+            } => {
+                if elapsed >= settles_after {
+                    1.0
+                } else {
+                    spring.position(elapsed.as_secs_f32())
+                }
+            }
+        }
+    }
+
+    /// Maps both states of the current transition with the given function
+    /// and interpolates the results at the given [`Instant`].
+    ///
+    /// This is useful to animate discrete states; like the status of a
+    /// widget.
+    pub fn interpolate_with<V>(&self, f: impl Fn(&T) -> V, now: Instant) -> V
+    where
+        V: Interpolate,
+    {
+        if !self.is_animating(now) {
+            return f(&self.target);
+        }
+
+        f(&self.origin).interpolate(&f(&self.target), self.progress(now))
+    }
+
+    /// Returns true if the [`Animation`] is still transitioning at the given
+    /// [`Instant`]; including any remaining delay.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.elapsed(now).is_some_and(|elapsed| {
+            elapsed
+                < match self.motion {
+                    Motion::Curve { duration, .. } => duration,
+                    Motion::Spring { settles_after, .. } => settles_after,
+                }
+        })
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the [`Animation`]
+    /// running, if it is still transitioning at the given [`Instant`].
+    pub fn redraw_request(
+        &self,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        if !self.is_animating(now) {
+            return None;
+        }
+
+        let starts_at = self.started_at? + self.delay;
+
+        Some(window::RedrawRequest::At(starts_at.max(now)))
+    }
+
+    fn elapsed(&self, now: Instant) -> Option<Duration> {
+        let starts_at = self.started_at? + self.delay;
+
+        Some(now.saturating_duration_since(starts_at))
+    }
+
+    fn raw_progress(&self, now: Instant) -> f32 {
+        match (self.elapsed(now), self.motion) {
+            (Some(elapsed), Motion::Curve { duration, .. }) => {
+                progress_of(elapsed, duration)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone + PartialEq,
+{
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        if !self.is_animating(now) {
+            return self.target.clone();
+        }
+
+        self.origin.interpolate(&self.target, self.progress(now))
+    }
+}
+
+fn progress_of(elapsed: Duration, duration: Duration) -> f32 {
+    if duration.is_zero() {
+        1.0
+    } else {
+        (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+    }
+}
+
+/// A curve describing the rate of change of an [`Animation`].
+///
+/// The named curves match their CSS counterparts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// A constant rate of change.
+    Linear,
+    /// Starts quickly and slows down gradually.
+    Ease,
+    /// Starts slowly and speeds up.
+    EaseIn,
+    /// Starts quickly and slows down.
+    ///
+    /// This is the default.
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+    /// Slows down past the target and settles back into it.
+    EaseOutBack,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the given
+    /// control points `(x1, y1)` and `(x2, y2)`.
+    ///
+    /// Both `x1` and `x2` must be in the `[0, 1]` range.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given linear progress in the
+    /// `[0, 1]` range.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::EaseOutBack => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1, y1, x2, y2, t)
+            }
+        }
+    }
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    fn sample(a1: f32, a2: f32, s: f32) -> f32 {
+        ((1.0 - 3.0 * a2 + 3.0 * a1) * s + (3.0 * a2 - 6.0 * a1)) * s * s
+            + 3.0 * a1 * s
+    }
+
+    fn slope(a1: f32, a2: f32, s: f32) -> f32 {
+        3.0 * (1.0 - 3.0 * a2 + 3.0 * a1) * s * s
+            + 2.0 * (3.0 * a2 - 6.0 * a1) * s
+            + 3.0 * a1
+    }
+
+    // Newton's method converges quickly for most curves...
+    let mut s = x;
+
+    for _ in 0..8 {
+        let error = sample(x1, x2, s) - x;
+
+        if error.abs() < 1e-6 {
+            return sample(y1, y2, s);
+        }
+
+        let slope = slope(x1, x2, s);
+
+        if slope.abs() < 1e-6 {
+            break;
+        }
+
+        s -= error / slope;
+    }
+
+    // ...but flat slopes need bisection
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+
+    for _ in 0..32 {
+        let error = sample(x1, x2, s) - x;
+
+        if error.abs() < 1e-6 {
+            break;
+        }
+
+        if error > 0.0 {
+            high = s;
+        } else {
+            low = s;
+        }
+
+        s = (low + high) / 2.0;
+    }
+
+    sample(y1, y2, s)
+}
+
+/// A damped spring driving an [`Animation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the spring. Stiffer springs are faster.
+    pub stiffness: f32,
+    /// The damping of the spring. Less damped springs bounce more.
+    pub damping: f32,
+    /// The mass attached to the spring. Heavier masses are slower.
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A balanced spring without any bounce.
+    pub const DEFAULT: Self = Self::new(170.0, 26.0);
+
+    /// A soft spring with a slight bounce.
+    pub const GENTLE: Self = Self::new(120.0, 14.0);
+
+    /// A bouncy spring.
+    pub const WOBBLY: Self = Self::new(180.0, 12.0);
+
+    /// A fast spring with a short bounce.
+    pub const STIFF: Self = Self::new(210.0, 20.0);
+
+    /// A slow spring without any bounce.
+    pub const SLOW: Self = Self::new(280.0, 60.0);
+
+    /// Creates a new [`Spring`] with the given stiffness and damping,
+    /// and a unit mass.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Sets the mass of the [`Spring`].
+    pub const fn mass(self, mass: f32) -> Self {
+        Self { mass, ..self }
+    }
+
+    /// Returns the position of a unit step released at rest, `t` seconds
+    /// after its release.
+    fn position(self, t: f32) -> f32 {
+        let mass = self.mass.max(f32::EPSILON);
+        let omega = (self.stiffness.max(f32::EPSILON) / mass).sqrt();
+        let zeta =
+            self.damping.max(0.0) / (2.0 * (self.stiffness * mass).sqrt());
+
+        if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let envelope = (-zeta * omega * t).exp();
+
+            1.0 - envelope
+                * ((omega_d * t).cos()
+                    + zeta * omega / omega_d * (omega_d * t).sin())
+        } else if zeta == 1.0 {
+            1.0 - (-omega * t).exp() * (1.0 + omega * t)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            1.0 + (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r1 - r2)
+        }
+    }
+
+    /// Returns the time it takes for the [`Spring`] to first reach the
+    /// given position.
+    fn reached(self, position: f32) -> Duration {
+        let step = (0..MAX_STEPS)
+            .find(|&step| self.position(step as f32 * STEP) >= position)
+            .unwrap_or(0);
+
+        Duration::from_secs_f32(step as f32 * STEP)
+    }
+
+    /// Returns the time it takes for the [`Spring`] to come to rest.
+    fn settles_after(self) -> Duration {
+        const TOLERANCE: f32 = 0.001;
+
+        let last_away = (0..MAX_STEPS)
+            .rev()
+            .find(|&step| {
+                (1.0 - self.position(step as f32 * STEP)).abs() > TOLERANCE
+            })
+            .map_or(0, |step| step + 1);
+
+        Duration::from_secs_f32(last_away as f32 * STEP)
+    }
+}
+
+/// The time step used to sample a [`Spring`].
+const STEP: f32 = 1.0 / 240.0;
+
+/// The maximum amount of steps sampled before a [`Spring`] is considered
+/// at rest.
+const MAX_STEPS: u32 = 240 * 10;
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Interpolates between `self` and `other` by the given amount, where
+    /// `0.0` produces `self` and `1.0` produces `other`.
+    ///
+    /// Amounts outside of the `[0, 1]` range extrapolate.
+    fn interpolate(&self, other: &Self, amount: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        self + (other - self) * amount
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, amount),
+            g: self.g.interpolate(&other.g, amount),
+            b: self.b.interpolate(&other.b, amount),
+            a: self.a.interpolate(&other.a, amount).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, amount),
+            self.y.interpolate(&other.y, amount),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, amount),
+            self.y.interpolate(&other.y, amount),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, amount),
+            self.height.interpolate(&other.height, amount),
+        )
+    }
+}
+
+impl Interpolate for Rectangle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&other.position(), amount),
+            self.size().interpolate(&other.size(), amount),
+        )
+    }
+}
+
+impl Interpolate for border::Radius {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        border::Radius {
+            top_left: self.top_left.interpolate(&other.top_left, amount),
+            top_right: self.top_right.interpolate(&other.top_right, amount),
+            bottom_right: self
+                .bottom_right
+                .interpolate(&other.bottom_right, amount),
+            bottom_left: self
+                .bottom_left
+                .interpolate(&other.bottom_left, amount),
+        }
+    }
+}
+
+impl Interpolate for Border {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Border {
+            color: self.color.interpolate(&other.color, amount),
+            width: self.width.interpolate(&other.width, amount),
+            radius: self.radius.interpolate(&other.radius, amount),
+        }
+    }
+}
+
+impl Interpolate for Shadow {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, amount),
+            offset: self.offset.interpolate(&other.offset, amount),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, amount),
+        }
+    }
+}
+
+/// Solid colors are interpolated; gradients switch halfway through.
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, amount))
+            }
+            _ if amount < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+/// A missing color fades in or out as a transparent version of the other.
+impl Interpolate for Option<Color> {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, amount)),
+            (Some(a), None) => Some(a.interpolate(&transparent(*a), amount)),
+            (None, Some(b)) => Some(transparent(*b).interpolate(b, amount)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// A missing background fades in or out as a transparent version of
+/// the other.
+impl Interpolate for Option<Background> {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, amount)),
+            (Some(a), None) => Some(a.interpolate(&a.scale_alpha(0.0), amount)),
+            (None, Some(b)) => Some(b.scale_alpha(0.0).interpolate(b, amount)),
+            (None, None) => None,
+        }
+    }
+}
+
+fn transparent(color: Color) -> Color {
+    Color { a: 0.0, ..color }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eases_between_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::Ease,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::EaseOutBack,
+            Easing::CubicBezier(0.3, 0.0, 0.0, 1.0),
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-4, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-4, "{easing:?}");
+        }
+
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-4);
+        assert!(Easing::EaseIn.apply(0.25) < 0.25);
+        assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    }
+
+    #[test]
+    fn springs_settle() {
+        for spring in [
+            Spring::DEFAULT,
+            Spring::GENTLE,
+            Spring::WOBBLY,
+            Spring::STIFF,
+            Spring::SLOW,
+        ] {
+            let settles_after = spring.settles_after();
+
+            assert!(spring.position(0.0).abs() < 1e-4, "{spring:?}");
+            assert!(settles_after > Duration::ZERO, "{spring:?}");
+            assert!(settles_after < Duration::from_secs(5), "{spring:?}");
+        }
+
+        let overshoots = (0..240)
+            .any(|step| Spring::WOBBLY.position(step as f32 / 240.0) > 1.0);
+
+        assert!(overshoots);
+    }
+
+    #[test]
+    fn animates_and_reverses() {
+        let now = Instant::now();
+        let duration = Duration::from_millis(100);
+
+        let mut animation = Animation::new(0.0)
+            .duration(duration)
+            .easing(Easing::Linear)
+            .go(10.0, now);
+
+        let halfway = now + duration / 2;
+
+        assert!(animation.is_animating(halfway));
+        assert!((animation.value(halfway) - 5.0).abs() < 1e-3);
+
+        animation.go_mut(0.0, halfway);
+
+        assert!((animation.value(halfway) - 5.0).abs() < 1e-3);
+        assert!(!animation.is_animating(halfway + duration / 2));
+        assert_eq!(animation.value(halfway + duration / 2), 0.0);
+        assert_eq!(animation.redraw_request(halfway + duration), None);
+
+        let mut animation =
+            Animation::new(0.0).spring(Spring::DEFAULT).go(10.0, now);
+
+        let later = now + Duration::from_millis(80);
+        let before = animation.value(later);
+
+        animation.go_mut(0.0, later);
+
+        assert!((animation.value(later) - before).abs() < 0.1);
+    }
+}
@@ -1,4 +1,7 @@
 //! Access the clipboard.
+use bytes::Bytes;
+
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -8,6 +11,26 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, kind: Kind, contents: String);
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`Format`].
+    ///
+    /// By default, only [`Format::Text`] is supported.
+    fn read_contents(&self, kind: Kind, format: &Format) -> Option<Contents> {
+        match format {
+            Format::Text => self.read(kind).map(Contents::Text),
+            _ => None,
+        }
+    }
+
+    /// Writes the given [`Contents`] to the [`Clipboard`].
+    ///
+    /// By default, only [`Contents::Text`] is supported.
+    fn write_contents(&mut self, kind: Kind, contents: Contents) {
+        if let Contents::Text(text) = contents {
+            self.write(kind, text);
+        }
+    }
 }
 
 /// The kind of [`Clipboard`].
@@ -21,6 +44,74 @@ pub enum Kind {
     Primary,
 }
 
+/// Some typed contents of a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Contents {
+    /// Plain text.
+    Text(String),
+    /// Some HTML markup.
+    Html {
+        /// The HTML markup.
+        html: String,
+        /// The plain text alternative of the markup, if any.
+        alt_text: Option<String>,
+    },
+    /// An RGBA image.
+    Image(Image),
+    /// A list of files.
+    Files(Vec<PathBuf>),
+    /// Arbitrary data with a custom MIME type.
+    Custom {
+        /// The MIME type of the data.
+        mime: String,
+        /// The raw data.
+        data: Bytes,
+    },
+}
+
+impl Contents {
+    /// Returns the [`Format`] of the [`Contents`].
+    pub fn format(&self) -> Format {
+        match self {
+            Contents::Text(_) => Format::Text,
+            Contents::Html { .. } => Format::Html,
+            Contents::Image(_) => Format::Image,
+            Contents::Files(_) => Format::Files,
+            Contents::Custom { mime, .. } => Format::Custom(mime.clone()),
+        }
+    }
+}
+
+/// The format of some [`Contents`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Plain text.
+    Text,
+    /// Some HTML markup.
+    Html,
+    /// An RGBA image.
+    Image,
+    /// A list of files.
+    Files,
+    /// Arbitrary data with the given MIME type.
+    Custom(String),
+}
+
+/// An RGBA image stored in a [`Clipboard`].
+///
+/// It can be displayed with [`image::Handle::from_rgba`].
+///
+/// [`image::Handle::from_rgba`]: crate::image::Handle::from_rgba
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The RGBA pixels of the image.
+    pub pixels: Bytes,
+}
+
 /// A null implementation of the [`Clipboard`] trait.
 #[derive(Debug, Clone, Copy)]
 pub struct Null;
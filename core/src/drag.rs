@@ -0,0 +1,70 @@
+//! Drag and drop data between widgets.
+//!
+//! A widget starts a drag by calling [`Shell::start_drag`] with a
+//! [`Payload`]. Every widget can then inspect the current drag with
+//! [`Shell::drag`] until the mouse button is released.
+//!
+//! [`Shell::start_drag`]: crate::Shell::start_drag
+//! [`Shell::drag`]: crate::Shell::drag
+use crate::{mouse, touch, Event};
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+/// The data carried by an in-app drag.
+#[derive(Clone)]
+pub struct Payload(Arc<dyn Any + Send + Sync>);
+
+impl Payload {
+    /// Creates a new [`Payload`] with the given data.
+    pub fn new<T>(data: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self(Arc::new(data))
+    }
+
+    /// Returns a reference to the data of the [`Payload`], if it is of
+    /// type `T`.
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.0.downcast_ref()
+    }
+
+    /// Returns true if the data of the [`Payload`] is of type `T`.
+    pub fn is<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        self.0.is::<T>()
+    }
+}
+
+impl PartialEq for Payload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Payload").finish_non_exhaustive()
+    }
+}
+
+/// Returns true if the given [`Event`] ends any ongoing drag.
+///
+/// Widgets can check this to drop the current [`Payload`].
+pub fn is_drop(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(
+                touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. }
+            )
+    )
+}
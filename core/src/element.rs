@@ -32,6 +32,12 @@ impl<'a, Message, Theme, Renderer> Element<'a, Message, Theme, Renderer> {
     where
         Renderer: crate::Renderer,
     {
+        if layout::record::is_enabled() {
+            return Self {
+                widget: Box::new(Recorder::new(widget)),
+            };
+        }
+
         Self {
             widget: Box::new(widget),
         }
@@ -321,7 +327,8 @@ where
         viewport: &Rectangle,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag());
 
         let status = self.widget.on_event(
             tree,
@@ -380,6 +387,137 @@ where
     }
 }
 
+/// A widget that records its layout while the recording of layouts is
+/// enabled.
+struct Recorder<W> {
+    widget: W,
+    name: &'static str,
+}
+
+impl<W> Recorder<W> {
+    fn new(widget: W) -> Self {
+        Self {
+            name: std::any::type_name::<W>(),
+            widget,
+        }
+    }
+}
+
+impl<W, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Recorder<W>
+where
+    W: Widget<Message, Theme, Renderer>,
+    Renderer: crate::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.widget.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.widget.size_hint()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.widget.tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.widget.state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.widget.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.widget.diff(tree);
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut node = self.widget.layout(tree, renderer, limits);
+        let size = self.widget.size();
+
+        node.record_with(|record| {
+            // Wrappers share the node of their contents, so the innermost
+            // widget—the most specific one—is kept.
+            if record.widget.is_none() {
+                record.widget = Some(self.name);
+                record.limits = Some(*limits);
+                record.size = Some(size);
+            }
+        });
+
+        node
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.widget.operate(state, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.widget.on_event(
+            state, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.widget
+            .draw(state, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.widget
+            .mouse_interaction(state, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.widget.overlay(state, layout, renderer, translation)
+    }
+}
+
 struct Explain<'a, Message, Theme, Renderer: crate::Renderer> {
     element: Element<'a, Message, Theme, Renderer>,
     color: Color,
@@ -174,6 +174,12 @@ impl From<&Handle> for Handle {
     }
 }
 
+impl From<crate::clipboard::Image> for Handle {
+    fn from(image: crate::clipboard::Image) -> Self {
+        Self::from_rgba(image.width, image.height, image.pixels)
+    }
+}
+
 impl std::fmt::Debug for Handle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
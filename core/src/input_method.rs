@@ -0,0 +1,99 @@
+//! Listen to input method events and report the state of text inputs.
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input methods let users compose text that cannot be typed directly
+/// with their keyboard (e.g. CJK characters).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The input method was enabled.
+    Opened,
+
+    /// A new composing text should be displayed.
+    ///
+    /// The range is the byte range of the cursor inside the text, if any.
+    /// An empty text means the composition should be cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Text was committed and should be inserted.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
+
+/// The text that is being composed with an input method.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Preedit {
+    /// The composing text.
+    pub content: String,
+
+    /// The byte range of the cursor inside the [`Preedit`], if any.
+    pub selection: Option<Range<usize>>,
+}
+
+impl Preedit {
+    /// Creates a new [`Preedit`] from the given content and selection.
+    ///
+    /// Returns `None` if the content is empty.
+    pub fn new(
+        content: String,
+        selection: Option<Range<usize>>,
+    ) -> Option<Self> {
+        if content.is_empty() {
+            return None;
+        }
+
+        Some(Self { content, selection })
+    }
+}
+
+/// The input method requested by a focused widget.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum InputMethod {
+    /// No input method is allowed.
+    #[default]
+    Disabled,
+
+    /// An input method is allowed.
+    Enabled {
+        /// The bounds of the caret, used to place the candidate window.
+        cursor: Rectangle,
+
+        /// The [`Purpose`] of the input method.
+        purpose: Purpose,
+    },
+}
+
+impl InputMethod {
+    /// Merges two [`InputMethod`] requests.
+    ///
+    /// The first enabled request takes precedence.
+    pub fn merge(&mut self, other: &Self) {
+        if let InputMethod::Disabled = self {
+            *self = *other;
+        }
+    }
+
+    /// Returns true if the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, InputMethod::Enabled { .. })
+    }
+}
+
+/// The purpose of an [`InputMethod`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Purpose {
+    /// No special hints for the input method.
+    #[default]
+    Normal,
+
+    /// The input method is used to enter secrets, like passwords.
+    Secure,
+
+    /// The input method is used for a terminal.
+    Terminal,
+}
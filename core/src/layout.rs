@@ -3,9 +3,11 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod record;
 
 pub use limits::Limits;
 pub use node::Node;
+pub use record::Record;
 
 use crate::{Length, Padding, Point, Rectangle, Size, Vector};
 
@@ -53,6 +55,11 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Record`] of the [`Node`] of the [`Layout`], if any.
+    pub fn record(&self) -> Option<&'a Record> {
+        self.node.record()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
@@ -85,7 +92,7 @@ pub fn next_to_each_other(
         ((right_size.height - left_size.height) / 2.0, 0.0)
     };
 
-    Node::with_children(
+    let mut node = Node::with_children(
         Size::new(
             left_size.width + spacing + right_size.width,
             left_size.height.max(right_size.height),
@@ -94,7 +101,10 @@ pub fn next_to_each_other(
             left_node.move_to(Point::new(0.0, left_y)),
             right_node.move_to(Point::new(left_size.width + spacing, right_y)),
         ],
-    )
+    );
+
+    node.record_with(|record| record.spacing = spacing);
+    node
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -183,8 +193,11 @@ pub fn positioned(
         .shrink(padding)
         .resolve(width, height, content.size());
 
-    Node::with_children(
+    let mut node = Node::with_children(
         size.expand(padding),
         vec![position(content.move_to((padding.left, padding.top)), size)],
-    )
+    );
+
+    node.record_with(|record| record.padding = padding);
+    node
 }
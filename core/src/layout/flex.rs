@@ -215,5 +215,12 @@ where
         Size::new(intrinsic_width, intrinsic_height),
     );
 
-    Node::with_children(size.expand(padding), nodes)
+    let mut node = Node::with_children(size.expand(padding), nodes);
+
+    node.record_with(|record| {
+        record.padding = padding;
+        record.spacing = spacing;
+    });
+
+    node
 }
@@ -1,3 +1,4 @@
+use crate::layout::{record, Record};
 use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
@@ -5,6 +6,7 @@ use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    record: Option<Box<Record>>,
 }
 
 impl Node {
@@ -23,15 +25,19 @@ impl Node {
                 height: size.height,
             },
             children,
+            record: None,
         }
     }
 
     /// Creates a new [`Node`] that wraps a single child with some [`Padding`].
     pub fn container(child: Self, padding: Padding) -> Self {
-        Self::with_children(
+        let mut node = Self::with_children(
             child.bounds.size().expand(padding),
             vec![child.move_to(Point::new(padding.left, padding.top))],
-        )
+        );
+
+        node.record_with(|record| record.padding = padding);
+        node
     }
 
     /// Returns the [`Size`] of the [`Node`].
@@ -49,6 +55,21 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Record`] of the [`Node`], if its layout was recorded.
+    pub fn record(&self) -> Option<&Record> {
+        self.record.as_deref()
+    }
+
+    /// Updates the [`Record`] of the [`Node`] with the given closure, if the
+    /// recording of layouts is [enabled].
+    ///
+    /// [enabled]: record::enable
+    pub fn record_with(&mut self, f: impl FnOnce(&mut Record)) {
+        if record::is_enabled() {
+            f(self.record.get_or_insert_with(Box::default));
+        }
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         mut self,
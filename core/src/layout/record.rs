@@ -0,0 +1,55 @@
+//! Record how the layout of every widget is computed.
+use crate::layout::Limits;
+use crate::{Length, Padding, Size};
+
+use std::sync::atomic::{self, AtomicBool};
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the recording of layouts.
+///
+/// While enabled, every [`Element`] created records its widget type, sizing
+/// strategy, and the [`Limits`] it receives into the [`Node`] it produces.
+/// Layout helpers record any [`Padding`] and spacing they apply as well.
+///
+/// Since only new elements are recorded, the view of a program must be
+/// rebuilt for the change to take effect. Records are only written while
+/// recording is enabled, so it must stay enabled during layout as well.
+///
+/// [`Element`]: crate::Element
+/// [`Node`]: crate::layout::Node
+pub fn enable(is_enabled: bool) {
+    IS_ENABLED.store(is_enabled, atomic::Ordering::Relaxed);
+}
+
+/// Returns true if the recording of layouts is enabled.
+pub fn is_enabled() -> bool {
+    IS_ENABLED.load(atomic::Ordering::Relaxed)
+}
+
+/// The details of how a layout [`Node`] was computed.
+///
+/// [`Node`]: crate::layout::Node
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Record {
+    /// The type name of the widget that produced the [`Node`], if known.
+    ///
+    /// [`Node`]: crate::layout::Node
+    pub widget: Option<&'static str>,
+
+    /// The [`Limits`] the widget received, if known.
+    pub limits: Option<Limits>,
+
+    /// The sizing strategy of the widget, if known.
+    pub size: Option<Size<Length>>,
+
+    /// The [`Padding`] applied around the children of the [`Node`].
+    ///
+    /// [`Node`]: crate::layout::Node
+    pub padding: Padding,
+
+    /// The spacing applied between the children of the [`Node`].
+    ///
+    /// [`Node`]: crate::layout::Node
+    pub spacing: f32,
+}
@@ -56,6 +56,29 @@ pub trait Renderer {
         );
     }
 
+    /// Starts recording with the given opacity, multiplied by any opacity
+    /// already applied.
+    ///
+    /// By default, this does nothing; renderers without opacity support
+    /// ignore it and draw every primitive fully opaque.
+    fn start_opacity(&mut self, _opacity: f32) {}
+
+    /// Ends recording with the last opacity started.
+    ///
+    /// By default, this does nothing.
+    fn end_opacity(&mut self) {}
+
+    /// Applies the given opacity to the primitives recorded in the given
+    /// closure.
+    ///
+    /// The opacity scales the alpha of every quad, text, image, and svg
+    /// drawn; overlapping primitives are still blended one by one.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        self.start_opacity(opacity);
+        f(self);
+        self.end_opacity();
+    }
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
@@ -76,6 +99,24 @@ pub struct Quad {
     pub shadow: Shadow,
 }
 
+impl Quad {
+    /// Scales the alpha channel of the colors of the [`Quad`] by the given
+    /// factor.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        Self {
+            border: Border {
+                color: self.border.color.scale_alpha(factor),
+                ..self.border
+            },
+            shadow: Shadow {
+                color: self.shadow.color.scale_alpha(factor),
+                ..self.shadow
+            },
+            ..self
+        }
+    }
+}
+
 impl Default for Quad {
     fn default() -> Self {
         Self {
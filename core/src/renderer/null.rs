@@ -137,6 +137,17 @@ impl text::Editor for () {
         None
     }
 
+    fn selection_anchor(&self) -> Option<text::editor::Position> {
+        None
+    }
+
+    fn move_to(
+        &mut self,
+        _position: text::editor::Position,
+        _anchor: Option<text::editor::Position>,
+    ) {
+    }
+
     fn line(&self, _index: usize) -> Option<&str> {
         None
     }
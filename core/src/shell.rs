@@ -1,4 +1,6 @@
+use crate::drag;
 use crate::window;
+use crate::InputMethod;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +12,9 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
+    drag: Option<drag::Payload>,
+    is_drag_changed: bool,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +25,9 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
+            drag: None,
+            is_drag_changed: false,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +61,51 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`InputMethod`] to be enabled.
+    ///
+    /// Focused text widgets should call this on every redraw, so the
+    /// candidate window of the input method follows their caret.
+    pub fn request_input_method(&mut self, input_method: &InputMethod) {
+        self.input_method.merge(input_method);
+    }
+
+    /// Returns the [`InputMethod`] requested, if any.
+    pub fn input_method(&self) -> &InputMethod {
+        &self.input_method
+    }
+
+    /// Sets the [`drag::Payload`] currently being dragged, if any.
+    ///
+    /// Shells of nested widgets should share the drag of their parent.
+    pub fn with_drag(mut self, drag: Option<&drag::Payload>) -> Self {
+        self.drag = drag.cloned();
+        self
+    }
+
+    /// Returns the [`drag::Payload`] currently being dragged, if any.
+    pub fn drag(&self) -> Option<&drag::Payload> {
+        self.drag.as_ref()
+    }
+
+    /// Starts dragging the given [`drag::Payload`].
+    ///
+    /// The drag ends when the mouse button is released.
+    pub fn start_drag(&mut self, payload: drag::Payload) {
+        self.drag = Some(payload);
+        self.is_drag_changed = true;
+    }
+
+    /// Cancels the current drag, if any.
+    pub fn cancel_drag(&mut self) {
+        self.drag = None;
+        self.is_drag_changed = true;
+    }
+
+    /// Returns whether the current drag was started or cancelled.
+    pub fn is_drag_changed(&self) -> bool {
+        self.is_drag_changed
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +152,13 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.request_input_method(&other.input_method);
+
+        if other.is_drag_changed {
+            self.drag = other.drag;
+            self.is_drag_changed = true;
+        }
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
@@ -27,6 +27,15 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the anchor of the current selection of the [`Editor`], if any.
+    ///
+    /// The anchor is the end of the selection opposite to the cursor.
+    fn selection_anchor(&self) -> Option<Position>;
+
+    /// Moves the cursor of the [`Editor`] to the given [`Position`],
+    /// selecting the text between it and the given anchor, if any.
+    fn move_to(&mut self, position: Position, anchor: Option<Position>);
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
@@ -87,12 +96,16 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last editing transaction.
+    Undo,
+    /// Redo the last undone editing transaction.
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
@@ -180,6 +193,15 @@ pub enum Direction {
     Right,
 }
 
+/// A position in the text of an [`Editor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The byte offset of the position in the line.
+    pub column: usize,
+}
+
 /// The cursor of an [`Editor`].
 #[derive(Debug, Clone)]
 pub enum Cursor {
@@ -7,6 +7,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
+use crate::accessibility;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
@@ -46,6 +47,15 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that can be described to assistive technologies.
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _node: &accessibility::Node,
+    ) {
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -93,6 +103,15 @@ where
         self.as_mut().text_input(state, id);
     }
 
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+    ) {
+        self.as_mut().accessible(id, bounds, node);
+    }
+
     fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
         self.as_mut().custom(state, id);
     }
@@ -175,6 +194,15 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, bounds, node);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -265,6 +293,15 @@ where
                     self.operation.text_input(state, id);
                 }
 
+                fn accessible(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    node: &accessibility::Node,
+                ) {
+                    self.operation.accessible(id, bounds, node);
+                }
+
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
@@ -300,6 +337,15 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, bounds, node);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -386,6 +432,15 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+        ) {
+            self.operation.accessible(id, bounds, node);
+        }
+
         fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -20,6 +20,7 @@
 //!         .into()
 //! }
 //! ```
+use crate::accessibility;
 use crate::alignment;
 use crate::layout;
 use crate::mouse;
@@ -27,6 +28,7 @@ use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{
     Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
     Widget,
@@ -252,6 +254,21 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Label)
+                .label(self.fragment.as_ref()),
+        );
+    }
+
     fn draw(
         &self,
         tree: &Tree,
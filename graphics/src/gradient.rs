@@ -3,20 +3,28 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
+use std::f32::consts::{FRAC_PI_2, TAU};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a center,
+/// or around a center.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its `center` up to
+    /// its `radius`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`,
+    /// starting at its `angle`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +33,25 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
         }
     }
 }
@@ -63,19 +85,123 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            Kind::Linear,
+            &self.stops,
+            [self.start.x, self.start.y, self.end.x, self.end.y],
+        )
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The absolute radius of the gradient.
+    pub radius: f32,
+
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            Kind::Radial,
+            &self.stops,
+            [self.center.x, self.center.y, self.radius, 0.0],
+        )
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The angle where the gradient starts, measured clockwise from the top.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s along a full turn around the center.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -94,12 +220,56 @@ impl Linear {
         self
     }
 
+    /// Returns the offset of the [`Conic`] gradient at the given [`Point`].
+    pub fn offset(&self, point: Point) -> f32 {
+        let distance = point - self.center;
+        let angle = distance.y.atan2(distance.x) + FRAC_PI_2 - self.angle.0;
+
+        (angle / TAU).rem_euclid(1.0)
+    }
+
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
+        Packed::new(
+            Kind::Conic,
+            &self.stops,
+            [self.center.x, self.center.y, self.angle.0, 0.0],
+        )
+    }
+}
+
+/// Packed [`Gradient`] data for use in shader code.
+#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct Packed {
+    // 8 colors, each channel = 16 bit float, 2 colors packed into 1 u32
+    colors: [[u32; 2]; 8],
+    // 8 offsets, 8x 16 bit floats packed into 4 u32s
+    offsets: [u32; 4],
+    // Linear: start and end points
+    // Radial: center and radius
+    // Conic: center and angle
+    direction: [f32; 4],
+    kind: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Linear = 0,
+    Radial = 1,
+    Conic = 2,
+}
+
+impl Packed {
+    fn new(
+        kind: Kind,
+        stops: &[Option<ColorStop>; 8],
+        direction: [f32; 4],
+    ) -> Self {
         let mut colors = [[0u32; 2]; 8];
         let mut offsets = [f16::from(0u8); 8];
 
-        for (index, stop) in self.stops.iter().enumerate() {
+        for (index, stop) in stops.iter().enumerate() {
             let [r, g, b, a] =
                 color::pack(stop.map_or(Color::default(), |s| s.color))
                     .components();
@@ -120,66 +290,78 @@ impl Linear {
             pack_f16s([offsets[6], offsets[7]]),
         ];
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
-
         Packed {
             colors,
             offsets,
             direction,
+            kind: kind as u32,
         }
     }
 }
 
-/// Packed [`Gradient`] data for use in shader code.
-#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
-#[repr(C)]
-pub struct Packed {
-    // 8 colors, each channel = 16 bit float, 2 colors packed into 1 u32
-    colors: [[u32; 2]; 8],
-    // 8 offsets, 8x 16 bit floats packed into 4 u32s
-    offsets: [u32; 4],
-    direction: [f32; 4],
-}
-
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
+            let (start, end) = linear.angle.to_distance(&bounds);
 
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+            Linear {
+                start,
+                end,
+                stops: linear.stops,
             }
+            .pack()
+        }
+        core::Gradient::Radial(radial) => Radial {
+            center: absolute_center(radial.center, bounds),
+            radius: radial.radius
+                * farthest_corner(
+                    absolute_center(radial.center, bounds),
+                    bounds,
+                ),
+            stops: radial.stops,
+        }
+        .pack(),
+        core::Gradient::Conic(conic) => Conic {
+            center: absolute_center(conic.center, bounds),
+            angle: conic.angle,
+            stops: conic.stops,
+        }
+        .pack(),
+    }
+}
 
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
+/// Converts a center relative to the given bounds into an absolute [`Point`].
+pub fn absolute_center(center: Point, bounds: Rectangle) -> Point {
+    Point::new(
+        bounds.x + center.x * bounds.width,
+        bounds.y + center.y * bounds.height,
+    )
+}
 
-            let (start, end) = linear.angle.to_distance(&bounds);
+/// Returns the distance from the given [`Point`] to the farthest corner
+/// of the given bounds.
+pub fn farthest_corner(point: Point, bounds: Rectangle) -> f32 {
+    let dx = (point.x - bounds.x).max(bounds.x + bounds.width - point.x);
+    let dy = (point.y - bounds.y).max(bounds.y + bounds.height - point.y);
 
-            let direction = [start.x, start.y, end.x, end.y];
+    dx.hypot(dy)
+}
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
         }
-    }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
 }
 
 /// Packs two f16s into one u32.
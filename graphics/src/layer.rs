@@ -27,6 +27,7 @@ pub trait Layer: Default {
 pub struct Stack<T: Layer> {
     layers: Vec<T>,
     transformations: Vec<Transformation>,
+    opacities: Vec<f32>,
     previous: Vec<usize>,
     current: usize,
     active_count: usize,
@@ -38,6 +39,7 @@ impl<T: Layer> Stack<T> {
         Self {
             layers: vec![T::default()],
             transformations: vec![Transformation::IDENTITY],
+            opacities: vec![1.0],
             previous: vec![],
             current: 0,
             active_count: 1,
@@ -101,6 +103,28 @@ impl<T: Layer> Stack<T> {
         let _ = self.transformations.pop();
     }
 
+    /// Returns the current opacity of the [`Stack`].
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.opacities.last().copied().unwrap()
+    }
+
+    /// Pushes a new opacity in the [`Stack`], multiplied by the current one.
+    ///
+    /// Future drawing operations will be affected by this new opacity until
+    /// it is popped using [`pop_opacity`].
+    ///
+    /// [`pop_opacity`]: Self::pop_opacity
+    pub fn push_opacity(&mut self, opacity: f32) {
+        self.opacities
+            .push(self.opacity() * opacity.clamp(0.0, 1.0));
+    }
+
+    /// Pops the current opacity in the [`Stack`].
+    pub fn pop_opacity(&mut self) {
+        let _ = self.opacities.pop();
+    }
+
     /// Returns an iterator over mutable references to the layers in the [`Stack`].
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.flush();
@@ -1,6 +1,6 @@
 //! Draw and edit text.
 use crate::core::text::editor::{
-    self, Action, Cursor, Direction, Edit, Motion,
+    self, Action, Cursor, Direction, Edit, Motion, Position,
 };
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{LineHeight, Wrapping};
@@ -104,6 +104,20 @@ impl editor::Editor for Editor {
         self.internal().editor.copy_selection()
     }
 
+    fn selection_anchor(&self) -> Option<Position> {
+        let editor = &self.internal().editor;
+        let cursor = editor.cursor();
+
+        editor.selection_bounds().map(|(start, end)| {
+            let anchor = if cursor == start { end } else { start };
+
+            Position {
+                line: anchor.line,
+                column: anchor.index,
+            }
+        })
+    }
+
     fn cursor(&self) -> editor::Cursor {
         let internal = self.internal();
 
@@ -416,11 +430,36 @@ impl editor::Editor for Editor {
                     cosmic_text::Action::Scroll { lines },
                 );
             }
+
+            // History is kept by the owner of the editor
+            Action::Undo | Action::Redo => {}
         }
 
         self.0 = Some(Arc::new(internal));
     }
 
+    fn move_to(&mut self, position: Position, anchor: Option<Position>) {
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
+
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
+
+        let to_cursor = |position: Position| {
+            cosmic_text::Cursor::new(position.line, position.column)
+        };
+
+        internal.editor.set_cursor(to_cursor(position));
+        internal.editor.set_selection(match anchor {
+            Some(anchor) if anchor != position => {
+                cosmic_text::Selection::Normal(to_cursor(anchor))
+            }
+            _ => cosmic_text::Selection::None,
+        });
+
+        self.0 = Some(Arc::new(internal));
+    }
+
     fn bounds(&self) -> Size {
         self.internal().bounds
     }
@@ -68,6 +68,14 @@ where
     fn end_transformation(&mut self) {
         delegate!(self, renderer, renderer.end_transformation());
     }
+
+    fn start_opacity(&mut self, opacity: f32) {
+        delegate!(self, renderer, renderer.start_opacity(opacity));
+    }
+
+    fn end_opacity(&mut self) {
+        delegate!(self, renderer, renderer.end_opacity());
+    }
 }
 
 impl<A, B> core::text::Renderer for Renderer<A, B>
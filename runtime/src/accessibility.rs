@@ -0,0 +1,15 @@
+//! Let assistive technologies interact with the user interface.
+use crate::core::accessibility::Request;
+use crate::core::window;
+
+/// An accessibility action to be performed by the runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// An assistive technology started inspecting the window with the
+    /// given [`window::Id`], so its accessibility tree must be updated.
+    Activate(window::Id),
+
+    /// Perform the [`Request`] of an assistive technology in the window
+    /// with the given [`window::Id`].
+    Perform(window::Id, Request),
+}
@@ -1,8 +1,10 @@
 //! Access the clipboard.
-use crate::core::clipboard::Kind;
+use crate::core::clipboard::{Contents, Format, Image, Kind};
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
+use std::path::PathBuf;
+
 /// A clipboard action to be performed by some [`Task`].
 ///
 /// [`Task`]: crate::Task
@@ -23,6 +25,25 @@ pub enum Action {
         /// The contents to be written.
         contents: String,
     },
+
+    /// Read the clipboard in the given [`Format`] and produce `T` with the
+    /// result.
+    ReadContents {
+        /// The clipboard target.
+        target: Kind,
+        /// The [`Format`] to read.
+        format: Format,
+        /// The channel to send the read contents.
+        channel: oneshot::Sender<Option<Contents>>,
+    },
+
+    /// Write the given [`Contents`] to the clipboard.
+    WriteContents {
+        /// The clipboard target.
+        target: Kind,
+        /// The contents to be written.
+        contents: Contents,
+    },
 }
 
 /// Read the current contents of the clipboard.
@@ -60,3 +81,57 @@ pub fn write_primary<Message>(contents: String) -> Task<Message> {
         contents,
     }))
 }
+
+/// Read the current contents of the clipboard in the given [`Format`].
+pub fn read_contents(format: Format) -> Task<Option<Contents>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadContents {
+            target: Kind::Standard,
+            format,
+            channel,
+        })
+    })
+}
+
+/// Read the current HTML contents of the clipboard.
+pub fn read_html() -> Task<Option<String>> {
+    read_contents(Format::Html).map(|contents| match contents {
+        Some(Contents::Html { html, .. }) => Some(html),
+        _ => None,
+    })
+}
+
+/// Read the current image contents of the clipboard.
+pub fn read_image() -> Task<Option<Image>> {
+    read_contents(Format::Image).map(|contents| match contents {
+        Some(Contents::Image(image)) => Some(image),
+        _ => None,
+    })
+}
+
+/// Read the list of files in the clipboard.
+pub fn read_files() -> Task<Option<Vec<PathBuf>>> {
+    read_contents(Format::Files).map(|contents| match contents {
+        Some(Contents::Files(files)) => Some(files),
+        _ => None,
+    })
+}
+
+/// Write the given [`Contents`] to the clipboard.
+pub fn write_contents<T>(contents: Contents) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteContents {
+        target: Kind::Standard,
+        contents,
+    }))
+}
+
+/// Write the given HTML markup to the clipboard, together with its plain
+/// text alternative.
+pub fn write_html<T>(html: String, alt_text: Option<String>) -> Task<T> {
+    write_contents(Contents::Html { html, alt_text })
+}
+
+/// Write the given [`Image`] to the clipboard.
+pub fn write_image<T>(image: Image) -> Task<T> {
+    write_contents(Contents::Image(image))
+}
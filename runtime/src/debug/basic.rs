@@ -1,7 +1,96 @@
+//! Measure the performance of the runtime.
 #![allow(missing_docs)]
 use crate::core::time;
+use crate::core::window;
 
 use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Enters a [`tracing`] span for a phase, if the `trace` feature is enabled.
+#[cfg(feature = "trace")]
+macro_rules! enter {
+    ($phase:expr, $($span:tt)*) => {
+        $phase = Some(tracing::info_span!($($span)*).entered())
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! enter {
+    ($phase:expr, $($span:tt)*) => {};
+}
+
+/// Exits the [`tracing`] span of a phase, recording its window, if any.
+#[cfg(feature = "trace")]
+macro_rules! exit {
+    ($phase:expr) => {
+        let _ = $phase.take();
+    };
+    ($phase:expr, $window:expr) => {
+        if let Some(span) = $phase.take() {
+            let _ = span.record("window", $window.map(tracing::field::debug));
+        }
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! exit {
+    ($phase:expr) => {};
+    ($phase:expr, $window:expr) => {
+        let _ = $window;
+    };
+}
+
+/// The maximum amount of [`Span`]s kept in the timeline until taken.
+const TIMELINE_CAPACITY: usize = 1_000;
+
+static TIMELINE: Mutex<VecDeque<Span>> = Mutex::new(VecDeque::new());
+
+/// A stage of the runtime measured for every window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// The `view` of a window being called.
+    View,
+    /// The layout of a window being computed.
+    Layout,
+    /// The primitives of a window being generated.
+    Draw,
+}
+
+/// A measurement of some [`Stage`] of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// The measured [`Stage`].
+    pub stage: Stage,
+    /// The window the [`Stage`] ran for, if known.
+    pub window: Option<window::Id>,
+    /// The instant the [`Stage`] started.
+    pub start: time::Instant,
+    /// The time the [`Stage`] took.
+    pub duration: time::Duration,
+}
+
+/// Takes all the [`Span`]s recorded since the last call.
+///
+/// Once the timeline is full, new spans are discarded until it is taken.
+pub fn take_spans() -> Vec<Span> {
+    TIMELINE
+        .lock()
+        .map(|mut timeline| timeline.drain(..).collect())
+        .unwrap_or_default()
+}
+
+fn record(stage: Stage, window: Option<window::Id>, start: time::Instant) {
+    if let Ok(mut timeline) = TIMELINE.lock() {
+        if timeline.len() < TIMELINE_CAPACITY {
+            timeline.push_back(Span {
+                stage,
+                window,
+                start,
+                duration: start.elapsed(),
+            });
+        }
+    }
+}
 
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
@@ -31,6 +120,22 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    #[cfg(feature = "trace")]
+    spans: Spans,
+}
+
+/// The [`tracing`] spans of the phases in progress.
+#[cfg(feature = "trace")]
+#[derive(Debug, Default)]
+struct Spans {
+    startup: Option<tracing::span::EnteredSpan>,
+    update: Option<tracing::span::EnteredSpan>,
+    view: Option<tracing::span::EnteredSpan>,
+    layout: Option<tracing::span::EnteredSpan>,
+    event: Option<tracing::span::EnteredSpan>,
+    draw: Option<tracing::span::EnteredSpan>,
+    render: Option<tracing::span::EnteredSpan>,
 }
 
 impl Debug {
@@ -63,6 +168,9 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            #[cfg(feature = "trace")]
+            spans: Spans::default(),
         }
     }
 
@@ -72,58 +180,99 @@ impl Debug {
 
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
+
+        enter!(self.spans.startup, "startup");
     }
 
     pub fn startup_finished(&mut self) {
         self.startup_duration = self.startup_start.elapsed();
+
+        exit!(self.spans.startup);
     }
 
     pub fn update_started(&mut self) {
         self.update_start = time::Instant::now();
+
+        enter!(
+            self.spans.update,
+            "update",
+            message = self
+                .last_messages
+                .back()
+                .map(|message| message_type(message))
+        );
     }
 
     pub fn update_finished(&mut self) {
         self.update_durations.push(self.update_start.elapsed());
+
+        exit!(self.spans.update);
     }
 
     pub fn view_started(&mut self) {
         self.view_start = time::Instant::now();
+
+        enter!(self.spans.view, "view", window = tracing::field::Empty);
     }
 
-    pub fn view_finished(&mut self) {
+    pub fn view_finished(&mut self, window: Option<window::Id>) {
         self.view_durations.push(self.view_start.elapsed());
+
+        record(Stage::View, window, self.view_start);
+
+        exit!(self.spans.view, window);
     }
 
     pub fn layout_started(&mut self) {
         self.layout_start = time::Instant::now();
+
+        enter!(self.spans.layout, "layout", window = tracing::field::Empty);
     }
 
-    pub fn layout_finished(&mut self) {
+    pub fn layout_finished(&mut self, window: Option<window::Id>) {
         self.layout_durations.push(self.layout_start.elapsed());
+
+        record(Stage::Layout, window, self.layout_start);
+
+        exit!(self.spans.layout, window);
     }
 
     pub fn event_processing_started(&mut self) {
         self.event_start = time::Instant::now();
+
+        enter!(self.spans.event, "event processing");
     }
 
     pub fn event_processing_finished(&mut self) {
         self.event_durations.push(self.event_start.elapsed());
+
+        exit!(self.spans.event);
     }
 
     pub fn draw_started(&mut self) {
         self.draw_start = time::Instant::now();
+
+        enter!(self.spans.draw, "draw", window = tracing::field::Empty);
     }
 
-    pub fn draw_finished(&mut self) {
+    pub fn draw_finished(&mut self, window: Option<window::Id>) {
         self.draw_durations.push(self.draw_start.elapsed());
+
+        record(Stage::Draw, window, self.draw_start);
+
+        exit!(self.spans.draw, window);
     }
 
     pub fn render_started(&mut self) {
         self.render_start = time::Instant::now();
+
+        enter!(self.spans.render, "render", window = tracing::field::Empty);
     }
 
-    pub fn render_finished(&mut self) {
+    pub fn render_finished(&mut self, window: Option<window::Id>) {
         self.render_durations.push(self.render_start.elapsed());
+
+        exit!(self.spans.render, window);
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -180,6 +329,16 @@ impl Debug {
     }
 }
 
+/// Returns the type of a `Debug`-formatted message; that is, the name of its
+/// outermost variant or struct.
+#[cfg(feature = "trace")]
+fn message_type(message: &str) -> &str {
+    message
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .next()
+        .unwrap_or_default()
+}
+
 impl Default for Debug {
     fn default() -> Self {
         Self::new()
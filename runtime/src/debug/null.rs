@@ -1,4 +1,7 @@
+//! Measure the performance of the runtime.
 #![allow(missing_docs)]
+use crate::core::window;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -17,11 +20,11 @@ impl Debug {
 
     pub fn view_started(&mut self) {}
 
-    pub fn view_finished(&mut self) {}
+    pub fn view_finished(&mut self, _window: Option<window::Id>) {}
 
     pub fn layout_started(&mut self) {}
 
-    pub fn layout_finished(&mut self) {}
+    pub fn layout_finished(&mut self, _window: Option<window::Id>) {}
 
     pub fn event_processing_started(&mut self) {}
 
@@ -29,11 +32,11 @@ impl Debug {
 
     pub fn draw_started(&mut self) {}
 
-    pub fn draw_finished(&mut self) {}
+    pub fn draw_finished(&mut self, _window: Option<window::Id>) {}
 
     pub fn render_started(&mut self) {}
 
-    pub fn render_finished(&mut self) {}
+    pub fn render_finished(&mut self, _window: Option<window::Id>) {}
 
     pub fn log_message<Message: std::fmt::Debug>(
         &mut self,
@@ -9,6 +9,7 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod accessibility;
 pub mod clipboard;
 pub mod font;
 pub mod keyboard;
@@ -22,14 +23,17 @@ pub mod window;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
-// We disable debug capabilities on release builds unless the `debug` feature
-// is explicitly enabled.
-#[cfg(feature = "debug")]
+#[cfg(feature = "trace")]
+pub mod trace;
+
+// We disable debug capabilities on release builds unless the `debug` or
+// `trace` features are explicitly enabled.
+#[cfg(any(feature = "debug", feature = "trace"))]
 #[path = "debug/basic.rs"]
-mod debug;
-#[cfg(not(feature = "debug"))]
+pub mod debug;
+#[cfg(not(any(feature = "debug", feature = "trace")))]
 #[path = "debug/null.rs"]
-mod debug;
+pub mod debug;
 
 pub use iced_core as core;
 pub use iced_futures as futures;
@@ -70,6 +74,9 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action),
 
+    /// Run an accessibility action.
+    Accessibility(accessibility::Action),
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -93,6 +100,7 @@ impl<T> Action<T> {
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
+            Action::Accessibility(action) => Err(Action::Accessibility(action)),
             Action::Exit => Err(Action::Exit),
         }
     }
@@ -116,6 +124,9 @@ where
             }
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
+            Action::Accessibility(action) => {
+                write!(f, "Action::Accessibility({action:?})")
+            }
             Action::Exit => write!(f, "Action::Exit"),
         }
     }
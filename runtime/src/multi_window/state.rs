@@ -145,7 +145,7 @@ where
                     ui.draw(renderer, theme, style, cursor);
             }
 
-            debug.draw_finished();
+            debug.draw_finished(None);
 
             self.caches = Some(
                 user_interfaces
@@ -186,7 +186,7 @@ where
                 self.mouse_interaction =
                     ui.draw(renderer, theme, style, cursor);
             }
-            debug.draw_finished();
+            debug.draw_finished(None);
 
             self.caches = Some(
                 user_interfaces
@@ -268,11 +268,11 @@ fn build_user_interface<'a, P: Program>(
 ) -> UserInterface<'a, P::Message, P::Theme, P::Renderer> {
     debug.view_started();
     let view = program.view();
-    debug.view_finished();
+    debug.view_finished(None);
 
     debug.layout_started();
     let user_interface = UserInterface::build(view, size, cache, renderer);
-    debug.layout_finished();
+    debug.layout_finished(None);
 
     user_interface
 }
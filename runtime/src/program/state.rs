@@ -133,7 +133,7 @@ where
             debug.draw_started();
             self.mouse_interaction =
                 user_interface.draw(renderer, theme, style, cursor);
-            debug.draw_finished();
+            debug.draw_finished(None);
 
             self.cache = Some(user_interface.into_cache());
 
@@ -164,7 +164,7 @@ where
             debug.draw_started();
             self.mouse_interaction =
                 user_interface.draw(renderer, theme, style, cursor);
-            debug.draw_finished();
+            debug.draw_finished(None);
 
             self.cache = Some(user_interface.into_cache());
 
@@ -219,11 +219,11 @@ fn build_user_interface<'a, P: Program>(
 ) -> UserInterface<'a, P::Message, P::Theme, P::Renderer> {
     debug.view_started();
     let view = program.view();
-    debug.view_finished();
+    debug.view_finished(None);
 
     debug.layout_started();
     let user_interface = UserInterface::build(view, size, cache, renderer);
-    debug.layout_finished();
+    debug.layout_finished(None);
 
     user_interface
 }
@@ -0,0 +1,126 @@
+//! Export the [`tracing`] spans of a program as a Chrome trace.
+//!
+//! With the `trace` feature enabled, the runtime emits a span for every
+//! phase of a frame—update, view, layout, event processing, draw, and
+//! render—tagged with its window and message type.
+//!
+//! The [`chrome`] function installs a [`tracing_subscriber`] registry that
+//! writes these spans in the [`trace_event`] format, which can be inspected
+//! offline in `chrome://tracing` or [Perfetto].
+//!
+//! [`trace_event`]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+//! [Perfetto]: https://ui.perfetto.dev
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use tracing_chrome::ChromeLayerBuilder;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+pub use tracing_chrome::FlushGuard as Guard;
+
+/// Writes the spans of the program to a Chrome trace file at the given path.
+///
+/// This sets a [`tracing_subscriber`] registry as the global default of
+/// [`tracing`], recording spans and events up to the
+/// [`Level::DEBUG`](tracing::Level::DEBUG). The trace is finished once the
+/// returned [`Guard`] is dropped.
+pub fn chrome(path: impl AsRef<Path>) -> io::Result<Guard> {
+    let (layer, guard) = ChromeLayerBuilder::new()
+        .writer(BufWriter::new(File::create(path)?))
+        .include_args(true)
+        .build();
+
+    tracing_subscriber::registry()
+        .with(LevelFilter::DEBUG)
+        .with(layer)
+        .try_init()
+        .map_err(io::Error::other)?;
+
+    Ok(guard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(bytes)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn nests_events_in_spans() {
+        let buffer = Buffer::default();
+
+        let (layer, guard) = ChromeLayerBuilder::new()
+            .writer(buffer.clone())
+            .include_args(true)
+            .build();
+
+        let subscriber = tracing_subscriber::registry()
+            .with(LevelFilter::DEBUG)
+            .with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("update").in_scope(|| {
+                tracing::info_span!("view", window = 1).in_scope(|| {
+                    let current = tracing::Span::current();
+
+                    assert_eq!(
+                        current.metadata().map(tracing::Metadata::name),
+                        Some("view")
+                    );
+
+                    tracing::info!(bytes = 42, "atlas upload");
+                });
+            });
+
+            tracing::trace_span!("ignored").in_scope(|| {});
+        });
+
+        drop(guard);
+
+        let trace = String::from_utf8(buffer.0.lock().unwrap().clone())
+            .expect("Trace is UTF-8");
+
+        let entries: Vec<&str> = trace.lines().collect();
+
+        let position = |predicate: &dyn Fn(&str) -> bool| {
+            entries
+                .iter()
+                .position(|entry| predicate(entry))
+                .expect("Trace entry exists")
+        };
+
+        let update = position(&|entry| {
+            entry.contains(r#""ph":"B""#)
+                && entry.contains(r#""name":"update""#)
+        });
+        let view = position(&|entry| {
+            entry.contains(r#""ph":"B""#) && entry.contains(r#""name":"view""#)
+        });
+        let event = position(&|entry| {
+            entry.contains(r#""ph":"i""#) && entry.contains(r#""bytes""#)
+        });
+        let end = position(&|entry| entry.contains(r#""ph":"E""#));
+
+        assert!(update < view);
+        assert!(view < event);
+        assert!(event < end);
+        assert!(!trace.contains("ignored"));
+    }
+}
@@ -1,11 +1,14 @@
 //! Implement your own event loop to drive a user interface.
+use crate::core::drag;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget;
 use crate::core::window;
-use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{
+    Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector,
+};
 use crate::overlay;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -27,6 +30,7 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    drag: Option<drag::Payload>,
 }
 
 impl<'a, Message, Theme, Renderer> UserInterface<'a, Message, Theme, Renderer>
@@ -92,7 +96,7 @@ where
     ) -> Self {
         let root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache { mut state, drag } = cache;
         state.diff(root.as_widget());
 
         let base = root.as_widget().layout(
@@ -107,6 +111,7 @@ where
             state,
             overlay: None,
             bounds,
+            drag,
         }
     }
 
@@ -187,6 +192,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -208,7 +214,8 @@ where
             let mut event_statuses = Vec::new();
 
             for event in events.iter().cloned() {
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_drag(self.drag.as_ref());
 
                 let event_status = overlay.on_event(
                     event,
@@ -231,6 +238,12 @@ where
                     _ => {}
                 }
 
+                input_method.merge(shell.input_method());
+
+                if shell.is_drag_changed() {
+                    self.drag = shell.drag().cloned();
+                }
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -302,11 +315,18 @@ where
             .cloned()
             .zip(overlay_statuses)
             .map(|(event, overlay_status)| {
+                let is_drop = drag::is_drop(&event);
+
                 if matches!(overlay_status, event::Status::Captured) {
+                    if is_drop {
+                        self.drag = None;
+                    }
+
                     return overlay_status;
                 }
 
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_drag(self.drag.as_ref());
 
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
@@ -333,6 +353,14 @@ where
                     _ => {}
                 }
 
+                input_method.merge(shell.input_method());
+
+                if is_drop {
+                    self.drag = None;
+                } else if shell.is_drag_changed() {
+                    self.drag = shell.drag().cloned();
+                }
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -355,7 +383,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -601,13 +632,24 @@ where
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                drag: self.drag,
+            },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            drag: self.drag,
+        }
     }
 }
 
@@ -615,6 +657,7 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    drag: Option<drag::Payload>,
 }
 
 impl Cache {
@@ -625,6 +668,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            drag: None,
         }
     }
 }
@@ -646,5 +690,8 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the focused widget, if any.
+        input_method: InputMethod,
     },
 }
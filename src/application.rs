@@ -379,6 +379,27 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Enables time travel in the inspector of the [`Application`].
+    ///
+    /// The state is cloned after every message, which lets the inspector
+    /// replay the [`Application`] to any earlier point. The inspector is
+    /// opened with F12 when the `debug` feature is enabled; otherwise,
+    /// this does nothing.
+    pub fn time_travel(
+        self,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::State: Clone,
+    {
+        Application {
+            raw: program::with_time_travel(self.raw),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
     /// Sets the executor of the [`Application`].
     pub fn executor<E>(
         self,
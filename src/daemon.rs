@@ -224,6 +224,26 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Enables time travel in the inspector of the [`Daemon`].
+    ///
+    /// The state is cloned after every message, which lets the inspector
+    /// replay the [`Daemon`] to any earlier point. The inspector is
+    /// opened with F12 when the `debug` feature is enabled; otherwise,
+    /// this does nothing.
+    pub fn time_travel(
+        self,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::State: Clone,
+    {
+        Daemon {
+            raw: program::with_time_travel(self.raw),
+            settings: self.settings,
+        }
+    }
+
     /// Sets the executor of the [`Daemon`].
     pub fn executor<E>(
         self,
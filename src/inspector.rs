@@ -0,0 +1,1265 @@
+//! Inspect the messages, timings, and widgets of a running program.
+//!
+//! The inspector is opened in its own window by pressing F12 when the
+//! `debug` feature is enabled. It can also outline the layout of every
+//! widget, showing how each one was sized when hovered.
+use crate::core::event;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::Paragraph as _;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
+use crate::core::{
+    accessibility, alignment, Border, Clipboard, Color, Element, Event, Layout,
+    Length, Padding, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::keyboard;
+use crate::program::{self, Program};
+use crate::runtime::debug::{self, Stage};
+use crate::shell;
+use crate::widget::{
+    button, column, horizontal_rule, horizontal_space, mouse_area, row,
+    scrollable, text, text_input, themer, toggler, tree_view,
+};
+use crate::window;
+use crate::{Subscription, Task, Theme};
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::marker::PhantomData;
+
+/// The maximum amount of messages kept in the log of the [`Inspector`].
+const LOG_CAPACITY: usize = 1_000;
+
+/// The time the [`Inspector`] waits for the timings of a message.
+const TIMINGS_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The color of the layout bounds highlighted by the [`Inspector`].
+const HIGHLIGHT: Color = Color::from_rgb(0.2, 0.6, 1.0);
+
+/// The color of the padding outlined by the [`Inspector`].
+const PADDING: Color = Color::from_rgba(0.3, 0.8, 0.4, 0.3);
+
+/// The color of the spacing outlined by the [`Inspector`].
+const SPACING: Color = Color::from_rgba(1.0, 0.6, 0.2, 0.3);
+
+/// A [`Program`] running with an inspector window.
+pub struct Inspector<P: Program, I> {
+    program: P,
+    state: P::State,
+    origin: Option<P::State>,
+    log: VecDeque<Entry<P::State>>,
+    replay: Option<usize>,
+    queue: Vec<P::Message>,
+    search: String,
+    window: Option<window::Id>,
+    started_at: Instant,
+    widgets: RefCell<HashMap<window::Id, Vec<Item>>>,
+    collapsed: HashSet<Key>,
+    hovered: Option<(window::Id, Rectangle)>,
+    outline: bool,
+    _initialize: PhantomData<I>,
+}
+
+/// A message of an [`Inspector`].
+#[derive(Debug)]
+pub enum Message<T> {
+    Program(T),
+    Inspector(Interaction),
+    Frame,
+}
+
+/// An interaction with the window of an [`Inspector`].
+#[derive(Debug, Clone)]
+pub enum Interaction {
+    Toggled,
+    Closed(window::Id),
+    Searched(String),
+    Replayed(usize),
+    Resumed,
+    Hovered(Option<(window::Id, Rectangle)>),
+    Expanded(Key, bool),
+    Outlined(bool),
+}
+
+/// A node of the widget tree explorer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    window: window::Id,
+    index: Option<usize>,
+}
+
+struct Entry<State> {
+    message: String,
+    at: Instant,
+    update: Duration,
+    timings: Timings,
+    snapshot: Option<State>,
+}
+
+impl<State> Entry<State> {
+    fn is_pending(&self) -> bool {
+        !self.timings.has(Stage::Draw) && self.at.elapsed() < TIMINGS_TIMEOUT
+    }
+}
+
+/// The timings of the first frame drawn after a message.
+///
+/// Messages handled in the same batch share the timings of the same frame.
+#[derive(Debug, Default)]
+struct Timings {
+    view: Duration,
+    layout: Duration,
+    draw: Duration,
+    measured: Vec<(Stage, Option<window::Id>)>,
+}
+
+impl Timings {
+    /// Records the first [`debug::Span`] of each stage of every window.
+    fn record(&mut self, span: debug::Span) {
+        if self.measured.contains(&(span.stage, span.window)) {
+            return;
+        }
+
+        self.measured.push((span.stage, span.window));
+
+        match span.stage {
+            Stage::View => self.view += span.duration,
+            Stage::Layout => self.layout += span.duration,
+            Stage::Draw => self.draw += span.duration,
+        }
+    }
+
+    fn has(&self, stage: Stage) -> bool {
+        self.measured.iter().any(|(measured, _)| *measured == stage)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    depth: usize,
+    name: String,
+    bounds: Rectangle,
+    is_leaf: bool,
+}
+
+impl<P, I> Inspector<P, I>
+where
+    P: Program,
+{
+    /// Assigns the recorded timings to the pending messages in the log.
+    fn collect(&mut self) {
+        let spans = debug::take_spans();
+
+        for span in spans {
+            let is_inspector =
+                span.window.is_some() && span.window == self.window;
+
+            if is_inspector {
+                continue;
+            }
+
+            for entry in self
+                .log
+                .iter_mut()
+                .rev()
+                .take_while(|entry| entry.is_pending())
+            {
+                if span.start >= entry.at {
+                    entry.timings.record(span);
+                }
+            }
+        }
+    }
+
+    /// Updates the program with the given message, logging it.
+    fn run(&mut self, message: P::Message) -> Task<Message<P::Message>> {
+        let description = format!("{message:?}");
+
+        let at = Instant::now();
+        let task = self.program.update(&mut self.state, message);
+        let update = at.elapsed();
+
+        self.log.push_back(Entry {
+            message: description,
+            at,
+            update,
+            timings: Timings::default(),
+            snapshot: self.program.snapshot(&self.state),
+        });
+
+        if self.log.len() > LOG_CAPACITY {
+            if let Some(entry) = self.log.pop_front() {
+                self.origin = entry.snapshot;
+            }
+        }
+
+        task.map(Message::Program)
+    }
+
+    /// Runs the messages received while replaying.
+    fn resume(&mut self) -> Task<Message<P::Message>> {
+        let queue = std::mem::take(&mut self.queue);
+
+        Task::batch(queue.into_iter().map(|message| self.run(message)))
+    }
+
+    fn inspect(
+        &mut self,
+        interaction: Interaction,
+    ) -> Task<Message<P::Message>> {
+        match interaction {
+            Interaction::Toggled => {
+                if let Some(window) = self.window.take() {
+                    self.hovered = None;
+                    self.widgets.get_mut().clear();
+                    self.outline(false);
+
+                    return window::close(window);
+                }
+
+                let (window, open) = window::open(window::Settings {
+                    size: Size::new(480.0, 720.0),
+                    ..window::Settings::default()
+                });
+
+                self.window = Some(window);
+
+                open.discard()
+            }
+            Interaction::Closed(window) => {
+                if Some(window) == self.window {
+                    self.window = None;
+                    self.hovered = None;
+                    self.widgets.get_mut().clear();
+                    self.outline(false);
+
+                    return Task::none();
+                }
+
+                let widgets = self.widgets.get_mut();
+
+                if widgets.remove(&window).is_some() && widgets.is_empty() {
+                    // Closing the last window of the program closes the
+                    // inspector too, so applications still exit.
+                    if let Some(inspector) = self.window.take() {
+                        self.outline(false);
+
+                        return window::close(inspector);
+                    }
+                }
+
+                Task::none()
+            }
+            Interaction::Searched(search) => {
+                self.search = search;
+
+                Task::none()
+            }
+            Interaction::Replayed(position) => {
+                let snapshot = if position == 0 {
+                    self.origin.as_ref()
+                } else {
+                    self.log
+                        .get(position - 1)
+                        .and_then(|entry| entry.snapshot.as_ref())
+                };
+
+                if let Some(state) =
+                    snapshot.and_then(|state| self.program.snapshot(state))
+                {
+                    self.state = state;
+                    self.replay =
+                        (position < self.log.len()).then_some(position);
+
+                    if self.replay.is_none() {
+                        return self.resume();
+                    }
+                }
+
+                Task::none()
+            }
+            Interaction::Resumed => {
+                if let Some(position) = self.replay.take() {
+                    self.log.truncate(position);
+                }
+
+                self.resume()
+            }
+            Interaction::Hovered(hovered) => {
+                self.hovered = hovered;
+
+                Task::none()
+            }
+            Interaction::Expanded(key, is_expanded) => {
+                if is_expanded {
+                    let _ = self.collapsed.remove(&key);
+                } else {
+                    let _ = self.collapsed.insert(key);
+                }
+
+                Task::none()
+            }
+            Interaction::Outlined(outline) => {
+                self.outline(outline);
+
+                Task::none()
+            }
+        }
+    }
+
+    /// Shows or hides the layout outline of the windows of the program.
+    ///
+    /// Layouts are only recorded while the outline is shown.
+    fn outline(&mut self, outline: bool) {
+        self.outline = outline;
+        layout::record::enable(outline);
+    }
+
+    fn inspector(&self) -> Element<'_, Interaction, Theme, P::Renderer> {
+        let search = self.search.to_lowercase();
+        let current = self.replay.unwrap_or(self.log.len());
+
+        let start = button(text("Start").size(12))
+            .width(Length::Fill)
+            .style(if current == 0 {
+                button::secondary
+            } else {
+                button::text
+            })
+            .on_press_maybe(
+                self.origin.is_some().then_some(Interaction::Replayed(0)),
+            );
+
+        let messages = self
+            .log
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                search.is_empty()
+                    || entry.message.to_lowercase().contains(&search)
+            })
+            .map(|(index, entry)| {
+                let timestamp = entry.at.duration_since(self.started_at);
+
+                let timings = format!(
+                    "update {:?} · view {:?} · layout {:?} · draw {:?}",
+                    entry.update,
+                    entry.timings.view,
+                    entry.timings.layout,
+                    entry.timings.draw,
+                );
+
+                button(column![
+                    row![
+                        text!("#{}", index + 1).size(12),
+                        text!("{:.3}s", timestamp.as_secs_f32()).size(12),
+                        text(&entry.message).size(12),
+                    ]
+                    .spacing(10),
+                    text(timings).size(10),
+                ])
+                .width(Length::Fill)
+                .style(if current == index + 1 {
+                    button::secondary
+                } else {
+                    button::text
+                })
+                .on_press_maybe(
+                    entry
+                        .snapshot
+                        .is_some()
+                        .then_some(Interaction::Replayed(index + 1)),
+                )
+                .into()
+            });
+
+        let replay: Element<'_, Interaction, Theme, P::Renderer> = match self
+            .replay
+        {
+            Some(position) => row![
+                text!("Replaying {position} of {}", self.log.len()).size(12),
+                button(text("Resume").size(12)).on_press(Interaction::Resumed),
+                button(text("Latest").size(12))
+                    .style(button::secondary)
+                    .on_press(Interaction::Replayed(self.log.len())),
+            ]
+            .spacing(10)
+            .align_y(crate::Center)
+            .into(),
+            None if self.origin.is_none() => {
+                text("Enable time travel to replay messages")
+                    .size(12)
+                    .into()
+            }
+            None => text("Click a message to replay it").size(12).into(),
+        };
+
+        let windows = {
+            let widgets = self.widgets.borrow();
+            let mut windows: Vec<_> = widgets.keys().copied().collect();
+            windows.sort();
+
+            windows
+                .into_iter()
+                .map(|window| {
+                    let key = Key {
+                        window,
+                        index: None,
+                    };
+
+                    let mut index = 0;
+                    let children = nodes(
+                        window,
+                        widgets.get(&window).map(Vec::as_slice).unwrap_or(&[]),
+                        &mut index,
+                        0,
+                        &self.collapsed,
+                    );
+
+                    tree_view::Node::new(key, text!("{window:?}").size(12))
+                        .children(children)
+                        .expanded(!self.collapsed.contains(&key))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        column![
+            row![text("Messages").size(16), horizontal_space(), replay]
+                .align_y(crate::Center),
+            text_input("Search messages...", &self.search)
+                .on_input(Interaction::Searched)
+                .size(12),
+            scrollable(column![start].extend(messages).spacing(2))
+                .height(Length::FillPortion(3)),
+            horizontal_rule(1),
+            row![
+                text("Widgets").size(16),
+                horizontal_space(),
+                toggler(self.outline)
+                    .label("Outline layout")
+                    .on_toggle(Interaction::Outlined)
+                    .size(16)
+                    .text_size(12),
+            ]
+            .align_y(crate::Center),
+            scrollable(tree_view(windows).on_toggle(Interaction::Expanded))
+                .height(Length::FillPortion(2)),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+/// Builds the explorer nodes of the items at the given depth.
+fn nodes<'a, Renderer>(
+    window: window::Id,
+    items: &[Item],
+    index: &mut usize,
+    depth: usize,
+    collapsed: &HashSet<Key>,
+) -> Vec<tree_view::Node<'a, Key, Interaction, Theme, Renderer>>
+where
+    Renderer: crate::core::text::Renderer + 'a,
+{
+    let mut siblings = Vec::new();
+
+    while let Some(item) = items.get(*index) {
+        if item.depth < depth {
+            break;
+        }
+
+        let key = Key {
+            window,
+            index: Some(*index),
+        };
+
+        *index += 1;
+
+        let children = nodes(window, items, index, depth + 1, collapsed);
+
+        let bounds = item.bounds;
+
+        let label = mouse_area(
+            text!(
+                "{}  {}×{} at ({}, {})",
+                item.name,
+                bounds.width,
+                bounds.height,
+                bounds.x,
+                bounds.y
+            )
+            .size(12),
+        )
+        .on_enter(Interaction::Hovered(Some((window, bounds))))
+        .on_exit(Interaction::Hovered(None));
+
+        siblings.push(
+            tree_view::Node::new(key, label)
+                .children(children)
+                .expanded(!collapsed.contains(&key)),
+        );
+    }
+
+    siblings
+}
+
+impl<P, I> shell::Program for Inspector<P, I>
+where
+    P: Program,
+    I: FnOnce() -> (P::State, Task<P::Message>),
+{
+    type Message = Message<P::Message>;
+    type Theme = P::Theme;
+    type Renderer = P::Renderer;
+    type Flags = (P, I);
+    type Executor = P::Executor;
+
+    fn new((program, initialize): Self::Flags) -> (Self, Task<Self::Message>) {
+        let (state, task) = initialize();
+        let origin = program.snapshot(&state);
+
+        (
+            Self {
+                program,
+                state,
+                origin,
+                log: VecDeque::new(),
+                replay: None,
+                queue: Vec::new(),
+                search: String::new(),
+                window: None,
+                started_at: Instant::now(),
+                widgets: RefCell::new(HashMap::new()),
+                collapsed: HashSet::new(),
+                hovered: None,
+                outline: false,
+                _initialize: PhantomData,
+            },
+            task.map(Message::Program),
+        )
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if Some(window) == self.window {
+            return String::from("Inspector");
+        }
+
+        self.program.title(&self.state, window)
+    }
+
+    fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
+        self.collect();
+
+        match message {
+            Message::Program(message) => {
+                // The program is paused while replaying; messages like
+                // subscription events and task results run on resume.
+                if self.replay.is_some() {
+                    self.queue.push(message);
+
+                    return Task::none();
+                }
+
+                self.run(message)
+            }
+            Message::Inspector(interaction) => self.inspect(interaction),
+            Message::Frame => Task::none(),
+        }
+    }
+
+    fn view(
+        &self,
+        window: window::Id,
+    ) -> crate::Element<'_, Self::Message, Self::Theme, Self::Renderer> {
+        if Some(window) == self.window {
+            return Element::from(
+                themer(Theme::Dark, self.inspector())
+                    .background(|theme| theme.palette().background.into())
+                    .text_color(|theme| theme.palette().text),
+            )
+            .map(Message::Inspector);
+        }
+
+        let content =
+            self.program.view(&self.state, window).map(Message::Program);
+
+        if self.window.is_none() {
+            return content;
+        }
+
+        Element::new(Probe {
+            window,
+            content,
+            widgets: &self.widgets,
+            highlight: self
+                .hovered
+                .filter(|(hovered, _)| *hovered == window)
+                .map(|(_, bounds)| bounds),
+            outline: self.outline,
+        })
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        fn toggle<T>(
+            key: keyboard::Key,
+            _modifiers: keyboard::Modifiers,
+        ) -> Option<Message<T>> {
+            match key {
+                keyboard::Key::Named(keyboard::key::Named::F12) => {
+                    Some(Message::Inspector(Interaction::Toggled))
+                }
+                _ => None,
+            }
+        }
+
+        let mut subscriptions = vec![
+            self.program.subscription(&self.state).map(Message::Program),
+            keyboard::on_key_press(toggle),
+            window::close_events()
+                .map(|window| Message::Inspector(Interaction::Closed(window))),
+        ];
+
+        // Redraw until the timings of the last message are measured.
+        if self.window.is_some()
+            && self.log.back().is_some_and(Entry::is_pending)
+        {
+            subscriptions.push(window::frames().map(|_| Message::Frame));
+        }
+
+        Subscription::batch(subscriptions)
+    }
+
+    fn theme(&self, window: window::Id) -> Self::Theme {
+        if Some(window) == self.window {
+            return P::Theme::default();
+        }
+
+        self.program.theme(&self.state, window)
+    }
+
+    fn style(&self, theme: &Self::Theme) -> program::Appearance {
+        self.program.style(&self.state, theme)
+    }
+
+    fn scale_factor(&self, window: window::Id) -> f64 {
+        if Some(window) == self.window {
+            return 1.0;
+        }
+
+        self.program.scale_factor(&self.state, window)
+    }
+}
+
+/// A widget that collects the widget tree of its contents, highlights
+/// the hovered bounds, and outlines the recorded layout.
+struct Probe<'a, Message, Theme, Renderer> {
+    window: window::Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    widgets: &'a RefCell<HashMap<window::Id, Vec<Item>>>,
+    highlight: Option<Rectangle>,
+    outline: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Probe<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self.content.as_widget().layout(tree, renderer, limits);
+
+        let mut explorer = Explorer::default();
+
+        self.content.as_widget().operate(
+            tree,
+            Layout::new(&node),
+            renderer,
+            &mut explorer,
+        );
+
+        let _ = self
+            .widgets
+            .borrow_mut()
+            .insert(self.window, explorer.items);
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+
+        if let Some(bounds) = self.highlight {
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            color: HIGHLIGHT,
+                            width: 1.0,
+                            radius: 0.0.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    HIGHLIGHT.scale_alpha(0.2),
+                );
+            });
+        }
+
+        if self.outline {
+            renderer.with_layer(*viewport, |renderer| {
+                outline(renderer, layout);
+
+                if let Some(position) = cursor.position() {
+                    if let Some(hovered) = hovered(layout, position) {
+                        describe(renderer, hovered, position, viewport);
+                    }
+                }
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+/// Draws the bounds of every node of a [`Layout`], together with the padding
+/// and spacing recorded for it.
+fn outline<Renderer>(renderer: &mut Renderer, layout: Layout<'_>)
+where
+    Renderer: crate::core::Renderer,
+{
+    let bounds = layout.bounds();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: HIGHLIGHT.scale_alpha(0.6),
+                width: 1.0,
+                radius: 0.0.into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    if let Some(record) = layout.record() {
+        let Padding {
+            top,
+            right,
+            bottom,
+            left,
+        } = record.padding;
+
+        let inner = Rectangle {
+            x: bounds.x + left,
+            y: bounds.y + top,
+            width: (bounds.width - left - right).max(0.0),
+            height: (bounds.height - top - bottom).max(0.0),
+        };
+
+        let padding = [
+            Rectangle::new(bounds.position(), Size::new(bounds.width, top)),
+            Rectangle::new(
+                Point::new(bounds.x, inner.y + inner.height),
+                Size::new(bounds.width, bottom),
+            ),
+            Rectangle::new(
+                Point::new(bounds.x, inner.y),
+                Size::new(left, inner.height),
+            ),
+            Rectangle::new(
+                Point::new(inner.x + inner.width, inner.y),
+                Size::new(right, inner.height),
+            ),
+        ];
+
+        for area in padding {
+            if area.width > 0.0 && area.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area,
+                        ..renderer::Quad::default()
+                    },
+                    PADDING,
+                );
+            }
+        }
+
+        if record.spacing > 0.0 {
+            let children: Vec<_> =
+                layout.children().map(|child| child.bounds()).collect();
+
+            for pair in children.windows(2) {
+                let (previous, next) = (pair[0], pair[1]);
+
+                let gap = if next.y >= previous.y + previous.height {
+                    Rectangle::new(
+                        Point::new(inner.x, previous.y + previous.height),
+                        Size::new(inner.width, record.spacing),
+                    )
+                } else {
+                    Rectangle::new(
+                        Point::new(previous.x + previous.width, inner.y),
+                        Size::new(record.spacing, inner.height),
+                    )
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: gap,
+                        ..renderer::Quad::default()
+                    },
+                    SPACING,
+                );
+            }
+        }
+    }
+
+    for child in layout.children() {
+        outline(renderer, child);
+    }
+}
+
+/// Finds the innermost recorded widget of a [`Layout`] under the given
+/// position.
+fn hovered(layout: Layout<'_>, position: Point) -> Option<Layout<'_>> {
+    // Children may overflow their parent (e.g. the contents of a scrollable),
+    // so every node is visited.
+    let child = layout
+        .children()
+        .rev()
+        .find_map(|child| hovered(child, position));
+
+    child.or_else(|| {
+        let is_hovered = layout.bounds().contains(position);
+        let is_recorded = layout
+            .record()
+            .is_some_and(|record| record.widget.is_some());
+
+        (is_hovered && is_recorded).then_some(layout)
+    })
+}
+
+/// Highlights the hovered [`Layout`] and describes how it was sized next to
+/// the cursor.
+fn describe<Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor: Point,
+    viewport: &Rectangle,
+) where
+    Renderer: crate::core::text::Renderer,
+{
+    let Some(record) = layout.record() else {
+        return;
+    };
+
+    let bounds = layout.bounds();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: HIGHLIGHT,
+                width: 2.0,
+                radius: 0.0.into(),
+            },
+            ..renderer::Quad::default()
+        },
+        HIGHLIGHT.scale_alpha(0.2),
+    );
+
+    let mut description = String::from(widget_name(record.widget));
+
+    if let Some(size) = record.size {
+        description.push_str(&format!(
+            "\nwidth: {:?} · height: {:?}",
+            size.width, size.height
+        ));
+    }
+
+    if let Some(limits) = record.limits {
+        description.push_str(&format!(
+            "\nlimits: {} to {}",
+            dimensions(limits.min()),
+            dimensions(limits.max())
+        ));
+    }
+
+    description.push_str(&format!("\nsize: {}", dimensions(bounds.size())));
+
+    let Padding {
+        top,
+        right,
+        bottom,
+        left,
+    } = record.padding;
+
+    if record.padding != Padding::ZERO {
+        description
+            .push_str(&format!("\npadding: {top} {right} {bottom} {left}"));
+    }
+
+    if record.spacing > 0.0 {
+        description.push_str(&format!("\nspacing: {}", record.spacing));
+    }
+
+    let paragraph = Renderer::Paragraph::with_text(crate::core::text::Text {
+        content: &description,
+        bounds: Size::INFINITY,
+        size: 12.into(),
+        line_height: crate::core::text::LineHeight::default(),
+        font: renderer.default_font(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: crate::core::text::Shaping::Advanced,
+        wrapping: crate::core::text::Wrapping::None,
+    });
+
+    let size = paragraph.min_bounds().expand(Size::new(8.0, 8.0));
+
+    // Keep the description inside the viewport.
+    let position = Point::new(
+        (cursor.x + 16.0)
+            .min(viewport.x + viewport.width - size.width)
+            .max(viewport.x),
+        (cursor.y + 16.0)
+            .min(viewport.y + viewport.height - size.height)
+            .max(viewport.y),
+    );
+
+    let background = Rectangle::new(position, size);
+
+    renderer.with_layer(background, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: background,
+                border: Border {
+                    color: HIGHLIGHT,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..renderer::Quad::default()
+            },
+            Color::from_rgba(0.0, 0.0, 0.0, 0.85),
+        );
+
+        renderer.fill_paragraph(
+            &paragraph,
+            position + Vector::new(4.0, 4.0),
+            Color::WHITE,
+            background,
+        );
+    });
+}
+
+/// Returns the type name of a widget without its path and generics.
+fn widget_name(name: Option<&str>) -> &str {
+    let Some(name) = name else {
+        return "Widget";
+    };
+
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Formats a [`Size`], writing unbounded dimensions as `∞`.
+fn dimensions(size: Size) -> String {
+    fn dimension(value: f32) -> String {
+        // Some widgets (e.g. scrollables) use `f32::MAX` as an unbounded limit.
+        if value >= f32::MAX {
+            String::from("∞")
+        } else {
+            format!("{value}")
+        }
+    }
+
+    format!("{}×{}", dimension(size.width), dimension(size.height))
+}
+
+/// An [`Operation`] that flattens the widget tree into [`Item`]s.
+#[derive(Default)]
+struct Explorer {
+    items: Vec<Item>,
+    depth: usize,
+}
+
+impl Operation for Explorer {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        // Widgets describing themselves before operating on their
+        // children are merged into a single item.
+        let is_described = self.items.last().is_some_and(|item| {
+            item.is_leaf && item.depth == self.depth && item.bounds == bounds
+        });
+
+        if is_described {
+            if let Some(item) = self.items.last_mut() {
+                item.is_leaf = false;
+            }
+        } else {
+            self.items.push(Item {
+                depth: self.depth,
+                name: match id {
+                    Some(id) => format!("Container {id:?}"),
+                    None => String::from("Container"),
+                },
+                bounds,
+                is_leaf: false,
+            });
+        }
+
+        self.depth += 1;
+        operate_on_children(self);
+        self.depth -= 1;
+    }
+
+    fn accessible(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+    ) {
+        let name = match &node.label {
+            Some(label) => format!("{:?} {label:?}", node.role),
+            None => format!("{:?}", node.role),
+        };
+
+        self.items.push(Item {
+            depth: self.depth,
+            name,
+            bounds,
+            is_leaf: true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell::Program as _;
+
+    #[derive(Debug, Clone, Copy)]
+    struct Increment;
+
+    struct Counter;
+
+    impl Program for Counter {
+        type State = u32;
+        type Message = Increment;
+        type Theme = Theme;
+        type Renderer = ();
+        type Executor = crate::executor::Default;
+
+        fn update(
+            &self,
+            state: &mut u32,
+            _message: Increment,
+        ) -> Task<Increment> {
+            *state += 1;
+
+            Task::none()
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a u32,
+            _window: window::Id,
+        ) -> crate::Element<'a, Increment, Theme, ()> {
+            text!("{state}").into()
+        }
+
+        fn snapshot(&self, state: &u32) -> Option<u32> {
+            Some(*state)
+        }
+    }
+
+    type Counting = Inspector<Counter, fn() -> (u32, Task<Increment>)>;
+
+    fn inspector(messages: usize) -> Counting {
+        let (mut inspector, _) = Counting::new((Counter, || (0, Task::none())));
+
+        for _ in 0..messages {
+            let _ = inspector.update(Message::Program(Increment));
+        }
+
+        inspector
+    }
+
+    fn replay(inspector: &mut Counting, interaction: Interaction) {
+        let _ = inspector.update(Message::Inspector(interaction));
+    }
+
+    #[test]
+    fn log_capacity_hands_over_origin() {
+        let inspector = inspector(LOG_CAPACITY + 2);
+
+        assert_eq!(inspector.log.len(), LOG_CAPACITY);
+        assert_eq!(inspector.origin, Some(2));
+        assert_eq!(inspector.log.front().and_then(|e| e.snapshot), Some(3));
+        assert_eq!(inspector.state, LOG_CAPACITY as u32 + 2);
+    }
+
+    #[test]
+    fn resuming_truncates_and_runs_queued_messages() {
+        let mut inspector = inspector(3);
+
+        replay(&mut inspector, Interaction::Replayed(1));
+        assert_eq!((inspector.state, inspector.replay), (1, Some(1)));
+
+        let _ = inspector.update(Message::Program(Increment));
+        assert_eq!((inspector.state, inspector.queue.len()), (1, 1));
+
+        replay(&mut inspector, Interaction::Replayed(0));
+        assert_eq!(inspector.state, 0);
+
+        replay(&mut inspector, Interaction::Replayed(2));
+        replay(&mut inspector, Interaction::Resumed);
+
+        assert_eq!(inspector.replay, None);
+        assert_eq!(inspector.log.len(), 3);
+        assert_eq!(inspector.state, 3);
+        assert!(inspector.queue.is_empty());
+    }
+
+    #[test]
+    fn replaying_the_latest_message_runs_queued_messages() {
+        let mut inspector = inspector(3);
+
+        replay(&mut inspector, Interaction::Replayed(1));
+
+        let _ = inspector.update(Message::Program(Increment));
+
+        replay(&mut inspector, Interaction::Replayed(3));
+
+        assert_eq!(inspector.replay, None);
+        assert_eq!(inspector.log.len(), 4);
+        assert_eq!(inspector.state, 4);
+    }
+
+    #[test]
+    fn timings_record_the_first_span_of_each_window() {
+        let main = window::Id::unique();
+        let other = window::Id::unique();
+        let start = Instant::now();
+
+        let span = |stage, window, millis| debug::Span {
+            stage,
+            window: Some(window),
+            start,
+            duration: Duration::from_millis(millis),
+        };
+
+        let mut timings = Timings::default();
+
+        timings.record(span(Stage::View, main, 1));
+        timings.record(span(Stage::View, main, 10));
+        timings.record(span(Stage::View, other, 2));
+        timings.record(span(Stage::Layout, main, 4));
+
+        assert_eq!(timings.view, Duration::from_millis(3));
+        assert_eq!(timings.layout, Duration::from_millis(4));
+        assert!(timings.has(Stage::Layout));
+        assert!(!timings.has(Stage::Draw));
+    }
+}
@@ -486,9 +486,15 @@ pub use iced_highlighter as highlighter;
 #[cfg(feature = "wgpu")]
 pub use iced_renderer::wgpu::wgpu;
 
+#[cfg(feature = "trace")]
+pub use crate::runtime::trace;
+
 mod error;
 mod program;
 
+#[cfg(feature = "debug")]
+mod inspector;
+
 pub mod application;
 pub mod daemon;
 pub mod settings;
@@ -499,15 +505,16 @@ pub mod window;
 pub mod advanced;
 
 pub use crate::core::alignment;
+pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Background, Border, Color, ContentFit, Degrees, Gradient,
-    Length, Padding, Pixels, Point, Radians, Rectangle, Rotation, Shadow, Size,
-    Theme, Transformation, Vector,
+    Alignment, Animation, Background, Border, Color, ContentFit, Degrees,
+    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Rotation,
+    Shadow, Size, Theme, Transformation, Vector,
 };
 pub use crate::runtime::exit;
 pub use iced_futures::Subscription;
@@ -62,6 +62,10 @@ pub trait Program: Sized {
         1.0
     }
 
+    fn snapshot(&self, _state: &Self::State) -> Option<Self::State> {
+        None
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -94,14 +98,20 @@ pub trait Program: Sized {
         Self: 'static,
         I: FnOnce() -> (Self::State, Task<Self::Message>) + 'static,
     {
+        #[cfg(not(feature = "debug"))]
         use std::marker::PhantomData;
 
+        #[cfg(feature = "debug")]
+        use crate::inspector::Inspector as Instance;
+
+        #[cfg(not(feature = "debug"))]
         struct Instance<P: Program, I> {
             program: P,
             state: P::State,
             _initialize: PhantomData<I>,
         }
 
+        #[cfg(not(feature = "debug"))]
         impl<P: Program, I: FnOnce() -> (P::State, Task<P::Message>)>
             shell::Program for Instance<P, I>
         {
@@ -259,6 +269,10 @@ pub fn with_title<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithTitle { program, title }
@@ -329,6 +343,10 @@ pub fn with_subscription<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithSubscription {
@@ -402,6 +420,10 @@ pub fn with_theme<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -472,6 +494,10 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithStyle { program, style: f }
@@ -542,6 +568,10 @@ pub fn with_scale_factor<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithScaleFactor {
@@ -616,6 +646,10 @@ pub fn with_executor<P: Program, E: Executor>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            self.program.snapshot(state)
+        }
     }
 
     WithExecutor {
@@ -624,6 +658,81 @@ pub fn with_executor<P: Program, E: Executor>(
     }
 }
 
+pub fn with_time_travel<P: Program>(
+    program: P,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P::State: Clone,
+{
+    struct WithTimeTravel<P> {
+        program: P,
+    }
+
+    impl<P: Program> Program for WithTimeTravel<P>
+    where
+        P::State: Clone,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn snapshot(&self, state: &Self::State) -> Option<Self::State> {
+            Some(state.clone())
+        }
+    }
+
+    WithTimeTravel { program }
+}
+
 /// The renderer of some [`Program`].
 pub trait Renderer: text::Renderer + compositor::Default {}
 
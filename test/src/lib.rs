@@ -0,0 +1,626 @@
+//! Test your `iced` applications in headless mode.
+//!
+//! The [`Simulator`] builds a user interface out of any [`Element`]—like the
+//! `view` of a [`Program`]—and lets you find widgets, click them, type text
+//! and scroll; all without a window or a display server.
+//!
+//! # Example
+//! ```
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::button;
+//! use iced_test::simulator;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! fn view<'a>() -> Element<'a, Message> {
+//!     button("Increment").on_press(Message::Increment).into()
+//! }
+//!
+//! # fn main() -> Result<(), iced_test::Error> {
+//! let mut ui = simulator(view());
+//!
+//! let _ = ui.click("Increment")?;
+//!
+//! assert_eq!(
+//!     ui.into_messages().collect::<Vec<_>>(),
+//!     vec![Message::Increment]
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Snapshots
+//! With the `tiny-skia` feature enabled, a [`Simulator`] can draw its user
+//! interface in software and compare it against a golden PNG image:
+//!
+//! ```no_run
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! # fn view<'a>() -> Element<'a, ()> { iced_widget::text("Hello").into() }
+//! # fn main() -> Result<(), iced_test::Error> {
+//! use iced_test::{simulator, Tolerance};
+//! use iced_widget::Theme;
+//!
+//! let mut ui = simulator(view());
+//!
+//! let snapshot = ui.snapshot(&Theme::Dark)?.tolerance(Tolerance::new(2, 10));
+//!
+//! assert!(snapshot.matches_image("tests/snapshots/hello.png")?);
+//! # Ok(())
+//! # }
+//! ```
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub mod selector;
+pub mod snapshot;
+
+pub use selector::{Selector, Target};
+pub use snapshot::{Snapshot, Tolerance};
+
+use crate::core::clipboard;
+use crate::core::event;
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::text;
+use crate::core::{Color, Element, Event, Font, Pixels, Point, Size, SmolStr};
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window;
+use crate::runtime::Program;
+
+use std::io;
+use std::sync::Arc;
+
+/// Creates a new [`Simulator`] of the given [`Element`].
+pub fn simulator<'a, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: Headless,
+{
+    Simulator::new(element)
+}
+
+/// Creates a new [`Simulator`] of the current view of the given [`Program`].
+///
+/// Once the interaction is simulated, the produced messages can be fed to
+/// [`Program::update`].
+pub fn program<P>(
+    program: &P,
+) -> Simulator<'_, P::Message, P::Theme, P::Renderer>
+where
+    P: Program,
+    P::Renderer: Headless,
+{
+    Simulator::new(program.view())
+}
+
+/// A user interface that can be interacted with programmatically.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<
+    'a,
+    Message,
+    Theme = core::Theme,
+    Renderer = renderer::Renderer,
+> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    scale_factor: f64,
+    background_color: Color,
+    text_color: Color,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+/// The settings of a [`Simulator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The size of the simulated window, in logical pixels.
+    pub size: Size,
+
+    /// The scale factor of the simulated window.
+    pub scale_factor: f64,
+
+    /// The default [`Font`] of the renderer.
+    pub default_font: Font,
+
+    /// The default text size of the renderer.
+    pub default_text_size: Pixels,
+
+    /// The background [`Color`] of snapshots.
+    pub background_color: Color,
+
+    /// The default text [`Color`] of the widgets.
+    pub text_color: Color,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            size: Size::new(1024.0, 768.0),
+            scale_factor: 1.0,
+            default_font: Font::default(),
+            default_text_size: Pixels(16.0),
+            background_color: Color::WHITE,
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: Headless,
+{
+    /// Creates a new [`Simulator`] of the given [`Element`] with the
+    /// default [`Settings`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(Settings::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] of the given [`Element`] with the
+    /// given [`Settings`].
+    pub fn with_settings(
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut renderer =
+            Renderer::new(settings.default_font, settings.default_text_size);
+
+        let raw = UserInterface::build(
+            element,
+            settings.size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            size: settings.size,
+            scale_factor: settings.scale_factor,
+            background_color: settings.background_color,
+            text_color: settings.text_color,
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let mut finder = selector::Finder::new(&selector);
+
+        self.raw.operate(&self.renderer, &mut finder);
+
+        finder.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let _ = self.simulate([Event::Mouse(mouse::Event::CursorMoved {
+            position: position.into(),
+        })]);
+    }
+
+    /// Clicks the center of the first widget matching the given
+    /// [`Selector`].
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Scrolls the given amount over the first widget matching the given
+    /// [`Selector`].
+    pub fn scroll(
+        &mut self,
+        selector: impl Into<Selector>,
+        delta: mouse::ScrollDelta,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::WheelScrolled { delta })]);
+
+        Ok(target)
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        let key = key.into();
+
+        let text = match &key {
+            keyboard::Key::Character(c) => Some(c.clone()),
+            keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                Some(SmolStr::new("\n"))
+            }
+            keyboard::Key::Named(keyboard::key::Named::Space) => {
+                Some(SmolStr::new(" "))
+            }
+            _ => None,
+        };
+
+        self.simulate(tap(key, text))
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        let events: Vec<_> = text
+            .chars()
+            .flat_map(|c| {
+                let text = SmolStr::new(c.to_string());
+
+                tap(keyboard::Key::Character(text.clone()), Some(text))
+            })
+            .collect();
+
+        self.simulate(events)
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Feeds the given events to the user interface, one by one, and
+    /// returns their [`event::Status`].
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        events
+            .into_iter()
+            .flat_map(|event| {
+                if let Event::Mouse(mouse::Event::CursorMoved { position }) =
+                    event
+                {
+                    self.cursor = mouse::Cursor::Available(position);
+                }
+
+                let (_state, statuses) = self.raw.update(
+                    &[event],
+                    self.cursor,
+                    &mut self.renderer,
+                    &mut clipboard::Null,
+                    &mut self.messages,
+                );
+
+                statuses
+            })
+            .collect()
+    }
+
+    /// Draws the user interface and returns a [`Snapshot`] of its pixels.
+    ///
+    /// Fails if the simulated window is empty or if the renderer cannot
+    /// draw offscreen.
+    pub fn snapshot(&mut self, theme: &Theme) -> Result<Snapshot, Error> {
+        let _ = self.raw.draw(
+            &mut self.renderer,
+            theme,
+            &core::renderer::Style {
+                text_color: self.text_color,
+            },
+            self.cursor,
+        );
+
+        let scale_factor = self.scale_factor as f32;
+
+        let size = Size::new(
+            (self.size.width * scale_factor).round() as u32,
+            (self.size.height * scale_factor).round() as u32,
+        );
+
+        if size.width == 0 || size.height == 0 {
+            return Err(Error::EmptySnapshot(size));
+        }
+
+        let rgba = self
+            .renderer
+            .screenshot(size, self.scale_factor, self.background_color)
+            .ok_or(Error::SnapshotUnsupported)?;
+
+        Ok(Snapshot::new(window::Screenshot::new(
+            rgba,
+            size,
+            self.scale_factor,
+        )))
+    }
+
+    /// Returns the messages produced by the interactions so far.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+}
+
+fn tap(key: keyboard::Key, text: Option<SmolStr>) -> [Event; 2] {
+    [
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text,
+        }),
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+        }),
+    ]
+}
+
+/// A renderer that can be used without a window.
+pub trait Headless: text::Renderer {
+    /// Creates a new renderer with the given default font and text size.
+    fn new(default_font: Font, default_text_size: Pixels) -> Self;
+
+    /// Draws the current frame offscreen with the given physical size and
+    /// scale factor, and returns its RGBA pixels.
+    ///
+    /// Returns `None` if the renderer cannot draw offscreen with the given
+    /// size.
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f64,
+        background_color: Color,
+    ) -> Option<Vec<u8>>;
+}
+
+impl Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Self {}
+
+    fn screenshot(
+        &mut self,
+        _size: Size<u32>,
+        _scale_factor: f64,
+        _background_color: Color,
+    ) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+#[cfg(feature = "tiny-skia")]
+impl Headless for iced_tiny_skia::Renderer {
+    fn new(default_font: Font, default_text_size: Pixels) -> Self {
+        Self::new(default_font, default_text_size)
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f64,
+        background_color: Color,
+    ) -> Option<Vec<u8>> {
+        let viewport = renderer::graphics::Viewport::with_physical_size(
+            size,
+            scale_factor,
+        );
+
+        self.screenshot(&viewport, background_color)
+    }
+}
+
+impl<A, B> Headless for renderer::fallback::Renderer<A, B>
+where
+    A: text::Renderer,
+    B: Headless<Font = A::Font, Paragraph = A::Paragraph, Editor = A::Editor>,
+{
+    fn new(default_font: Font, default_text_size: Pixels) -> Self {
+        Self::Secondary(B::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f64,
+        background_color: Color,
+    ) -> Option<Vec<u8>> {
+        match self {
+            Self::Primary(_) => None,
+            Self::Secondary(renderer) => {
+                renderer.screenshot(size, scale_factor, background_color)
+            }
+        }
+    }
+}
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matched the [`Selector`].
+    #[error("no widget matched the selector: {0:?}")]
+    NotFound(Selector),
+    /// The renderer cannot draw snapshots.
+    #[error("the renderer cannot draw snapshots")]
+    SnapshotUnsupported,
+    /// The simulated window has no pixels to draw.
+    #[error("cannot draw a snapshot of an empty window: {0:?}")]
+    EmptySnapshot(Size<u32>),
+    /// An I/O operation failed.
+    #[error("io operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+    /// A PNG image could not be decoded.
+    #[error("png decoding failed: {0}")]
+    PngDecodingFailed(Arc<png::DecodingError>),
+    /// A PNG image could not be encoded.
+    #[error("png encoding failed: {0}")]
+    PngEncodingFailed(Arc<png::EncodingError>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<png::DecodingError> for Error {
+    fn from(error: png::DecodingError) -> Self {
+        Self::PngDecodingFailed(Arc::new(error))
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodingFailed(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::Id;
+
+    use iced_widget::{button, column, scrollable, text, text_input, Space};
+
+    type Element<'a> = crate::core::Element<
+        'a,
+        Message,
+        iced_widget::Theme,
+        iced_widget::Renderer,
+    >;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        Decrement,
+        Input(String),
+    }
+
+    fn counter<'a>() -> Element<'a> {
+        column![
+            button("Increment").on_press(Message::Increment),
+            text("0"),
+            button("Decrement").on_press(Message::Decrement),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn clicks_buttons() -> Result<(), Error> {
+        let mut ui = simulator(counter());
+
+        let _ = ui.click("Decrement")?;
+        let _ = ui.click("Increment")?;
+        let _ = ui.click("Decrement")?;
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Decrement, Message::Increment, Message::Decrement]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn finds_widgets() -> Result<(), Error> {
+        let mut ui = simulator(counter());
+
+        let increment = ui.find("Increment")?;
+        let count = ui.find("0")?;
+        let decrement = ui.find("Decrement")?;
+
+        assert!(increment.bounds.y < count.bounds.y);
+        assert!(count.bounds.y < decrement.bounds.y);
+
+        assert!(matches!(
+            ui.find("Reset"),
+            Err(Error::NotFound(Selector::Text(text))) if text == "Reset"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn finds_scrolled_widgets() -> Result<(), Error> {
+        let view: Element<'_> = scrollable(column![
+            Space::with_height(2000),
+            button("Bottom").on_press(Message::Increment),
+        ])
+        .id(scrollable::Id::new("scrollable"))
+        .into();
+
+        let mut ui = simulator(view);
+
+        let before = ui.find("Bottom")?;
+
+        let _ = ui.scroll(
+            Id::new("scrollable"),
+            mouse::ScrollDelta::Pixels { x: 0.0, y: -500.0 },
+        )?;
+
+        let after = ui.find("Bottom")?;
+
+        assert_eq!(after.bounds.y, before.bounds.y - 500.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn types_text() -> Result<(), Error> {
+        let view: Element<'_> = text_input("Name", "")
+            .id(text_input::Id::new("name"))
+            .on_input(Message::Input)
+            .into();
+
+        let mut ui = simulator(view);
+
+        assert_eq!(ui.typewrite("ab"), event::Status::Ignored);
+
+        let _ = ui.click(Id::new("name"))?;
+
+        assert_eq!(ui.typewrite("ab"), event::Status::Captured);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Input("a".into()), Message::Input("ab".into())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_snapshots_fail() {
+        let mut ui = Simulator::with_settings(
+            Settings {
+                size: Size::ZERO,
+                ..Settings::default()
+            },
+            counter(),
+        );
+
+        assert!(matches!(
+            ui.snapshot(&iced_widget::Theme::Light),
+            Err(Error::EmptySnapshot(Size {
+                width: 0,
+                height: 0
+            }))
+        ));
+    }
+}
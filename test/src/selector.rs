@@ -0,0 +1,142 @@
+//! Find widgets in a user interface.
+use crate::core::accessibility::{self, Value};
+use crate::core::widget::operation::{Operation, Scrollable};
+use crate::core::widget::Id;
+use crate::core::{Rectangle, Vector};
+
+use std::borrow::Cow;
+
+/// A description of the widgets to find in a user interface.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// Find the widget with the given [`Id`].
+    Id(Id),
+    /// Find the first widget showing the given text.
+    ///
+    /// This matches the labels and text values of widgets.
+    Text(Cow<'static, str>),
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&'static str> for Selector {
+    fn from(text: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(Cow::Owned(text))
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The absolute bounds of the widget.
+    pub bounds: Rectangle,
+}
+
+/// An [`Operation`] that finds the first widget matching a [`Selector`].
+#[derive(Debug)]
+pub(crate) struct Finder<'a> {
+    selector: &'a Selector,
+    offset: Vector,
+    translation: Option<Vector>,
+    target: Option<Target>,
+}
+
+impl<'a> Finder<'a> {
+    pub fn new(selector: &'a Selector) -> Self {
+        Self {
+            selector,
+            offset: Vector::ZERO,
+            translation: None,
+            target: None,
+        }
+    }
+
+    pub fn target(&self) -> Option<Target> {
+        self.target
+    }
+
+    fn select(&mut self, bounds: Rectangle) {
+        self.target = Some(Target {
+            bounds: bounds + self.offset,
+        });
+    }
+}
+
+impl Operation for Finder<'_> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        let translation = self.translation.take();
+
+        if self.target.is_some() {
+            return;
+        }
+
+        if let (Selector::Id(target), Some(id)) = (self.selector, id) {
+            if target == id {
+                self.select(bounds);
+                return;
+            }
+        }
+
+        if let Some(translation) = translation {
+            self.offset = self.offset - translation;
+        }
+
+        operate_on_children(self);
+
+        if let Some(translation) = translation {
+            self.offset = self.offset + translation;
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.translation = Some(translation);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+    ) {
+        if self.target.is_some() {
+            return;
+        }
+
+        let is_match = match self.selector {
+            Selector::Id(target) => id == Some(target),
+            Selector::Text(text) => {
+                node.label.as_deref() == Some(text)
+                    || matches!(
+                        &node.value,
+                        Some(Value::Text(value)) if value == text
+                    )
+            }
+        };
+
+        if is_match {
+            self.select(bounds);
+        }
+    }
+}
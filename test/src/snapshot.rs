@@ -0,0 +1,270 @@
+//! Compare the pixels of a user interface against golden images.
+use crate::runtime::window::Screenshot;
+use crate::Error;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The pixels of a user interface drawn by a [`Simulator`].
+///
+/// [`Simulator`]: crate::Simulator
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+    tolerance: Tolerance,
+}
+
+impl Snapshot {
+    pub(crate) fn new(screenshot: Screenshot) -> Self {
+        Self {
+            screenshot,
+            tolerance: Tolerance::default(),
+        }
+    }
+
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Sets the [`Tolerance`] used when comparing the [`Snapshot`].
+    pub fn tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Compares the [`Snapshot`] against the PNG image at the given path.
+    ///
+    /// If the image does not exist, the [`Snapshot`] does not match. Set the
+    /// `ICED_TEST_UPDATE` environment variable to save the [`Snapshot`] there
+    /// instead and consider it a match.
+    ///
+    /// On a mismatch, an image highlighting the differing pixels is written
+    /// next to the original one with a `.diff.png` extension. If the sizes
+    /// differ, the diff image is the [`Snapshot`] itself. Any stale diff image
+    /// is removed on a match.
+    pub fn matches_image(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        self.compare(
+            path.as_ref(),
+            std::env::var_os("ICED_TEST_UPDATE").is_some(),
+        )
+    }
+
+    fn compare(&self, path: &Path, is_updating: bool) -> Result<bool, Error> {
+        if !path.exists() {
+            if !is_updating {
+                return Ok(false);
+            }
+
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        let (size, expected) = load(path)?;
+        let actual = &self.screenshot;
+
+        let diff_path = path.with_extension("diff.png");
+
+        if size != (actual.size.width, actual.size.height) {
+            self.save(&diff_path)?;
+
+            return Ok(false);
+        }
+
+        let (differences, diff) = compare(
+            &expected,
+            &actual.bytes,
+            self.tolerance.channel_difference,
+        );
+
+        if differences <= self.tolerance.pixels {
+            match fs::remove_file(&diff_path) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => {
+                    return Err(error.into());
+                }
+                _ => {}
+            }
+
+            return Ok(true);
+        }
+
+        save(&diff_path, actual.size.width, actual.size.height, &diff)?;
+
+        Ok(false)
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        save(
+            path.as_ref(),
+            self.screenshot.size.width,
+            self.screenshot.size.height,
+            &self.screenshot.bytes,
+        )
+    }
+}
+
+/// The allowed difference between a [`Snapshot`] and a golden image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tolerance {
+    /// The maximum difference of any color channel for two pixels to be
+    /// considered equal.
+    pub channel_difference: u8,
+
+    /// The maximum amount of differing pixels.
+    pub pixels: usize,
+}
+
+impl Tolerance {
+    /// No differences are allowed.
+    pub const EXACT: Self = Self {
+        channel_difference: 0,
+        pixels: 0,
+    };
+
+    /// Creates a new [`Tolerance`] with the given channel difference and
+    /// maximum amount of differing pixels.
+    pub const fn new(channel_difference: u8, pixels: usize) -> Self {
+        Self {
+            channel_difference,
+            pixels,
+        }
+    }
+}
+
+/// Compares two RGBA buffers of the same size and returns the amount of
+/// differing pixels together with a diff image.
+///
+/// Differing pixels are painted red, while equal pixels are faded.
+fn compare(expected: &[u8], actual: &[u8], tolerance: u8) -> (usize, Vec<u8>) {
+    let mut differences = 0;
+
+    let diff = expected
+        .chunks_exact(4)
+        .zip(actual.chunks_exact(4))
+        .flat_map(|(a, b)| {
+            let is_different =
+                a.iter().zip(b).any(|(a, b)| a.abs_diff(*b) > tolerance);
+
+            if is_different {
+                differences += 1;
+
+                [255, 0, 0, 255]
+            } else {
+                [a[0], a[1], a[2], a[3] / 4]
+            }
+        })
+        .collect();
+
+    (differences, diff)
+}
+
+fn load(path: &Path) -> Result<((u32, u32), Vec<u8>), Error> {
+    let file = io::BufReader::new(fs::File::open(path)?);
+
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    buffer.truncate(info.buffer_size());
+
+    let rgba = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+            .collect(),
+        png::ColorType::Grayscale | png::ColorType::Indexed => buffer
+            .iter()
+            .flat_map(|luma| [*luma, *luma, *luma, 255])
+            .collect(),
+    };
+
+    Ok(((info.width, info.height), rgba))
+}
+
+fn save(
+    path: &Path,
+    width: u32,
+    height: u32,
+    rgba: &[u8],
+) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let file = io::BufWriter::new(fs::File::create(path)?);
+
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_respects_channel_tolerance() {
+        let expected = [10, 20, 30, 255, 0, 0, 0, 255];
+        let actual = [12, 20, 30, 255, 0, 0, 100, 255];
+
+        let (differences, diff) = compare(&expected, &actual, 2);
+
+        assert_eq!(differences, 1);
+        assert_eq!(&diff[..4], &[10, 20, 30, 63]);
+        assert_eq!(&diff[4..], &[255, 0, 0, 255]);
+
+        let (differences, _) = compare(&expected, &actual, 100);
+
+        assert_eq!(differences, 0);
+    }
+
+    #[test]
+    fn goldens_are_only_written_on_update() {
+        let directory = std::env::temp_dir()
+            .join(format!("iced_test_snapshot_{}", std::process::id()));
+
+        let golden = directory.join("golden.png");
+        let diff = directory.join("golden.diff.png");
+
+        let snapshot = |pixel: [u8; 4]| {
+            Snapshot::new(Screenshot::new(
+                pixel.to_vec(),
+                crate::core::Size::new(1, 1),
+                1.0,
+            ))
+        };
+
+        let red = snapshot([255, 0, 0, 255]);
+        let blue = snapshot([0, 0, 255, 255]);
+
+        assert!(!red.compare(&golden, false).unwrap());
+        assert!(!golden.exists());
+
+        assert!(red.compare(&golden, true).unwrap());
+        assert!(golden.exists());
+
+        assert!(!blue.compare(&golden, false).unwrap());
+        assert!(diff.exists());
+
+        assert!(red.compare(&golden, false).unwrap());
+        assert!(!diff.exists());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
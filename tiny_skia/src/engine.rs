@@ -2,10 +2,13 @@ use crate::core::renderer::Quad;
 use crate::core::{
     Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
 };
-use crate::graphics::{Image, Text};
+use crate::gradient;
+use crate::graphics::{self, Image, Text};
 use crate::text;
 use crate::Primitive;
 
+use std::borrow::Cow;
+
 #[derive(Debug)]
 pub struct Engine {
     text_pipeline: text::Pipeline,
@@ -153,6 +156,24 @@ impl Engine {
             }
         }
 
+        let pattern = match background {
+            Background::Gradient(Gradient::Conic(conic)) => {
+                gradient::Pattern::conic(
+                    &graphics::gradient::Conic {
+                        center: graphics::gradient::absolute_center(
+                            conic.center,
+                            quad.bounds,
+                        ),
+                        angle: conic.angle,
+                        stops: conic.stops,
+                    },
+                    quad.bounds,
+                    transformation.scale_factor(),
+                )
+            }
+            _ => None,
+        };
+
         pixels.fill_path(
             &path,
             &tiny_skia::Paint {
@@ -201,6 +222,39 @@ impl Engine {
                         )
                         .expect("Create linear gradient")
                     }
+                    Background::Gradient(Gradient::Radial(radial)) => {
+                        let center = graphics::gradient::absolute_center(
+                            radial.center,
+                            quad.bounds,
+                        );
+
+                        gradient::radial(
+                            center,
+                            radial.radius
+                                * graphics::gradient::farthest_corner(
+                                    center,
+                                    quad.bounds,
+                                ),
+                            &radial.stops,
+                        )
+                    }
+                    Background::Gradient(Gradient::Conic(conic)) => {
+                        pattern.as_ref().map_or_else(
+                            || {
+                                tiny_skia::Shader::SolidColor(into_color(
+                                    conic
+                                        .stops
+                                        .iter()
+                                        .flatten()
+                                        .next()
+                                        .map_or(Color::BLACK, |stop| {
+                                            stop.color
+                                        }),
+                                ))
+                            },
+                            gradient::Pattern::shader,
+                        )
+                    }
                 },
                 anti_alias: true,
                 ..tiny_skia::Paint::default()
@@ -475,7 +529,12 @@ impl Engine {
         layer_bounds: Rectangle,
     ) {
         match primitive {
-            Primitive::Fill { path, paint, rule } => {
+            Primitive::Fill {
+                path,
+                paint,
+                rule,
+                conic,
+            } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
 
@@ -496,9 +555,24 @@ impl Engine {
                 let clip_mask =
                     (physical_bounds != clip_bounds).then_some(clip_mask as &_);
 
+                let pattern = conic.as_ref().and_then(|conic| {
+                    let bounds = path.bounds();
+
+                    gradient::Pattern::conic(
+                        conic,
+                        Rectangle {
+                            x: bounds.x(),
+                            y: bounds.y(),
+                            width: bounds.width(),
+                            height: bounds.height(),
+                        },
+                        transformation.scale_factor(),
+                    )
+                });
+
                 pixels.fill_path(
                     path,
-                    paint,
+                    &with_pattern(paint, pattern.as_ref()),
                     *rule,
                     into_transform(transformation),
                     clip_mask,
@@ -508,6 +582,7 @@ impl Engine {
                 path,
                 paint,
                 stroke,
+                conic,
             } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
@@ -529,9 +604,25 @@ impl Engine {
                 let clip_mask =
                     (physical_bounds != clip_bounds).then_some(clip_mask as &_);
 
+                let pattern = conic.as_ref().and_then(|conic| {
+                    let bounds = path.bounds();
+                    let padding = stroke.width / 2.0;
+
+                    gradient::Pattern::conic(
+                        conic,
+                        Rectangle {
+                            x: bounds.x() - padding,
+                            y: bounds.y() - padding,
+                            width: bounds.width() + padding * 2.0,
+                            height: bounds.height() + padding * 2.0,
+                        },
+                        transformation.scale_factor(),
+                    )
+                });
+
                 pixels.stroke_path(
                     path,
-                    paint,
+                    &with_pattern(paint, pattern.as_ref()),
                     stroke,
                     into_transform(transformation),
                     clip_mask,
@@ -640,6 +731,19 @@ pub fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
+fn with_pattern<'a>(
+    paint: &'a tiny_skia::Paint<'static>,
+    pattern: Option<&'a gradient::Pattern>,
+) -> Cow<'a, tiny_skia::Paint<'a>> {
+    match pattern {
+        Some(pattern) => Cow::Owned(tiny_skia::Paint {
+            shader: pattern.shader(),
+            ..paint.clone()
+        }),
+        None => Cow::Borrowed(paint),
+    }
+}
+
 fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
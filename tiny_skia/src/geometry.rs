@@ -1,5 +1,6 @@
 use crate::core::text::LineHeight;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::engine::into_color;
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
@@ -118,6 +119,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: into_conic(fill.style, self.transform),
         });
     }
 
@@ -145,6 +147,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: into_conic(fill.style, self.transform),
         });
     }
 
@@ -165,6 +168,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             stroke: skia_stroke,
+            conic: into_conic(stroke.style, self.transform),
         });
     }
 
@@ -456,6 +460,22 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                     )
                     .expect("Create linear gradient")
                 }
+                Gradient::Radial(radial) => crate::gradient::radial(
+                    radial.center,
+                    radial.radius,
+                    &radial.stops,
+                ),
+                Gradient::Conic(conic) => {
+                    // Conic gradients are rasterized when drawn; see `into_conic`
+                    tiny_skia::Shader::SolidColor(into_color(
+                        conic
+                            .stops
+                            .iter()
+                            .flatten()
+                            .next()
+                            .map_or(core::Color::BLACK, |stop| stop.color),
+                    ))
+                }
             },
         },
         anti_alias: true,
@@ -463,6 +483,30 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
     }
 }
 
+/// Returns the conic gradient of the [`Style`], if any, transformed
+/// with the given [`tiny_skia::Transform`].
+pub fn into_conic(
+    style: Style,
+    transform: tiny_skia::Transform,
+) -> Option<graphics::gradient::Conic> {
+    let Style::Gradient(Gradient::Conic(conic)) = style else {
+        return None;
+    };
+
+    let mut center = [tiny_skia::Point {
+        x: conic.center.x,
+        y: conic.center.y,
+    }];
+
+    transform.map_points(&mut center);
+
+    Some(graphics::gradient::Conic {
+        center: Point::new(center[0].x, center[0].y),
+        angle: conic.angle + Radians(transform.ky.atan2(transform.sx)),
+        stops: conic.stops,
+    })
+}
+
 pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
     match rule {
         fill::Rule::EvenOdd => tiny_skia::FillRule::EvenOdd,
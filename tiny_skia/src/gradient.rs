@@ -0,0 +1,144 @@
+use crate::core::gradient::ColorStop;
+use crate::core::{Color, Point, Rectangle};
+use crate::engine::into_color;
+use crate::graphics::gradient::Conic;
+
+/// Creates a radial gradient shader.
+pub fn radial(
+    center: Point,
+    radius: f32,
+    stops: &[Option<ColorStop>; 8],
+) -> tiny_skia::Shader<'static> {
+    let center = tiny_skia::Point {
+        x: center.x,
+        y: center.y,
+    };
+
+    tiny_skia::RadialGradient::new(
+        center,
+        center,
+        radius,
+        into_stops(stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::identity(),
+    )
+    .unwrap_or_else(|| {
+        // The radius is too small to be visible; only the last stop shows
+        tiny_skia::Shader::SolidColor(into_color(
+            stops
+                .iter()
+                .flatten()
+                .last()
+                .map_or(Color::BLACK, |stop| stop.color),
+        ))
+    })
+}
+
+/// A rasterized [`Conic`] gradient.
+///
+/// `tiny-skia` does not support sweep gradients, so we rasterize them
+/// and draw them as a pattern instead.
+#[derive(Debug)]
+pub struct Pattern {
+    pixmap: tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+}
+
+impl Pattern {
+    /// Rasterizes the [`Conic`] gradient inside the given bounds, with the
+    /// given scale factor.
+    pub fn conic(conic: &Conic, bounds: Rectangle, scale: f32) -> Option<Self> {
+        let width = (bounds.width * scale).ceil().max(1.0) as u32;
+        let height = (bounds.height * scale).ceil().max(1.0) as u32;
+
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x as f32, y as f32)))
+            .map(|(x, y)| {
+                let offset = conic.offset(Point::new(
+                    bounds.x + (x + 0.5) / scale,
+                    bounds.y + (y + 0.5) / scale,
+                ));
+
+                into_color(interpolate(&conic.stops, offset))
+                    .to_color_u8()
+                    .premultiply()
+            })
+            .collect();
+
+        let pixmap =
+            tiny_skia::IntSize::from_wh(width, height).and_then(|size| {
+                tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(pixels), size)
+            })?;
+
+        Some(Self {
+            pixmap,
+            transform: tiny_skia::Transform::from_row(
+                1.0 / scale,
+                0.0,
+                0.0,
+                1.0 / scale,
+                bounds.x,
+                bounds.y,
+            ),
+        })
+    }
+
+    /// Returns a shader which draws the [`Pattern`].
+    pub fn shader(&self) -> tiny_skia::Shader<'_> {
+        tiny_skia::Pattern::new(
+            self.pixmap.as_ref(),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::FilterQuality::Bilinear,
+            1.0,
+            self.transform,
+        )
+    }
+}
+
+fn into_stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<_> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(stop.offset, into_color(stop.color))
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+fn interpolate(stops: &[Option<ColorStop>; 8], offset: f32) -> Color {
+    let mut previous: Option<&ColorStop> = None;
+
+    for stop in stops.iter().flatten() {
+        if offset <= stop.offset {
+            let Some(previous) = previous else {
+                return stop.color;
+            };
+
+            let range = stop.offset - previous.offset;
+
+            if range <= f32::EPSILON {
+                return stop.color;
+            }
+
+            let t = (offset - previous.offset) / range;
+            let (a, b) = (previous.color, stop.color);
+
+            return Color {
+                r: a.r + (b.r - a.r) * t,
+                g: a.g + (b.g - a.g) * t,
+                b: a.b + (b.b - a.b) * t,
+                a: a.a + (b.a - a.a) * t,
+            };
+        }
+
+        previous = Some(stop);
+    }
+
+    previous.map_or(Color::BLACK, |stop| stop.color)
+}
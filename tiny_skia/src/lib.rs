@@ -3,6 +3,7 @@
 pub mod window;
 
 mod engine;
+mod gradient;
 mod layer;
 mod primitive;
 mod settings;
@@ -29,7 +30,7 @@ pub use geometry::Geometry;
 
 use crate::core::renderer;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Transformation,
+    Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
 use crate::engine::Engine;
 use crate::graphics::compositor;
@@ -63,6 +64,45 @@ impl Renderer {
         self.layers.as_slice()
     }
 
+    /// Draws the current layers offscreen and returns their RGBA pixels.
+    ///
+    /// This does not need a window, which makes it useful for headless
+    /// rendering.
+    ///
+    /// Returns `None` if the pixels of the [`Viewport`] cannot be allocated;
+    /// for instance, when it is empty.
+    pub fn screenshot(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Option<Vec<u8>> {
+        let size = viewport.physical_size();
+
+        let mut pixmap = tiny_skia::Pixmap::new(size.width, size.height)?;
+        let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)?;
+
+        self.draw(
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            viewport,
+            &[Rectangle::with_size(Size::new(
+                size.width as f32,
+                size.height as f32,
+            ))],
+            background_color,
+            &[] as &[&str],
+        );
+
+        // Colors are stored in BGRA order; see `engine::into_color`
+        Some(
+            pixmap
+                .data()
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+                .collect(),
+        )
+    }
+
     pub fn draw<T: AsRef<str>>(
         &mut self,
         pixels: &mut tiny_skia::PixmapMut<'_>,
@@ -243,13 +283,27 @@ impl core::Renderer for Renderer {
         self.layers.pop_transformation();
     }
 
+    fn start_opacity(&mut self, opacity: f32) {
+        self.layers.push_opacity(opacity);
+    }
+
+    fn end_opacity(&mut self) {
+        self.layers.pop_opacity();
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
         background: impl Into<Background>,
     ) {
+        let opacity = self.layers.opacity();
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+
+        layer.draw_quad(
+            quad.scale_alpha(opacity),
+            background.into().scale_alpha(opacity),
+            transformation,
+        );
     }
 
     fn clear(&mut self) {
@@ -281,6 +335,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
 
         layer.draw_paragraph(
@@ -299,6 +354,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_editor(editor, position, color, clip_bounds, transformation);
     }
@@ -310,6 +366,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_text(text, position, color, clip_bounds, transformation);
     }
@@ -382,6 +439,10 @@ impl core::image::Renderer for Renderer {
     }
 
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle) {
+        let image = core::Image {
+            opacity: image.opacity * self.layers.opacity(),
+            ..image
+        };
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, transformation);
     }
@@ -397,6 +458,10 @@ impl core::svg::Renderer for Renderer {
     }
 
     fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle) {
+        let svg = core::Svg {
+            opacity: svg.opacity * self.layers.opacity(),
+            ..svg
+        };
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(svg, bounds, transformation);
     }
@@ -597,6 +597,14 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                radial.center = self.transform_point(radial.center);
+                radial.radius *= self.0.m11.hypot(self.0.m12);
+            }
+            Gradient::Conic(conic) => {
+                conic.center = self.transform_point(conic.center);
+                conic.angle += Radians(self.0.m12.atan2(self.0.m11));
+            }
         }
 
         gradient
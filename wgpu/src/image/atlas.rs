@@ -103,6 +103,15 @@ impl Atlas {
         height: u32,
         data: &[u8],
     ) -> Option<Entry> {
+        #[cfg(feature = "trace")]
+        let _span = tracing::debug_span!(
+            "atlas upload",
+            width,
+            height,
+            bytes = data.len()
+        )
+        .entered();
+
         let entry = {
             let current_size = self.layers.len();
             let entry = self.allocate(width, height)?;
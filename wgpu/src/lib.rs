@@ -147,6 +147,10 @@ impl Renderer {
 
         for layer in self.layers.iter_mut() {
             if !layer.quads.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("quad", stage = "prepare").entered();
+
                 engine.quad_pipeline.prepare(
                     device,
                     encoder,
@@ -158,6 +162,10 @@ impl Renderer {
             }
 
             if !layer.triangles.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span = tracing::debug_span!("triangle", stage = "prepare")
+                    .entered();
+
                 engine.triangle_pipeline.prepare(
                     device,
                     encoder,
@@ -170,6 +178,11 @@ impl Renderer {
             }
 
             if !layer.primitives.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("primitive", stage = "prepare")
+                        .entered();
+
                 for instance in &layer.primitives {
                     instance.primitive.prepare(
                         device,
@@ -184,6 +197,10 @@ impl Renderer {
 
             #[cfg(any(feature = "svg", feature = "image"))]
             if !layer.images.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("image", stage = "prepare").entered();
+
                 engine.image_pipeline.prepare(
                     device,
                     encoder,
@@ -196,6 +213,10 @@ impl Renderer {
             }
 
             if !layer.text.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("text", stage = "prepare").entered();
+
                 engine.text_pipeline.prepare(
                     device,
                     queue,
@@ -210,6 +231,11 @@ impl Renderer {
         }
     }
 
+    /// Encodes the render passes of every layer.
+    ///
+    /// With the `trace` feature, every kind of primitive is encoded inside a
+    /// `"render"` span. These spans measure the CPU-side encoding of the
+    /// commands, not the time the GPU takes to execute them.
     fn render(
         &mut self,
         engine: &mut Engine,
@@ -279,6 +305,10 @@ impl Renderer {
             };
 
             if !layer.quads.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("quad", stage = "render").entered();
+
                 engine.quad_pipeline.render(
                     quad_layer,
                     scissor_rect,
@@ -290,6 +320,10 @@ impl Renderer {
             }
 
             if !layer.triangles.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span = tracing::debug_span!("triangle", stage = "render")
+                    .entered();
+
                 let _ = ManuallyDrop::into_inner(render_pass);
 
                 mesh_layer += engine.triangle_pipeline.render(
@@ -323,6 +357,10 @@ impl Renderer {
             }
 
             if !layer.primitives.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span = tracing::debug_span!("primitive", stage = "render")
+                    .entered();
+
                 let _ = ManuallyDrop::into_inner(render_pass);
 
                 for instance in &layer.primitives {
@@ -361,6 +399,10 @@ impl Renderer {
 
             #[cfg(any(feature = "svg", feature = "image"))]
             if !layer.images.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("image", stage = "render").entered();
+
                 engine.image_pipeline.render(
                     &image_cache,
                     image_layer,
@@ -372,6 +414,10 @@ impl Renderer {
             }
 
             if !layer.text.is_empty() {
+                #[cfg(feature = "trace")]
+                let _span =
+                    tracing::debug_span!("text", stage = "render").entered();
+
                 text_layer += engine.text_pipeline.render(
                     &self.text_viewport,
                     &self.text_storage,
@@ -448,13 +494,27 @@ impl core::Renderer for Renderer {
         self.layers.pop_transformation();
     }
 
+    fn start_opacity(&mut self, opacity: f32) {
+        self.layers.push_opacity(opacity);
+    }
+
+    fn end_opacity(&mut self) {
+        self.layers.pop_opacity();
+    }
+
     fn fill_quad(
         &mut self,
         quad: core::renderer::Quad,
         background: impl Into<Background>,
     ) {
+        let opacity = self.layers.opacity();
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+
+        layer.draw_quad(
+            quad.scale_alpha(opacity),
+            background.into().scale_alpha(opacity),
+            transformation,
+        );
     }
 
     fn clear(&mut self) {
@@ -486,6 +546,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
 
         layer.draw_paragraph(
@@ -504,6 +565,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_editor(editor, position, color, clip_bounds, transformation);
     }
@@ -515,6 +577,7 @@ impl core::text::Renderer for Renderer {
         color: Color,
         clip_bounds: Rectangle,
     ) {
+        let color = color.scale_alpha(self.layers.opacity());
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_text(text, position, color, clip_bounds, transformation);
     }
@@ -529,6 +592,10 @@ impl core::image::Renderer for Renderer {
     }
 
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle) {
+        let image = core::Image {
+            opacity: image.opacity * self.layers.opacity(),
+            ..image
+        };
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, transformation);
     }
@@ -541,6 +608,10 @@ impl core::svg::Renderer for Renderer {
     }
 
     fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle) {
+        let svg = core::Svg {
+            opacity: svg.opacity * self.layers.opacity(),
+            ..svg
+        };
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(svg, bounds, transformation);
     }
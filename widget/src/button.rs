@@ -16,6 +16,8 @@
 //!     button("Press me!").on_press(Message::ButtonPressed).into()
 //! }
 //! ```
+use crate::core::accessibility;
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::layout;
@@ -23,9 +25,11 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
     Shadow, Shell, Size, Theme, Vector, Widget,
@@ -197,9 +201,36 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone)]
 struct State {
     is_pressed: bool,
+    status: Option<Animation<Status>>,
+    now: Instant,
+}
+
+impl<'a, Message, Theme, Renderer> Button<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Theme: Catalog,
+{
+    fn status(
+        &self,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if cursor.is_over(layout.bounds()) {
+            if state.is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -214,7 +245,11 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::default())
+        tree::State::new(State {
+            is_pressed: false,
+            status: None,
+            now: Instant::now(),
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -260,6 +295,13 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Button)
+                .disabled(self.on_press.is_none()),
+        );
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -333,6 +375,21 @@ where
 
                 state.is_pressed = false;
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let status = self.status(state, layout, cursor);
+
+                state.now = now;
+
+                let animation =
+                    state.status.get_or_insert_with(|| Animation::new(status));
+
+                animation.go_mut(status, now);
+
+                if let Some(request) = animation.redraw_request(now) {
+                    shell.request_redraw(request);
+                }
+            }
             _ => {}
         }
 
@@ -351,24 +408,18 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let is_mouse_over = cursor.is_over(bounds);
-
-        let status = if self.on_press.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            let state = tree.state.downcast_ref::<State>();
-
-            if state.is_pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
-            }
-        } else {
-            Status::Active
+        let state = tree.state.downcast_ref::<State>();
+        let status = self.status(state, layout, cursor);
+
+        let style = match &state.status {
+            Some(animation) if *animation.target() == status => animation
+                .interpolate_with(
+                    |status| theme.style(&self.class, *status),
+                    state.now,
+                ),
+            _ => theme.style(&self.class, status),
         };
 
-        let style = theme.style(&self.class, status);
-
         if style.background.is_some()
             || style.border.width > 0.0
             || style.shadow.color.a > 0.0
@@ -483,6 +534,17 @@ pub struct Style {
     pub shadow: Shadow,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Style {
+            background: self.background.interpolate(&other.background, amount),
+            text_color: self.text_color.interpolate(&other.text_color, amount),
+            border: self.border.interpolate(&other.border, amount),
+            shadow: self.shadow.interpolate(&other.shadow, amount),
+        }
+    }
+}
+
 impl Style {
     /// Updates the [`Style`] with the given [`Background`].
     pub fn with_background(self, background: impl Into<Background>) -> Self {
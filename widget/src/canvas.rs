@@ -232,7 +232,7 @@ where
             core::Event::Keyboard(keyboard_event) => {
                 Some(Event::Keyboard(keyboard_event))
             }
-            core::Event::Window(_) => None,
+            core::Event::Window(_) | core::Event::InputMethod(_) => None,
         };
 
         if let Some(canvas_event) = canvas_event {
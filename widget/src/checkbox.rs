@@ -30,16 +30,20 @@
 //! }
 //! ```
 //! ![Checkbox drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
+use crate::core::accessibility;
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::theme::palette;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
@@ -245,6 +249,30 @@ where
     }
 }
 
+struct State<P: text::Paragraph> {
+    text: widget::text::State<P>,
+    status: Option<Animation<Status>>,
+    now: Instant,
+}
+
+impl<'a, Message, Theme, Renderer> Checkbox<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: Catalog,
+{
+    fn status(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Status {
+        let is_checked = self.is_checked;
+
+        if self.on_toggle.is_none() {
+            Status::Disabled { is_checked }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_checked }
+        } else {
+            Status::Active { is_checked }
+        }
+    }
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Checkbox<'a, Message, Theme, Renderer>
 where
@@ -252,11 +280,15 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            text: widget::text::State::default(),
+            status: None,
+            now: Instant::now(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -277,12 +309,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.text,
                     renderer,
                     limits,
                     self.width,
@@ -300,9 +331,26 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::CheckBox)
+                .label(&self.label)
+                .toggled(self.is_checked)
+                .disabled(self.on_toggle.is_none()),
+        );
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -323,6 +371,22 @@ where
                     }
                 }
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let status = self.status(layout, cursor);
+
+                state.now = now;
+
+                let animation =
+                    state.status.get_or_insert_with(|| Animation::new(status));
+
+                animation.go_mut(status, now);
+
+                if let Some(request) = animation.redraw_request(now) {
+                    shell.request_redraw(request);
+                }
+            }
             _ => {}
         }
 
@@ -354,21 +418,19 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let is_mouse_over = cursor.is_over(layout.bounds());
-        let is_disabled = self.on_toggle.is_none();
-        let is_checked = self.is_checked;
-
         let mut children = layout.children();
 
-        let status = if is_disabled {
-            Status::Disabled { is_checked }
-        } else if is_mouse_over {
-            Status::Hovered { is_checked }
-        } else {
-            Status::Active { is_checked }
-        };
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let status = self.status(layout, cursor);
 
-        let style = theme.style(&self.class, status);
+        let style = match &state.status {
+            Some(animation) if *animation.target() == status => animation
+                .interpolate_with(
+                    |status| theme.style(&self.class, *status),
+                    state.now,
+                ),
+            _ => theme.style(&self.class, status),
+        };
 
         {
             let layout = children.next().unwrap();
@@ -414,14 +476,12 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.text.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -493,6 +553,17 @@ pub struct Style {
     pub text_color: Option<Color>,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Style {
+            background: self.background.interpolate(&other.background, amount),
+            icon_color: self.icon_color.interpolate(&other.icon_color, amount),
+            border: self.border.interpolate(&other.border, amount),
+            text_color: self.text_color.interpolate(&other.text_color, amount),
+        }
+    }
+}
+
 /// The theme catalog of a [`Checkbox`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
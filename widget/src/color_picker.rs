@@ -0,0 +1,1625 @@
+//! Color pickers let users choose a color.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::color_picker;
+//! use iced::Color;
+//!
+//! struct State {
+//!    accent: Color,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     AccentChanged(Color),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     color_picker(state.accent, Message::AccentChanged).into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::AccentChanged(accent) => {
+//!             state.accent = accent;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::accessibility;
+use crate::core::border::{self, Border};
+use crate::core::clipboard::Clipboard;
+use crate::core::event::{self, Event};
+use crate::core::gradient::{self, Gradient};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Color, Element, Length, Pixels, Point, Radians, Rectangle,
+    Shadow, Shell, Size, Theme, Vector, Widget,
+};
+use crate::date_picker::position_below;
+use crate::text_input::{self, TextInput, Value};
+
+use std::cell::Cell;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// A widget to choose a [`Color`], either by dragging through its hue,
+/// saturation, value and alpha, by typing its hex or RGB representation,
+/// or by picking one of its swatches.
+///
+/// A [`ColorPicker`] produces a message whenever the color changes, and it
+/// can also produce a message once a change is committed; that is, when a
+/// drag ends, a swatch is picked, a field is submitted, or the picker is
+/// closed.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::color_picker;
+/// use iced::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Preview(Color),
+///     Apply(Color),
+/// }
+///
+/// fn view(color: Color) -> Element<'static, Message> {
+///     color_picker(color, Message::Preview)
+///         .on_commit(Message::Apply)
+///         .as_button()
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_commit: Option<Box<dyn Fn(Color) -> Message + 'a>>,
+    swatches: Option<Vec<Color>>,
+    width: f32,
+    is_button: bool,
+    inputs: [TextInput<'a, Input, Theme, Renderer>; 4],
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+/// A message of the fields of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(String),
+    Submitted,
+}
+
+/// The indices of the fields of a [`ColorPicker`].
+const HEX: usize = 0;
+const RGB: [usize; 3] = [1, 2, 3];
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default width of a [`ColorPicker`].
+    pub const DEFAULT_WIDTH: f32 = 240.0;
+
+    const SPACING: f32 = 8.0;
+    const BAR_HEIGHT: f32 = 12.0;
+    const SWATCH_SIZE: f32 = 20.0;
+    const BUTTON_SIZE: Size = Size::new(40.0, 28.0);
+
+    /// Creates a new [`ColorPicker`] with the given [`Color`] and the message
+    /// to produce when it changes.
+    pub fn new(
+        color: Color,
+        on_change: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        let field = |placeholder: &str| {
+            TextInput::new(placeholder, "")
+                .on_input(Input::Changed)
+                .on_submit(Input::Submitted)
+                .size(14)
+                .padding([4, 6])
+        };
+
+        let channel = |placeholder| {
+            field(placeholder)
+                .max_length(3)
+                .filter(|contents| contents.chars().all(|c| c.is_ascii_digit()))
+        };
+
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            on_commit: None,
+            swatches: None,
+            width: Self::DEFAULT_WIDTH,
+            is_button: false,
+            inputs: [
+                field("Hex").max_length(9).filter(|contents| {
+                    contents
+                        .strip_prefix('#')
+                        .unwrap_or(contents)
+                        .chars()
+                        .all(|c| c.is_ascii_hexdigit())
+                }),
+                channel("R"),
+                channel("G"),
+                channel("B"),
+            ],
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the message to produce when a change of [`Color`] is committed.
+    ///
+    /// Changes are committed when a drag ends, a swatch is picked, a field
+    /// is submitted, or the picker is closed.
+    pub fn on_commit(
+        mut self,
+        on_commit: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        self.on_commit = Some(Box::new(on_commit));
+        self
+    }
+
+    /// Sets the swatches of the [`ColorPicker`].
+    ///
+    /// By default, the swatches are taken from the [`Style`] of the picker;
+    /// which, for the built-in [`Theme`], are the colors of its palette.
+    pub fn swatches(
+        mut self,
+        swatches: impl IntoIterator<Item = Color>,
+    ) -> Self {
+        self.swatches = Some(swatches.into_iter().collect());
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Turns the [`ColorPicker`] into a button displaying its [`Color`],
+    /// which opens the picker in an overlay anchored to it when pressed.
+    pub fn as_button(mut self) -> Self {
+        self.is_button = true;
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ColorPicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The part of a [`ColorPicker`] being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    Square,
+    Hue,
+    Alpha,
+}
+
+struct State {
+    color: Color,
+    hsv: Hsv,
+    buffers: [Value; 4],
+    drag: Option<Drag>,
+    is_open: bool,
+    theme_swatches: Cell<[Color; THEME_SWATCHES]>,
+}
+
+impl State {
+    fn new(color: Color) -> Self {
+        Self {
+            color,
+            hsv: Hsv::from_color(color),
+            buffers: [HEX, RGB[0], RGB[1], RGB[2]]
+                .map(|field| Value::new(&format_field(color, field))),
+            drag: None,
+            is_open: false,
+            theme_swatches: Cell::new([Color::TRANSPARENT; THEME_SWATCHES]),
+        }
+    }
+
+    /// Sets the [`Color`] of the [`State`], keeping the hue and saturation
+    /// that the [`Color`] cannot represent, and reformatting every field but
+    /// the one being edited.
+    fn set_color(&mut self, color: Color, editing: Option<usize>) {
+        self.hsv = self.hsv.with_color(color);
+        self.color = color;
+
+        for (field, buffer) in self.buffers.iter_mut().enumerate() {
+            if Some(field) != editing {
+                *buffer = Value::new(&format_field(color, field));
+            }
+        }
+    }
+}
+
+fn format_field(color: Color, field: usize) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    match field {
+        HEX if a == u8::MAX => format!("#{r:02x}{g:02x}{b:02x}"),
+        HEX => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        _ => [r, g, b][field - 1].to_string(),
+    }
+}
+
+fn parse_field(color: Color, field: usize, contents: &str) -> Option<Color> {
+    if field == HEX {
+        let digits = contents.strip_prefix('#').unwrap_or(contents).len();
+
+        return (digits == 6 || digits == 8)
+            .then(|| Color::parse(contents))
+            .flatten();
+    }
+
+    let [mut r, mut g, mut b, _] = color.into_rgba8();
+    let channel = contents.parse().ok()?;
+
+    *[&mut r, &mut g, &mut b][field - 1] = channel;
+
+    Some(Color::from_rgba8(r, g, b, color.a))
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn change(
+        &self,
+        state: &mut State,
+        color: Color,
+        editing: Option<usize>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if color != state.color {
+            shell.publish((self.on_change)(color));
+        }
+
+        state.set_color(color, editing);
+        shell.invalidate_layout();
+    }
+
+    fn commit(&self, state: &State, shell: &mut Shell<'_, Message>) {
+        if let Some(on_commit) = &self.on_commit {
+            shell.publish(on_commit(state.color));
+        }
+    }
+
+    /// Lays out the square, the bars, the fields and the swatches of the
+    /// [`ColorPicker`].
+    fn layout_picker(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        swatches: usize,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let width = self.width;
+
+        let square = layout::Node::new(Size::new(width, width * 0.6));
+        let mut y = square.size().height + Self::SPACING;
+
+        let mut bar = || {
+            let node = layout::Node::new(Size::new(width, Self::BAR_HEIGHT))
+                .move_to((0.0, y));
+
+            y += Self::BAR_HEIGHT + Self::SPACING;
+
+            node
+        };
+
+        let hue = bar();
+        let alpha = bar();
+
+        let hex_width = (width - Self::SPACING * 3.0) * 0.4;
+        let channel_width = (width - Self::SPACING * 3.0 - hex_width) / 3.0;
+        let mut x = 0.0;
+
+        let fields: Vec<_> = self
+            .inputs
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&state.buffers)
+            .enumerate()
+            .map(|(field, ((input, tree), buffer))| {
+                let width = if field == HEX {
+                    hex_width
+                } else {
+                    channel_width
+                };
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width, f32::INFINITY),
+                );
+
+                let node = input
+                    .layout(tree, renderer, &limits, Some(buffer))
+                    .move_to((x, 0.0));
+
+                x += width + Self::SPACING;
+
+                node
+            })
+            .collect();
+
+        let fields_height = fields
+            .iter()
+            .map(|field| field.size().height)
+            .fold(0.0, f32::max);
+
+        let fields = layout::Node::with_children(
+            Size::new(width, fields_height),
+            fields,
+        )
+        .move_to((0.0, y));
+
+        y += fields_height + Self::SPACING;
+
+        let columns = ((width + Self::SPACING)
+            / (Self::SWATCH_SIZE + Self::SPACING))
+            .floor()
+            .max(1.0) as usize;
+
+        let swatches: Vec<_> = (0..swatches)
+            .map(|i| {
+                layout::Node::new(Size::new(
+                    Self::SWATCH_SIZE,
+                    Self::SWATCH_SIZE,
+                ))
+                .move_to((
+                    (i % columns) as f32 * (Self::SWATCH_SIZE + Self::SPACING),
+                    (i / columns) as f32 * (Self::SWATCH_SIZE + Self::SPACING),
+                ))
+            })
+            .collect();
+
+        let rows = swatches.len().div_ceil(columns) as f32;
+        let swatches_height = (rows * (Self::SWATCH_SIZE + Self::SPACING)
+            - Self::SPACING)
+            .max(0.0);
+
+        let swatches = layout::Node::with_children(
+            Size::new(width, swatches_height),
+            swatches,
+        )
+        .move_to((0.0, y));
+
+        y += swatches_height;
+
+        layout::Node::with_children(
+            Size::new(width, y),
+            vec![square, hue, alpha, fields, swatches],
+        )
+    }
+
+    fn operate_picker(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let color = state.color;
+        let mut children = layout.children();
+
+        let number =
+            |value: f32, max: f64, step: f64| accessibility::Value::Number {
+                value: f64::from(value),
+                min: 0.0,
+                max,
+                step,
+            };
+
+        for (label, value) in [
+            (
+                "Saturation and value",
+                accessibility::Value::Text(format!(
+                    "{:.0}%, {:.0}%",
+                    state.hsv.saturation * 100.0,
+                    state.hsv.value * 100.0
+                )),
+            ),
+            ("Hue", number(state.hsv.hue, 360.0, 1.0)),
+            ("Alpha", number(color.a, 1.0, 0.01)),
+        ] {
+            let Some(layout) = children.next() else {
+                return;
+            };
+
+            operation.accessible(
+                None,
+                layout.bounds(),
+                &accessibility::Node::new(accessibility::Role::Slider)
+                    .label(label)
+                    .value(value),
+            );
+        }
+
+        if let Some(fields) = children.next() {
+            for ((input, tree), layout) in self
+                .inputs
+                .iter()
+                .zip(&mut tree.children)
+                .zip(fields.children())
+            {
+                input.operate(tree, layout, renderer, operation);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn update_picker(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+        swatches: &[Color],
+    ) -> event::Status {
+        let mut children = layout.children();
+        let (
+            Some(square),
+            Some(hue),
+            Some(alpha),
+            Some(fields),
+            Some(swatch_layouts),
+        ) = (
+            children.next(),
+            children.next(),
+            children.next(),
+            children.next(),
+            children.next(),
+        )
+        else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.drag = [
+                    (square, Drag::Square),
+                    (hue, Drag::Hue),
+                    (alpha, Drag::Alpha),
+                ]
+                .into_iter()
+                .find(|(layout, _)| cursor.is_over(layout.bounds()))
+                .map(|(_, drag)| drag);
+
+                if let Some(swatch) = swatch_layouts
+                    .children()
+                    .position(|layout| cursor.is_over(layout.bounds()))
+                {
+                    self.change(state, swatches[swatch], None, shell);
+                    self.commit(state, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+                self.commit(state, shell);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let is_dragging = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                | Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. })
+        );
+
+        if let Some((drag, position)) =
+            state.drag.zip(cursor.position()).filter(|_| is_dragging)
+        {
+            let relative = |layout: Layout<'_>| {
+                let bounds = layout.bounds();
+
+                Vector::new(
+                    ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0),
+                    ((position.y - bounds.y) / bounds.height).clamp(0.0, 1.0),
+                )
+            };
+
+            let mut hsv = state.hsv;
+            let mut alpha_value = state.color.a;
+
+            match drag {
+                Drag::Square => {
+                    let offset = relative(square);
+
+                    hsv.saturation = offset.x;
+                    hsv.value = 1.0 - offset.y;
+                }
+                Drag::Hue => {
+                    hsv.hue = relative(hue).x * 360.0;
+                }
+                Drag::Alpha => {
+                    alpha_value = relative(alpha).x;
+                }
+            }
+
+            self.change(state, hsv.to_color(alpha_value), None, shell);
+            state.hsv = hsv;
+
+            return event::Status::Captured;
+        }
+
+        let mut status = event::Status::Ignored;
+        let mut edits = Vec::new();
+        let mut blurred = Vec::new();
+
+        for (field, ((input, tree), layout)) in self
+            .inputs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(fields.children())
+            .enumerate()
+        {
+            let was_focused = is_focused::<Renderer>(tree);
+
+            input.set_value(state.buffers[field].clone());
+
+            let mut messages = Vec::new();
+            let mut local_shell =
+                Shell::new(&mut messages).with_drag(shell.drag());
+
+            status = status.merge(input.on_event(
+                tree,
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            ));
+
+            local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+            if let Some(redraw_request) = local_shell.redraw_request() {
+                shell.request_redraw(redraw_request);
+            }
+
+            shell.request_input_method(local_shell.input_method());
+
+            edits.extend(messages.into_iter().map(|input| (field, input)));
+
+            if was_focused && !is_focused::<Renderer>(tree) {
+                blurred.push(field);
+            }
+        }
+
+        for (field, input) in edits {
+            match input {
+                Input::Changed(contents) => {
+                    state.buffers[field] = Value::new(&contents);
+
+                    if let Some(color) =
+                        parse_field(state.color, field, &contents)
+                    {
+                        self.change(state, color, Some(field), shell);
+                    }
+                }
+                Input::Submitted => {
+                    state.buffers[field] =
+                        Value::new(&format_field(state.color, field));
+
+                    self.commit(state, shell);
+                }
+            }
+        }
+
+        // Reformat any partial contents once the user is done editing
+        for field in blurred {
+            state.buffers[field] =
+                Value::new(&format_field(state.color, field));
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction_picker(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+
+        let is_over_control = state.drag.is_some()
+            || children
+                .by_ref()
+                .take(3)
+                .any(|layout| cursor.is_over(layout.bounds()));
+
+        if is_over_control {
+            return mouse::Interaction::Crosshair;
+        }
+
+        let (Some(fields), Some(swatches)) = (children.next(), children.next())
+        else {
+            return mouse::Interaction::default();
+        };
+
+        if swatches
+            .children()
+            .any(|layout| cursor.is_over(layout.bounds()))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(fields.children())
+            .map(|((input, tree), layout)| {
+                input
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_picker(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let hsv = state.hsv;
+        let mut children = layout.children();
+
+        let (
+            Some(square),
+            Some(hue),
+            Some(alpha),
+            Some(fields),
+            Some(swatch_layouts),
+        ) = (
+            children.next(),
+            children.next(),
+            children.next(),
+            children.next(),
+            children.next(),
+        )
+        else {
+            return;
+        };
+
+        let fill = |renderer: &mut Renderer,
+                    bounds: Rectangle,
+                    background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: border::rounded(2),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        let gradient = |angle: f32, stops: &[(f32, Color)]| {
+            Background::Gradient(Gradient::Linear(stops.iter().fold(
+                gradient::Linear::new(Radians(angle)),
+                |linear, &(offset, color)| linear.add_stop(offset, color),
+            )))
+        };
+
+        // Saturation increases to the right, and value to the top
+        let bounds = square.bounds();
+        let pure = Hsv {
+            saturation: 1.0,
+            value: 1.0,
+            ..hsv
+        };
+
+        fill(renderer, bounds, pure.to_color(1.0).into());
+        fill(
+            renderer,
+            bounds,
+            gradient(
+                FRAC_PI_2,
+                &[(0.0, Color::WHITE), (1.0, Color::WHITE.scale_alpha(0.0))],
+            ),
+        );
+        fill(
+            renderer,
+            bounds,
+            gradient(
+                PI,
+                &[(0.0, Color::BLACK.scale_alpha(0.0)), (1.0, Color::BLACK)],
+            ),
+        );
+
+        draw_handle(
+            renderer,
+            style,
+            Point::new(
+                bounds.x + hsv.saturation * bounds.width,
+                bounds.y + (1.0 - hsv.value) * bounds.height,
+            ),
+        );
+
+        let bounds = hue.bounds();
+        let stops: Vec<_> = (0..=6)
+            .map(|i| {
+                let offset = i as f32 / 6.0;
+
+                (offset, Hsv::pure(offset * 360.0).to_color(1.0))
+            })
+            .collect();
+
+        fill(renderer, bounds, gradient(FRAC_PI_2, &stops));
+        draw_handle(
+            renderer,
+            style,
+            Point::new(
+                bounds.x + hsv.hue / 360.0 * bounds.width,
+                bounds.center_y(),
+            ),
+        );
+
+        let bounds = alpha.bounds();
+        let opaque = Color {
+            a: 1.0,
+            ..state.color
+        };
+
+        draw_checkerboard(renderer, bounds, style.checker_color);
+        fill(
+            renderer,
+            bounds,
+            gradient(
+                FRAC_PI_2,
+                &[(0.0, opaque.scale_alpha(0.0)), (1.0, opaque)],
+            ),
+        );
+        draw_handle(
+            renderer,
+            style,
+            Point::new(
+                bounds.x + state.color.a * bounds.width,
+                bounds.center_y(),
+            ),
+        );
+
+        for (((input, tree), layout), buffer) in self
+            .inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(fields.children())
+            .zip(&state.buffers)
+        {
+            input.draw(
+                tree,
+                renderer,
+                theme,
+                layout,
+                cursor,
+                Some(buffer),
+                viewport,
+            );
+        }
+
+        let swatches = self.swatches.as_deref().unwrap_or(&style.swatches);
+
+        for (swatch, layout) in swatches.iter().zip(swatch_layouts.children()) {
+            let bounds = layout.bounds();
+
+            draw_checkerboard(renderer, bounds, style.checker_color);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: if *swatch == state.color {
+                        style.selected_swatch_border
+                    } else {
+                        style.swatch_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                *swatch,
+            );
+        }
+    }
+
+    fn swatch_count(&self) -> usize {
+        self.swatches.as_ref().map_or(THEME_SWATCHES, Vec::len)
+    }
+}
+
+fn draw_handle<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    center: Point,
+) {
+    const SIZE: f32 = 12.0;
+
+    let bounds = Rectangle::new(
+        center - Vector::new(SIZE / 2.0, SIZE / 2.0),
+        Size::new(SIZE, SIZE),
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: bounds.expand(1.0),
+            border: Border {
+                color: style.handle_outline,
+                width: 1.0,
+                radius: (SIZE / 2.0 + 1.0).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color: style.handle_color,
+                width: 2.0,
+                radius: (SIZE / 2.0).into(),
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Draws a checkerboard behind translucent colors.
+fn draw_checkerboard<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    const SIZE: f32 = 6.0;
+
+    let columns = (bounds.width / SIZE).ceil() as usize;
+    let rows = (bounds.height / SIZE).ceil() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * SIZE;
+            let y = row as f32 * SIZE;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + x,
+                        y: bounds.y + y,
+                        width: SIZE.min(bounds.width - x),
+                        height: SIZE.min(bounds.height - y),
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.color))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.inputs
+            .iter()
+            .map(|input| Tree::new(input as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Keep the fields being edited when the color changes externally
+        if state.color != self.color {
+            let editing = tree
+                .children
+                .iter()
+                .position(|tree| is_focused::<Renderer>(tree));
+
+            state.set_color(self.color, editing);
+        }
+
+        for (input, tree) in self.inputs.iter().zip(&mut tree.children) {
+            tree.diff(input as &dyn Widget<_, _, _>);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        if self.is_button {
+            Size::new(
+                Length::Fixed(Self::BUTTON_SIZE.width),
+                Length::Fixed(Self::BUTTON_SIZE.height),
+            )
+        } else {
+            Size::new(Length::Fixed(self.width), Length::Shrink)
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        if self.is_button {
+            layout::Node::new(Self::BUTTON_SIZE)
+        } else {
+            self.layout_picker(tree, renderer, self.swatch_count())
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.is_button {
+            let state = tree.state.downcast_ref::<State>();
+
+            operation.accessible(
+                None,
+                layout.bounds(),
+                &accessibility::Node::new(accessibility::Role::Button)
+                    .label(format_field(state.color, HEX)),
+            );
+        } else {
+            self.operate_picker(tree, layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.is_button {
+            let swatches = self.swatches.clone().unwrap_or_else(|| {
+                tree.state
+                    .downcast_ref::<State>()
+                    .theme_swatches
+                    .get()
+                    .to_vec()
+            });
+
+            return self.update_picker(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport, &swatches,
+            );
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_open {
+                    // The popup did not capture the click, so it happened
+                    // outside of it
+                    state.is_open = false;
+                    self.commit(state, shell);
+
+                    event::Status::Captured
+                } else if cursor.is_over(layout.bounds()) {
+                    state.is_open = true;
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_button {
+            self.mouse_interaction_picker(
+                tree, layout, cursor, viewport, renderer,
+            )
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if self.is_button && cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = Catalog::style(theme, &self.class, status);
+
+        // Swatches are only picked after they are drawn, so the ones of the
+        // theme can be remembered here
+        state.theme_swatches.set(style.swatches);
+
+        if !self.is_button {
+            self.draw_picker(
+                tree, renderer, theme, &style, layout, cursor, viewport,
+            );
+
+            return;
+        }
+
+        draw_checkerboard(renderer, bounds, style.checker_color);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            state.color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !self.is_button || !tree.state.downcast_ref::<State>().is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Popup {
+            picker: self,
+            tree,
+            button: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(color_picker)
+    }
+}
+
+struct Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a mut ColorPicker<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    button: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    const PADDING: f32 = 12.0;
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        let state = self.tree.state.downcast_mut::<State>();
+
+        state.is_open = false;
+        self.picker.commit(state, shell);
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let picker = self.picker.layout_picker(
+            self.tree,
+            renderer,
+            self.picker.swatch_count(),
+        );
+
+        let size = picker
+            .size()
+            .expand(Size::new(Self::PADDING * 2.0, Self::PADDING * 2.0));
+
+        layout::Node::with_children(
+            size,
+            vec![picker.move_to((Self::PADDING, Self::PADDING))],
+        )
+        .move_to(position_below(self.button, size, bounds))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(picker) = layout.children().next() {
+            self.picker
+                .operate_picker(self.tree, picker, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Escape),
+            ..
+        }) = event
+        {
+            self.close(shell);
+            shell.invalidate_layout();
+
+            return event::Status::Captured;
+        }
+
+        let Some(picker) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        let swatches = self.picker.swatches.clone().unwrap_or_else(|| {
+            self.tree
+                .state
+                .downcast_ref::<State>()
+                .theme_swatches
+                .get()
+                .to_vec()
+        });
+
+        let status = self.picker.update_picker(
+            self.tree,
+            event,
+            picker,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+            &swatches,
+        );
+
+        if is_press && cursor.is_over(layout.bounds()) {
+            event::Status::Captured
+        } else {
+            status
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout.children().next().map_or_else(
+            mouse::Interaction::default,
+            |picker| {
+                self.picker.mouse_interaction_picker(
+                    self.tree, picker, cursor, viewport, renderer,
+                )
+            },
+        )
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = Catalog::style(theme, &self.picker.class, Status::Opened);
+        let bounds = layout.bounds();
+
+        renderer.with_layer(
+            bounds.expand(style.shadow.blur_radius),
+            |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: style.shadow,
+                    },
+                    style.background,
+                );
+
+                if let Some(picker) = layout.children().next() {
+                    self.picker.draw_picker(
+                        self.tree, renderer, theme, &style, picker, cursor,
+                        &bounds,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// A [`Color`] in the HSV color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    fn pure(hue: f32) -> Self {
+        Self {
+            hue,
+            saturation: 1.0,
+            value: 1.0,
+        }
+    }
+
+    fn from_color(color: Color) -> Self {
+        let Color { r, g, b, .. } = color;
+
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        Self {
+            hue,
+            saturation: if max == 0.0 { 0.0 } else { delta / max },
+            value: max,
+        }
+    }
+
+    /// Converts the given [`Color`], keeping the hue and saturation of this
+    /// [`Hsv`] when the [`Color`] has none.
+    fn with_color(self, color: Color) -> Self {
+        let hsv = Self::from_color(color);
+
+        Self {
+            hue: if hsv.saturation == 0.0 || hsv.value == 0.0 {
+                self.hue
+            } else {
+                hsv.hue
+            },
+            saturation: if hsv.value == 0.0 {
+                self.saturation
+            } else {
+                hsv.saturation
+            },
+            value: hsv.value,
+        }
+    }
+
+    fn to_color(self, alpha: f32) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = self.hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let m = self.value - chroma;
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color::from_rgba(r + m, g + m, b + m, alpha)
+    }
+}
+
+/// The amount of swatches of a [`Style`].
+const THEME_SWATCHES: usize = 8;
+
+/// The possible status of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`ColorPicker`] can be interacted with.
+    Active,
+    /// The button of the [`ColorPicker`] is being hovered.
+    Hovered,
+    /// The [`ColorPicker`] is open in an overlay.
+    Opened,
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the overlay.
+    pub background: Background,
+    /// The [`Border`] of the button and the overlay.
+    pub border: Border,
+    /// The [`Shadow`] of the overlay.
+    pub shadow: Shadow,
+    /// The [`Color`] of the handles.
+    pub handle_color: Color,
+    /// The [`Color`] of the outline of the handles.
+    pub handle_outline: Color,
+    /// The [`Color`] of the checkerboard behind translucent colors.
+    pub checker_color: Color,
+    /// The [`Border`] of the swatches.
+    pub swatch_border: Border,
+    /// The [`Border`] of the swatch of the current [`Color`].
+    pub selected_swatch_border: Border,
+    /// The swatches displayed when none are provided to the [`ColorPicker`].
+    pub swatches: [Color; THEME_SWATCHES],
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`ColorPicker`], with the colors of the
+/// [`Palette`] of the [`Theme`] as swatches.
+///
+/// [`Palette`]: crate::core::theme::Palette
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+    let extended = theme.extended_palette();
+
+    Style {
+        background: extended.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: match status {
+                Status::Active => extended.background.strong.color,
+                Status::Hovered | Status::Opened => {
+                    extended.primary.strong.color
+                }
+            },
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        handle_color: Color::WHITE,
+        handle_outline: Color::BLACK.scale_alpha(0.5),
+        checker_color: extended.background.weak.color,
+        swatch_border: Border {
+            width: 1.0,
+            radius: 2.0.into(),
+            color: extended.background.strong.color,
+        },
+        selected_swatch_border: Border {
+            width: 2.0,
+            radius: 2.0.into(),
+            color: extended.primary.strong.color,
+        },
+        swatches: [
+            palette.background,
+            palette.text,
+            palette.primary,
+            palette.success,
+            palette.danger,
+            extended.primary.weak.color,
+            extended.secondary.base.color,
+            extended.background.strong.color,
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_hsv() {
+        let color = Color::from_rgb8(0x5e, 0x7c, 0xe2);
+        let hsv = Hsv::from_color(color);
+
+        assert_eq!(hsv.to_color(1.0).into_rgba8(), color.into_rgba8());
+
+        let black = hsv.with_color(Color::BLACK);
+
+        assert_eq!(black.hue, hsv.hue);
+        assert_eq!(black.saturation, hsv.saturation);
+        assert_eq!(black.value, 0.0);
+    }
+
+    #[test]
+    fn parses_fields() {
+        let color = Color::from_rgb8(0x12, 0x34, 0x56);
+
+        assert_eq!(format_field(color, HEX), "#123456");
+        assert_eq!(format_field(color, RGB[1]), "52");
+        assert_eq!(parse_field(color, HEX, "#12345"), None);
+        assert_eq!(
+            parse_field(color, HEX, "abcdef80"),
+            Color::parse("#abcdef80")
+        );
+        assert_eq!(
+            parse_field(color, RGB[2], "255").map(Color::into_rgba8),
+            Some([0x12, 0x34, 0xff, 0xff])
+        );
+    }
+}
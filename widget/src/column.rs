@@ -371,3 +371,105 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Container, Space};
+
+    type Element = crate::core::Element<'static, (), crate::Theme, ()>;
+
+    fn view() -> Element {
+        Container::new(
+            Column::new()
+                .push(Space::new(10, 10))
+                .push(Space::new(Length::Fill, 20))
+                .padding(5)
+                .spacing(3),
+        )
+        .padding(2)
+        .into()
+    }
+
+    fn layout(element: &Element) -> layout::Node {
+        element.as_widget().layout(
+            &mut Tree::new(element),
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        )
+    }
+
+    #[test]
+    fn records_layouts() {
+        // Recording is global, so both cases share a single test
+        let node = layout(&view());
+
+        assert!(node.record().is_none());
+        assert!(node.children()[0].record().is_none());
+
+        layout::record::enable(true);
+        let container = layout(&view());
+        layout::record::enable(false);
+
+        let column = &container.children()[0];
+        let spaces = column.children();
+
+        assert_eq!(
+            container.record(),
+            Some(&layout::Record {
+                widget: Some(std::any::type_name::<
+                    Container<'static, (), crate::Theme, ()>,
+                >()),
+                limits: Some(layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(100.0, 100.0),
+                )),
+                size: Some(Size::new(Length::Fill, Length::Shrink)),
+                padding: Padding::new(2.0),
+                spacing: 0.0,
+            })
+        );
+
+        assert_eq!(
+            column.record(),
+            Some(&layout::Record {
+                widget: Some(std::any::type_name::<
+                    Column<'static, (), crate::Theme, ()>,
+                >()),
+                limits: Some(layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(96.0, 96.0),
+                )),
+                size: Some(Size::new(Length::Fill, Length::Shrink)),
+                padding: Padding::new(5.0),
+                spacing: 3.0,
+            })
+        );
+
+        assert_eq!(
+            spaces[0].record(),
+            Some(&layout::Record {
+                widget: Some(std::any::type_name::<Space>()),
+                limits: Some(layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(86.0, 83.0),
+                )),
+                size: Some(Size::new(Length::Fixed(10.0), Length::Fixed(10.0))),
+                ..layout::Record::default()
+            })
+        );
+
+        assert_eq!(
+            spaces[1].record(),
+            Some(&layout::Record {
+                widget: Some(std::any::type_name::<Space>()),
+                limits: Some(layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(86.0, 73.0),
+                )),
+                size: Some(Size::new(Length::Fill, Length::Fixed(20.0))),
+                ..layout::Record::default()
+            })
+        );
+    }
+}
@@ -535,7 +535,8 @@ where
 
         // Create a new list of local messages
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag());
 
         // Provide it to the widget
         let mut event_status = self.text_input.on_event(
@@ -20,6 +20,7 @@
 //! }
 //! ```
 use crate::core::alignment::{self, Alignment};
+use crate::core::animation::Interpolate;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::gradient::{self, Gradient};
@@ -584,6 +585,17 @@ pub struct Style {
     pub shadow: Shadow,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Style {
+            text_color: self.text_color.interpolate(&other.text_color, amount),
+            background: self.background.interpolate(&other.background, amount),
+            border: self.border.interpolate(&other.border, amount),
+            shadow: self.shadow.interpolate(&other.shadow, amount),
+        }
+    }
+}
+
 impl Style {
     /// Updates the text color of the [`Style`].
     pub fn color(self, color: impl Into<Color>) -> Self {
@@ -636,6 +648,18 @@ impl From<gradient::Linear> for Style {
     }
 }
 
+impl From<gradient::Radial> for Style {
+    fn from(gradient: gradient::Radial) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
+impl From<gradient::Conic> for Style {
+    fn from(gradient: gradient::Conic) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
 /// The theme catalog of a [`Container`].
 pub trait Catalog {
     /// The item class of the [`Catalog`].
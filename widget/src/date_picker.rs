@@ -0,0 +1,1524 @@
+//! Date pickers let users select a date, or a range of dates, from a calendar.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::date_picker;
+//! use iced::widget::date_picker::Date;
+//!
+//! struct State {
+//!    today: Date,
+//!    deadline: Option<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     DeadlineSelected(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     date_picker(state.deadline, Message::DeadlineSelected)
+//!         .placeholder("Pick a deadline...")
+//!         .today(state.today)
+//!         .min(state.today)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::DeadlineSelected(date) => {
+//!             state.deadline = Some(date);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::accessibility;
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::SystemTime;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::fmt;
+
+/// A field that opens a calendar to select a [`Date`], or a range of dates.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::date_picker::{Date, Weekday};
+/// use iced::widget::date_range_picker;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     StayChanged(Date, Date),
+/// }
+///
+/// fn view(stay: Option<(Date, Date)>) -> Element<'static, Message> {
+///     date_range_picker(stay, Message::StayChanged)
+///         .placeholder("Check-in and check-out")
+///         .first_weekday(Weekday::Sunday)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    selection: Selection<'a, Message>,
+    placeholder: Option<String>,
+    min: Option<Date>,
+    max: Option<Date>,
+    today: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    first_weekday: Weekday,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+enum Selection<'a, Message> {
+    Single {
+        selected: Option<Date>,
+        on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    },
+    Range {
+        selected: Option<(Date, Date)>,
+        on_select: Box<dyn Fn(Date, Date) -> Message + 'a>,
+    },
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`DatePicker`] with the given selected [`Date`], if any,
+    /// and the message to produce when a [`Date`] is selected.
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(Selection::Single {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    /// Creates a new [`DatePicker`] that selects a range of dates, given
+    /// the current range, if any, and the message to produce with the
+    /// first and last dates of a new range.
+    ///
+    /// A range is selected by picking both of its ends, in any order.
+    pub fn range(
+        selected: Option<(Date, Date)>,
+        on_select: impl Fn(Date, Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(Selection::Range {
+            selected,
+            on_select: Box::new(on_select),
+        })
+    }
+
+    fn with_selection(selection: Selection<'a, Message>) -> Self {
+        Self {
+            selection,
+            placeholder: None,
+            min: None,
+            max: None,
+            today: None,
+            is_disabled: None,
+            first_weekday: Weekday::Monday,
+            width: Length::Shrink,
+            padding: crate::button::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the placeholder of the [`DatePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the current [`Date`], which the calendar opens on when nothing
+    /// is selected.
+    ///
+    /// By default, the current date in UTC is used, which may differ from
+    /// the local date of the user; see [`Date::today_utc`].
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Sets a predicate that disables the dates it returns `true` for.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the [`Weekday`] the weeks of the calendar start on.
+    ///
+    /// By default, weeks start on [`Weekday::Monday`].
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Starts the weeks of the calendar on the first weekday of the given
+    /// locale; like `"en-US"` or `"pt_BR.UTF-8"`.
+    ///
+    /// See [`Weekday::first_of_locale`].
+    pub fn locale(self, locale: &str) -> Self {
+        self.first_weekday(Weekday::first_of_locale(locale))
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        let is_before_min = self.min.is_some_and(|min| date < min);
+        let is_after_max = self.max.is_some_and(|max| date > max);
+
+        !is_before_min
+            && !is_after_max
+            && !self.is_disabled.as_ref().is_some_and(|f| f(date))
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    fn label(&self) -> Option<String> {
+        match &self.selection {
+            Selection::Single { selected, .. } => {
+                selected.map(|date| date.to_string())
+            }
+            Selection::Range { selected, .. } => {
+                selected.map(|(start, end)| format!("{start} – {end}"))
+            }
+        }
+    }
+
+    fn is_selected(&self, date: Date) -> bool {
+        match &self.selection {
+            Selection::Single { selected, .. } => *selected == Some(date),
+            Selection::Range { selected, .. } => selected
+                .is_some_and(|(start, end)| date == start || date == end),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.today.unwrap_or_else(Date::today_utc)))
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let sample = match self.selection {
+            Selection::Single { .. } => "0000-00-00",
+            Selection::Range { .. } => "0000-00-00 – 0000-00-00",
+        };
+
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+            [Some(sample), self.placeholder.as_deref()],
+        )
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut node = accessibility::Node::new(accessibility::Role::ComboBox);
+
+        if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder);
+        }
+
+        if let Some(label) = self.label() {
+            node = node.value(accessibility::Value::Text(label));
+        }
+
+        operation.accessible(None, layout.bounds(), &node);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_open {
+                    // The calendar did not capture the click, so it
+                    // happened outside of it
+                    state.is_open = false;
+
+                    event::Status::Captured
+                } else if cursor.is_over(layout.bounds()) {
+                    let selected = match &self.selection {
+                        Selection::Single { selected, .. } => *selected,
+                        Selection::Range { selected, .. } => {
+                            selected.map(|(start, _)| start)
+                        }
+                    };
+
+                    let focused = self.clamp(selected.unwrap_or_else(|| {
+                        self.today.unwrap_or_else(Date::today_utc)
+                    }));
+
+                    *state = State {
+                        is_open: true,
+                        month: focused.first_of_month(),
+                        focused,
+                        anchor: None,
+                    };
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        draw_field(
+            renderer,
+            &theme.style(&self.class, status),
+            bounds,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.label().as_deref(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Calendar {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct State {
+    is_open: bool,
+    month: Date,
+    focused: Date,
+    anchor: Option<Date>,
+}
+
+impl State {
+    fn new(today: Date) -> Self {
+        Self {
+            is_open: false,
+            month: today.first_of_month(),
+            focused: today,
+            anchor: None,
+        }
+    }
+}
+
+struct Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a DatePicker<'b, Message, Theme, Renderer>,
+    state: &'a mut State,
+    field: Rectangle,
+}
+
+/// The navigation buttons of a [`Calendar`], in layout order.
+const NAVIGATION: [(&str, i32); 4] =
+    [("«", -12), ("‹", -1), ("›", 1), ("»", 12)];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+impl<'a, 'b, Message, Theme, Renderer>
+    Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    const MARGIN: f32 = 8.0;
+
+    /// The first date displayed by the [`Calendar`].
+    fn start(&self) -> Date {
+        let offset = (self.state.month.weekday().index()
+            - self.picker.first_weekday.index())
+        .rem_euclid(7);
+
+        self.state.month.add_days(-i64::from(offset))
+    }
+
+    /// Returns whether the month at the given offset has any date within
+    /// the bounds of the [`DatePicker`].
+    fn can_show(&self, months: i32) -> bool {
+        let first = self.state.month.add_months(months);
+        let last = first.add_days(i64::from(first.days_in_month()) - 1);
+
+        let is_before_min = self.picker.min.is_some_and(|min| last < min);
+        let is_after_max = self.picker.max.is_some_and(|max| first > max);
+
+        !is_before_min && !is_after_max
+    }
+
+    fn show(&mut self, months: i32, shell: &mut Shell<'_, Message>) {
+        if self.can_show(months) {
+            self.state.month = self.state.month.add_months(months);
+            self.state.focused = self.picker.clamp(
+                self.state.focused.add_months(months).max(self.state.month),
+            );
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    fn focus(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        let date = self.picker.clamp(date);
+
+        self.state.focused = date;
+        self.state.month = date.first_of_month();
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    fn select(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        if !self.picker.is_enabled(date) {
+            return;
+        }
+
+        match &self.picker.selection {
+            Selection::Single { on_select, .. } => {
+                shell.publish(on_select(date));
+                self.state.is_open = false;
+            }
+            Selection::Range { on_select, .. } => {
+                if let Some(anchor) = self.state.anchor.take() {
+                    shell
+                        .publish(on_select(anchor.min(date), anchor.max(date)));
+                    self.state.is_open = false;
+                } else {
+                    self.state.anchor = Some(date);
+                    self.state.focused = date;
+                }
+            }
+        }
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    fn on_key(
+        &mut self,
+        key: key::Named,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let focused = self.state.focused;
+        let weekday = (focused.weekday().index()
+            - self.picker.first_weekday.index())
+        .rem_euclid(7);
+
+        let months = if modifiers.shift() { 12 } else { 1 };
+
+        match key {
+            key::Named::ArrowLeft => self.focus(focused.add_days(-1), shell),
+            key::Named::ArrowRight => self.focus(focused.add_days(1), shell),
+            key::Named::ArrowUp => self.focus(focused.add_days(-7), shell),
+            key::Named::ArrowDown => self.focus(focused.add_days(7), shell),
+            key::Named::Home => {
+                self.focus(focused.add_days(-i64::from(weekday)), shell);
+            }
+            key::Named::End => {
+                self.focus(focused.add_days(6 - i64::from(weekday)), shell);
+            }
+            key::Named::PageUp => {
+                self.focus(focused.add_months(-months), shell);
+            }
+            key::Named::PageDown => {
+                self.focus(focused.add_months(months), shell);
+            }
+            key::Named::Enter | key::Named::Space => {
+                self.select(focused, shell);
+            }
+            key::Named::Escape => {
+                self.state.is_open = false;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+
+    /// Returns whether the given date falls within the range being selected,
+    /// or the selected range.
+    fn is_in_range(&self, date: Date) -> bool {
+        let Selection::Range { selected, .. } = &self.picker.selection else {
+            return false;
+        };
+
+        let range = match self.state.anchor {
+            Some(anchor) => Some((
+                anchor.min(self.state.focused),
+                anchor.max(self.state.focused),
+            )),
+            None => *selected,
+        };
+
+        range.is_some_and(|(start, end)| start <= date && date <= end)
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let cell = (text_size.0 * 2.25).round();
+
+        let navigation = [0.0, cell, cell * 5.0, cell * 6.0].map(|x| {
+            layout::Node::new(Size::new(cell, cell))
+                .move_to((Self::MARGIN + x, Self::MARGIN))
+        });
+
+        let days = (0..42).map(|i| {
+            layout::Node::new(Size::new(cell, cell)).move_to((
+                Self::MARGIN + (i % 7) as f32 * cell,
+                Self::MARGIN + cell * 1.75 + (i / 7) as f32 * cell,
+            ))
+        });
+
+        let size = Size::new(
+            cell * 7.0 + Self::MARGIN * 2.0,
+            cell * 7.75 + Self::MARGIN * 2.0,
+        );
+
+        layout::Node::with_children(
+            size,
+            navigation.into_iter().chain(days).collect(),
+        )
+        .move_to(position_below(self.field, size, bounds))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let start = self.start();
+
+        for (i, layout) in layout.children().skip(NAVIGATION.len()).enumerate()
+        {
+            let date = start.add_days(i as i64);
+
+            operation.accessible(
+                None,
+                layout.bounds(),
+                &accessibility::Node::new(accessibility::Role::Button)
+                    .label(date.to_string())
+                    .disabled(!self.picker.is_enabled(date)),
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let start = self.start();
+
+        let hovered = layout
+            .children()
+            .position(|child| cursor.is_over(child.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(i) = hovered.filter(|&i| i >= NAVIGATION.len()) {
+                    let date = start.add_days((i - NAVIGATION.len()) as i64);
+
+                    if date != self.state.focused {
+                        self.state.focused = date;
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(layout.bounds()) {
+                    return event::Status::Ignored;
+                }
+
+                match hovered {
+                    Some(i) if i < NAVIGATION.len() => {
+                        self.show(NAVIGATION[i].1, shell);
+                    }
+                    Some(i) => {
+                        let date =
+                            start.add_days((i - NAVIGATION.len()) as i64);
+
+                        self.select(date, shell);
+                    }
+                    None => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(layout.bounds()) {
+                    return event::Status::Ignored;
+                }
+
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 {
+                    self.show(-1, shell);
+                } else if y < 0.0 {
+                    self.show(1, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) => {
+                return self.on_key(key, modifiers, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let start = self.start();
+
+        let is_over_enabled =
+            layout.children().enumerate().any(|(i, child)| {
+                cursor.is_over(child.bounds())
+                    && match i.checked_sub(NAVIGATION.len()) {
+                        None => self.can_show(NAVIGATION[i].1),
+                        Some(day) => {
+                            self.picker.is_enabled(start.add_days(day as i64))
+                        }
+                    }
+            });
+
+        if is_over_enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.picker.class, Status::Opened);
+        let bounds = layout.bounds();
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+
+        let label = |renderer: &mut Renderer,
+                     content: String,
+                     bounds: Rectangle,
+                     color: Color| {
+            renderer.fill_text(
+                Text {
+                    content,
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                bounds.center(),
+                color,
+                bounds,
+            );
+        };
+
+        renderer.with_layer(
+            bounds.expand(style.calendar_shadow.blur_radius),
+            |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.calendar_border,
+                        shadow: style.calendar_shadow,
+                    },
+                    style.calendar_background,
+                );
+
+                let mut children = layout.children();
+
+                for ((icon, months), layout) in
+                    NAVIGATION.into_iter().zip(children.by_ref())
+                {
+                    label(
+                        renderer,
+                        icon.to_owned(),
+                        layout.bounds(),
+                        if self.can_show(months) {
+                            style.calendar_text_color
+                        } else {
+                            style.disabled_text_color
+                        },
+                    );
+                }
+
+                let days: Vec<_> = children.collect();
+                let cell = days[0].bounds().size();
+
+                let month = self.state.month;
+
+                label(
+                    renderer,
+                    format!(
+                        "{} {}",
+                        MONTHS[usize::from(month.month()) - 1],
+                        month.year()
+                    ),
+                    Rectangle::new(
+                        Point::new(
+                            bounds.x + Self::MARGIN + cell.width * 1.5,
+                            bounds.y + Self::MARGIN,
+                        ),
+                        Size::new(cell.width * 4.0, cell.height),
+                    ),
+                    style.calendar_text_color,
+                );
+
+                for (i, layout) in days.iter().take(7).enumerate() {
+                    let weekday = self.picker.first_weekday.offset(i as i32);
+
+                    label(
+                        renderer,
+                        weekday.abbreviation().to_owned(),
+                        layout.bounds() - Vector::new(0.0, cell.height * 0.75),
+                        style.muted_text_color,
+                    );
+                }
+
+                let start = self.start();
+
+                for (i, layout) in days.iter().enumerate() {
+                    let date = start.add_days(i as i64);
+                    let bounds = layout.bounds();
+
+                    let is_selected = self.picker.is_selected(date)
+                        || self.state.anchor == Some(date);
+
+                    let background = if is_selected {
+                        Some(style.selected_background)
+                    } else if date == self.state.focused {
+                        Some(style.hovered_background)
+                    } else if self.is_in_range(date) {
+                        Some(style.range_background)
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: bounds.shrink(1.0),
+                                border: border::rounded(style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    let color = if !self.picker.is_enabled(date) {
+                        style.disabled_text_color
+                    } else if is_selected {
+                        style.selected_text_color
+                    } else if date.month() != month.month() {
+                        style.muted_text_color
+                    } else {
+                        style.calendar_text_color
+                    };
+
+                    label(renderer, date.day().to_string(), bounds, color);
+                }
+            },
+        );
+    }
+}
+
+/// Computes the layout of the field of a picker.
+pub(crate) fn layout_field<'a, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    labels: impl IntoIterator<Item = Option<&'a str>>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let line_height = text::LineHeight::default().to_absolute(text_size);
+
+    let max_width = match width {
+        Length::Shrink => labels
+            .into_iter()
+            .flatten()
+            .map(|content| {
+                Renderer::Paragraph::with_text(Text {
+                    content,
+                    bounds: Size::new(f32::INFINITY, line_height.0),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                })
+                .min_width()
+            })
+            .fold(0.0, f32::max),
+        _ => 0.0,
+    };
+
+    let intrinsic = Size::new(
+        max_width + text_size.0 + padding.left,
+        f32::from(line_height),
+    );
+
+    layout::Node::new(
+        limits
+            .width(width)
+            .shrink(padding)
+            .resolve(width, Length::Shrink, intrinsic)
+            .expand(padding),
+    )
+}
+
+/// Draws the field of a picker, with its label or placeholder and a handle.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw_field<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    label: Option<&str>,
+    placeholder: Option<&str>,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let line_height = text::LineHeight::default();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: style.border,
+            ..renderer::Quad::default()
+        },
+        style.background,
+    );
+
+    renderer.fill_text(
+        Text {
+            content: Renderer::ARROW_DOWN_ICON.to_string(),
+            size: text_size,
+            line_height,
+            font: Renderer::ICON_FONT,
+            bounds: Size::new(
+                bounds.width,
+                f32::from(line_height.to_absolute(text_size)),
+            ),
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::default(),
+        },
+        Point::new(bounds.x + bounds.width - padding.right, bounds.center_y()),
+        style.handle_color,
+        *viewport,
+    );
+
+    let (content, color) = match (label, placeholder) {
+        (Some(label), _) => (label, style.text_color),
+        (None, Some(placeholder)) => (placeholder, style.placeholder_color),
+        (None, None) => return,
+    };
+
+    renderer.fill_text(
+        Text {
+            content: content.to_owned(),
+            size: text_size,
+            line_height,
+            font,
+            bounds: Size::new(
+                bounds.width - padding.horizontal(),
+                f32::from(line_height.to_absolute(text_size)),
+            ),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+        },
+        Point::new(bounds.x + padding.left, bounds.center_y()),
+        color,
+        *viewport,
+    );
+}
+
+/// Positions a popup of the given [`Size`] right below the field of a
+/// picker; or above it, if it does not fit below.
+pub(crate) fn position_below(
+    field: Rectangle,
+    size: Size,
+    viewport: Size,
+) -> Point {
+    let below = field.y + field.height;
+
+    let y = if below + size.height > viewport.height
+        && field.y - size.height >= 0.0
+    {
+        field.y - size.height
+    } else {
+        below
+    };
+
+    Point::new(field.x.min(viewport.width - size.width).max(0.0), y)
+}
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from its year, month (starting at 1) and day
+    /// (starting at 1).
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    ///
+    /// This is not necessarily the local date of the user, which depends on
+    /// their time zone. Use [`DatePicker::today`] to provide it.
+    pub fn today_utc() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::Monday.offset((self.to_days() + 3).rem_euclid(7) as i32)
+    }
+
+    /// Returns the number of days in the month of the [`Date`].
+    pub fn days_in_month(self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Adds the given amount of days, which may be negative, to the [`Date`].
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Adds the given amount of months, which may be negative, to the
+    /// [`Date`].
+    ///
+    /// The day is clamped to the length of the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u8 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the [`Date`] the given amount of days after 1970-01-01.
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let month = (shifted_month + 2) % 12 + 1;
+
+        Self {
+            year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+            month: month as u8,
+            day: (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Returns the first day of the week in the given locale; like `"en-US"`,
+    /// `"ar_EG"` or `"pt_BR.UTF-8"`.
+    ///
+    /// Only the region of the locale is considered. Locales without a region
+    /// start their weeks on [`Weekday::Monday`], as defined by ISO 8601.
+    pub fn first_of_locale(locale: &str) -> Self {
+        const SUNDAY: &[&str] = &[
+            "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO",
+            "DM", "DO", "ET", "GT", "GU", "HK", "HN", "ID", "IL", "IN", "JM",
+            "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ",
+            "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG",
+            "SV", "TH", "TT", "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA",
+            "ZW",
+        ];
+
+        const SATURDAY: &[&str] = &[
+            "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY",
+            "OM", "QA", "SD", "SY",
+        ];
+
+        let locale = locale.split(['.', '@']).next().unwrap_or_default();
+
+        let region = locale.split(['-', '_']).skip(1).find(|subtag| {
+            subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+        });
+
+        match region.map(str::to_ascii_uppercase) {
+            Some(region) if SUNDAY.contains(&region.as_str()) => Self::Sunday,
+            Some(region) if SATURDAY.contains(&region.as_str()) => {
+                Self::Saturday
+            }
+            _ => Self::Monday,
+        }
+    }
+
+    /// Returns the [`Weekday`] the given amount of days after this one.
+    pub fn offset(self, days: i32) -> Self {
+        Self::ALL[(self.index() + days).rem_euclid(7) as usize]
+    }
+
+    /// Returns the two-letter English abbreviation of the [`Weekday`].
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Monday => "Mo",
+            Self::Tuesday => "Tu",
+            Self::Wednesday => "We",
+            Self::Thursday => "Th",
+            Self::Friday => "Fr",
+            Self::Saturday => "Sa",
+            Self::Sunday => "Su",
+        }
+    }
+
+    fn index(self) -> i32 {
+        self as i32
+    }
+}
+
+/// The possible status of a picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The picker can be interacted with.
+    Active,
+    /// The picker is being hovered.
+    Hovered,
+    /// The picker is open.
+    Opened,
+}
+
+/// The appearance of a date or time picker.
+///
+/// The field of a picker is styled like a [`PickList`], while the rest of
+/// the properties style its popup.
+///
+/// [`PickList`]: crate::PickList
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The text [`Color`] of the field.
+    pub text_color: Color,
+    /// The placeholder [`Color`] of the field.
+    pub placeholder_color: Color,
+    /// The handle [`Color`] of the field.
+    pub handle_color: Color,
+    /// The [`Background`] of the field.
+    pub background: Background,
+    /// The [`Border`] of the field.
+    pub border: Border,
+    /// The [`Background`] of the popup.
+    pub calendar_background: Background,
+    /// The [`Border`] of the popup.
+    pub calendar_border: Border,
+    /// The [`Shadow`] of the popup.
+    pub calendar_shadow: Shadow,
+    /// The text [`Color`] of the popup.
+    pub calendar_text_color: Color,
+    /// The text [`Color`] of weekdays and of the days of other months.
+    pub muted_text_color: Color,
+    /// The text [`Color`] of the values that cannot be selected.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the selected values.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the selected values.
+    pub selected_background: Background,
+    /// The [`Background`] of the hovered or focused value.
+    pub hovered_background: Background,
+    /// The [`Background`] of the days within a selected range.
+    pub range_background: Background,
+}
+
+/// The theme catalog of a [`DatePicker`] and a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DatePicker`] and a [`TimePicker`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+///
+/// [`TimePicker`]: crate::TimePicker
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DatePicker`] and a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        text_color: palette.background.weak.text,
+        background: palette.background.weak.color.into(),
+        placeholder_color: palette.background.strong.color,
+        handle_color: palette.background.weak.text,
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        calendar_background: palette.background.base.color.into(),
+        calendar_border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        calendar_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        calendar_text_color: palette.background.base.text,
+        muted_text_color: palette.background.base.text.scale_alpha(0.5),
+        disabled_text_color: palette.background.strong.color,
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        hovered_background: palette.background.weak.color.into(),
+        range_background: palette.primary.weak.color.scale_alpha(0.4).into(),
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Opened => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_dates() {
+        let date = Date::from_ymd(2024, 2, 29).unwrap();
+
+        assert_eq!(date.weekday(), Weekday::Thursday);
+        assert_eq!(date.add_days(1), Date::from_ymd(2024, 3, 1).unwrap());
+        assert_eq!(date.add_months(12), Date::from_ymd(2025, 2, 28).unwrap());
+        assert_eq!(date.add_months(-3), Date::from_ymd(2023, 11, 29).unwrap());
+        assert_eq!(Date::from_days(0), Date::from_ymd(1970, 1, 1).unwrap());
+        assert_eq!(Date::from_days(date.to_days()), date);
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+    }
+
+    #[test]
+    fn finds_first_weekday_of_locale() {
+        assert_eq!(Weekday::first_of_locale("en-US"), Weekday::Sunday);
+        assert_eq!(Weekday::first_of_locale("pt_BR.UTF-8"), Weekday::Sunday);
+        assert_eq!(Weekday::first_of_locale("ar_EG"), Weekday::Saturday);
+        assert_eq!(Weekday::first_of_locale("de-DE"), Weekday::Monday);
+        assert_eq!(Weekday::first_of_locale("zh-Hant-TW"), Weekday::Sunday);
+        assert_eq!(Weekday::first_of_locale("fr"), Weekday::Monday);
+    }
+}
@@ -0,0 +1,462 @@
+//! Drag sources let users drag some data out of an element.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, drag_source, drop_target, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dropped(usize),
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     column![
+//!         drag_source(42, text("Drag me!")),
+//!         drop_target(text("Drop here!")).on_drop(Message::Dropped),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::drag;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// An element that can be dragged around to drop some data on a
+/// [`DropTarget`].
+///
+/// While dragging, a preview of the element follows the cursor.
+///
+/// [`DropTarget`]: crate::DropTarget
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, drag_source, drop_target, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         drag_source(42, text("Drag me!")),
+///         drop_target(text("Drop here!")).on_drop(Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DragSource<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    payload: T,
+    content: Element<'a, Message, Theme, Renderer>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    on_drag: Option<Message>,
+    on_release: Option<Message>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    DragSource<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Creates a new [`DragSource`] carrying the given payload.
+    pub fn new(
+        payload: T,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            preview: None,
+            on_drag: None,
+            on_release: None,
+        }
+    }
+
+    /// Sets the element that follows the cursor while dragging.
+    ///
+    /// By default, the content of the [`DragSource`] is used.
+    pub fn preview(
+        mut self,
+        preview: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message that will be produced when a drag starts.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a drag ends, either
+    /// because the payload was dropped or because the drag was cancelled.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum State {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        grab: Vector,
+        position: Point,
+    },
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragSource<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.preview {
+            Some(preview) => tree.diff_children(&[
+                self.content.as_widget(),
+                preview.as_widget(),
+            ]),
+            None => tree.diff_children(std::slice::from_ref(&self.content)),
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let State::Dragging { grab, .. } = *state {
+            // The drag may have been dropped somewhere else
+            if shell.drag().is_none() {
+                *state = State::Idle;
+
+                if let Some(on_release) = &self.on_release {
+                    shell.publish(on_release.clone());
+                }
+
+                return event::Status::Ignored;
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    *state = State::Dragging { grab, position };
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) => {
+                    *state = State::Idle;
+                    shell.cancel_drag();
+
+                    if let Some(on_release) = &self.on_release {
+                        shell.publish(on_release.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ if drag::is_drop(&event) => {
+                    *state = State::Idle;
+
+                    if let Some(on_release) = &self.on_release {
+                        shell.publish(on_release.clone());
+                    }
+                }
+                _ => {}
+            }
+
+            return event::Status::Ignored;
+        }
+
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(origin) = cursor.position_over(layout.bounds()) {
+                    *state = State::Pressed { origin };
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let State::Pressed { origin } = *state {
+                    if position.distance(origin) > DRAG_DEADBAND_DISTANCE {
+                        *state = State::Dragging {
+                            grab: origin - layout.position(),
+                            position,
+                        };
+
+                        shell.start_drag(drag::Payload::new(
+                            self.payload.clone(),
+                        ));
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag.clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ if drag::is_drop(&event) => {
+                *state = State::Idle;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let State::Dragging { .. } = state {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let content_interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if content_interaction == mouse::Interaction::None
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            content_interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let State::Dragging { grab, position } =
+            *tree.state.downcast_ref::<State>()
+        else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        };
+
+        let (preview, tree) = match &self.preview {
+            Some(preview) => (preview, &mut tree.children[1]),
+            None => (&self.content, &mut tree.children[0]),
+        };
+
+        Some(overlay::Element::new(Box::new(Preview {
+            preview,
+            tree,
+            size: layout.bounds().size(),
+            position: position - grab,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<DragSource<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        source: DragSource<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(source)
+    }
+}
+
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    preview: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    position: Point,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        self.preview
+            .as_widget()
+            .layout(
+                self.tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, self.size),
+            )
+            .move_to(self.position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.preview.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
@@ -0,0 +1,520 @@
+//! Drop targets receive the data dragged from a drag source.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, drag_source, drop_target, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dropped(usize),
+//! }
+//!
+//! fn view(_state: &State) -> Element<'_, Message> {
+//!     column![
+//!         drag_source(42, text("Drag me!")),
+//!         drop_target(text("Drop here!")).on_drop(Message::Dropped),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::drag;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Rectangle, Shell,
+    Size, Theme, Vector, Widget,
+};
+
+/// An element that accepts payloads of type `T` dragged from a
+/// [`DragSource`].
+///
+/// Payloads of any other type are ignored.
+///
+/// [`DragSource`]: crate::DragSource
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, drag_source, drop_target, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view(_state: &State) -> Element<'_, Message> {
+///     column![
+///         drag_source(42, text("Drag me!")),
+///         drop_target(text("Drop here!")).on_drop(Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_enter: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_exit: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Theme: Catalog,
+{
+    /// Creates a new [`DropTarget`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_enter: None,
+            on_exit: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped on
+    /// the [`DropTarget`].
+    ///
+    /// Drops handled by a nested [`DropTarget`] are not produced again.
+    pub fn on_drop(mut self, on_drop: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged
+    /// into the [`DropTarget`].
+    pub fn on_enter(mut self, on_enter: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged
+    /// out of the [`DropTarget`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropTarget`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_active: bool,
+    is_hovered: bool,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let payload = shell
+            .drag()
+            .and_then(drag::Payload::downcast_ref::<T>)
+            .cloned();
+
+        let state = tree.state.downcast_mut::<State>();
+        let was_hovered = state.is_hovered;
+
+        state.is_active = payload.is_some();
+        state.is_hovered = state.is_active && cursor.is_over(layout.bounds());
+
+        if let Some(payload) = &payload {
+            if state.is_hovered && drag::is_drop(&event) {
+                state.is_active = false;
+                state.is_hovered = false;
+
+                // Nested targets take precedence
+                if status == event::Status::Ignored {
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(payload.clone()));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                return status;
+            }
+
+            if state.is_hovered && !was_hovered {
+                if let Some(on_enter) = &self.on_enter {
+                    shell.publish(on_enter(payload.clone()));
+                }
+            }
+        }
+
+        if was_hovered && !state.is_hovered {
+            if let Some(on_exit) = &self.on_exit {
+                shell.publish(on_exit.clone());
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if state.is_hovered {
+            Status::Hovered
+        } else if state.is_active {
+            Status::Active
+        } else {
+            Status::Idle
+        };
+
+        let style = theme.style(&self.class, status);
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            let bounds = layout.bounds();
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<DropTarget<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        target: DropTarget<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(target)
+    }
+}
+
+/// The possible status of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No compatible payload is being dragged.
+    Idle,
+    /// A compatible payload is being dragged somewhere else.
+    Active,
+    /// A compatible payload is being dragged over the [`DropTarget`].
+    Hovered,
+}
+
+/// The appearance of a [`DropTarget`].
+///
+/// It is drawn on top of its contents.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`DropTarget`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`DropTarget`].
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropTarget`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropTarget`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropTarget`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Active => Style {
+            background: None,
+            border: border::rounded(2)
+                .width(1)
+                .color(palette.primary.weak.color),
+        },
+        Status::Hovered => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.2).into(),
+            ),
+            border: border::rounded(2)
+                .width(2)
+                .color(palette.primary.strong.color),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::Point;
+    use crate::Space;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Inner(u32),
+        Outer(u32),
+        Entered(u32),
+    }
+
+    type Target = DropTarget<'static, u32, Message, crate::Theme, ()>;
+
+    fn release(target: Target, payload: drag::Payload) -> Vec<Message> {
+        let mut target = Element::from(target);
+        let mut tree = Tree::new(&target);
+
+        let node = target.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages).with_drag(Some(&payload));
+
+        for event in [
+            mouse::Event::CursorMoved {
+                position: Point::new(5.0, 5.0),
+            },
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = target.as_widget_mut().on_event(
+                &mut tree,
+                Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(5.0, 5.0)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            );
+        }
+
+        messages
+    }
+
+    fn inner() -> Target {
+        DropTarget::new(Space::new(10, 10)).on_enter(Message::Entered)
+    }
+
+    #[test]
+    fn nested_targets_receive_drops_first() {
+        let target = DropTarget::new(inner().on_drop(Message::Inner))
+            .on_drop(Message::Outer);
+
+        assert_eq!(
+            release(target, drag::Payload::new(7_u32)),
+            vec![Message::Entered(7), Message::Inner(7)]
+        );
+    }
+
+    #[test]
+    fn unhandled_drops_reach_outer_targets() {
+        let target = DropTarget::new(inner()).on_drop(Message::Outer);
+
+        assert_eq!(
+            release(target, drag::Payload::new(7_u32)),
+            vec![Message::Entered(7), Message::Outer(7)]
+        );
+    }
+
+    #[test]
+    fn other_payloads_are_ignored() {
+        let target = DropTarget::new(inner().on_drop(Message::Inner))
+            .on_drop(Message::Outer);
+
+        assert!(release(target, drag::Payload::new("text")).is_empty());
+    }
+}
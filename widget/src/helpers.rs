@@ -1,13 +1,21 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Pixels, Widget};
+use crate::date_picker::{self, Date, DatePicker};
+use crate::drag_source::DragSource;
+use crate::drop_target::{self, DropTarget};
 use crate::keyed;
+use crate::list::List;
+use crate::menu::{self, MenuBar};
+use crate::number_input::NumberInput;
 use crate::overlay;
 use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
@@ -18,11 +26,14 @@ use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{Time, TimePicker};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
 use crate::{Column, MouseArea, Row, Space, Stack, Themer};
 
@@ -308,6 +319,117 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`List`] with the given amount of rows and a function
+/// that builds the row at a given index.
+///
+/// Lists only build the rows that are visible, which makes them suitable
+/// for very large collections.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{list, scrollable, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     scrollable(list(100_000, |i| text!("Row {i}").into()).row_height(20))
+///         .into()
+/// }
+/// ```
+pub fn list<'a, Message, Theme, Renderer>(
+    len: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> List<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    List::new(len, view)
+}
+
+/// Creates a new [`Table`] with the given columns and rows.
+///
+/// Tables display data in rows that share the same columns.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{table, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view<'a>(rows: &'a [(String, u32)]) -> Element<'a, Message> {
+///     table(
+///         [
+///             table::column(text("Name"), |(name, _): &(String, u32)| {
+///                 text(name).into()
+///             }),
+///             table::column(text("Score"), |(_, score): &(String, u32)| {
+///                 text(score).into()
+///             }),
+///         ],
+///         rows,
+///     )
+///     .into()
+/// }
+/// ```
+pub fn table<'a, T, Message, Theme, Renderer>(
+    columns: impl IntoIterator<
+        Item = table::Column<'a, T, Message, Theme, Renderer>,
+    >,
+    rows: impl IntoIterator<Item = T>,
+) -> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: table::Catalog,
+    Renderer: core::Renderer,
+{
+    Table::new(columns, rows)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// Tree views display hierarchical data that can be expanded, collapsed,
+/// and selected.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(&'static str, bool),
+/// }
+///
+/// fn view<'a>(is_expanded: bool) -> Element<'a, Message> {
+///     tree_view([Node::new("src", text("src"))
+///         .children([Node::new("main.rs", text("main.rs"))])
+///         .expanded(is_expanded)])
+///     .on_toggle(Message::Toggled)
+///     .into()
+/// }
+/// ```
+pub fn tree_view<'a, Key, Message, Theme, Renderer>(
+    nodes: impl IntoIterator<
+        Item = tree_view::Node<'a, Key, Message, Theme, Renderer>,
+    >,
+) -> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: tree_view::Catalog,
+    Renderer: core::Renderer,
+{
+    TreeView::new(nodes)
+}
+
 /// Creates a new [`Row`] from an iterator.
 ///
 /// Rows distribute their children horizontally.
@@ -879,6 +1001,71 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`ContextMenu`] that opens a menu with the given items
+/// when its content is right clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu::Item;
+/// use iced::widget::{context_menu, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     context_menu(text("file.txt"), [Item::new("Delete").on_select(Message::Delete)])
+///         .into()
+/// }
+/// ```
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`MenuBar`] with the given items.
+///
+/// Every item is displayed as a title that opens its submenu when clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu::Item;
+/// use iced::widget::menu_bar;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Quit,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     menu_bar([Item::submenu(
+///         "File",
+///         [Item::new("Quit").on_select(Message::Quit).accelerator("Ctrl+Q")],
+///     )])
+///     .into()
+/// }
+/// ```
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = menu::Item<Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(items)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// # Example
@@ -1210,6 +1397,52 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// Number inputs display fields to type and step through numeric values.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::number_input;
+///
+/// struct State {
+///    opacity: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpacityChanged(f32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     number_input(state.opacity, Message::OpacityChanged)
+///         .min(0.0)
+///         .max(1.0)
+///         .step(0.1)
+///         .into()
+/// }
+/// ```
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + From<u8>
+        + std::fmt::Display
+        + std::str::FromStr
+        + num_traits::ToPrimitive
+        + 'static,
+    Message: Clone,
+    Theme: text_input::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// Text editors display a multi-line text input for text editing.
@@ -1426,6 +1659,151 @@ where
     PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`DatePicker`].
+///
+/// Date pickers display a field that opens a calendar to select a [`Date`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::date_picker;
+/// use iced::widget::date_picker::Date;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     BirthdaySelected(Date),
+/// }
+///
+/// fn view(today: Date, birthday: Option<Date>) -> Element<'static, Message> {
+///     date_picker(birthday, Message::BirthdaySelected)
+///         .today(today)
+///         .max(today)
+///         .locale("en-US")
+///         .into()
+/// }
+/// ```
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    selected: Option<Date>,
+    on_select: impl Fn(Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(selected, on_select)
+}
+
+/// Creates a new [`DatePicker`] that selects a range of dates.
+///
+/// The message is produced with the first and last [`Date`] of the range.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::date_range_picker;
+/// use iced::widget::date_picker::{Date, Weekday};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VacationChanged(Date, Date),
+/// }
+///
+/// fn view(vacation: Option<(Date, Date)>) -> Element<'static, Message> {
+///     date_range_picker(vacation, Message::VacationChanged)
+///         .disabled(|date| {
+///             matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+///         })
+///         .into()
+/// }
+/// ```
+pub fn date_range_picker<'a, Message, Theme, Renderer>(
+    selected: Option<(Date, Date)>,
+    on_select: impl Fn(Date, Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::range(selected, on_select)
+}
+
+/// Creates a new [`TimePicker`].
+///
+/// Time pickers display a field that opens a popup to select a [`Time`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     MeetingScheduled(Time),
+/// }
+///
+/// fn view(meeting: Option<Time>) -> Element<'static, Message> {
+///     time_picker(meeting, Message::MeetingScheduled)
+///         .min(Time::from_hm(9, 0).unwrap())
+///         .max(Time::from_hm(17, 30).unwrap())
+///         .step(30)
+///         .into()
+/// }
+/// ```
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    selected: Option<Time>,
+    on_select: impl Fn(Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(selected, on_select)
+}
+
+/// Creates a new [`ColorPicker`].
+///
+/// Color pickers let users choose a [`Color`] by dragging through its hue,
+/// saturation, value and alpha, by typing it, or by picking a swatch.
+///
+/// [`Color`]: crate::core::Color
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::color_picker;
+/// use iced::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     BackgroundChanged(Color),
+/// }
+///
+/// fn view(background: Color) -> Element<'static, Message> {
+///     color_picker(background, Message::BackgroundChanged)
+///         .swatches([Color::WHITE, Color::BLACK])
+///         .into()
+/// }
+/// ```
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: color_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
 /// Creates a new [`ComboBox`].
 ///
 /// Combo boxes display a dropdown list of searchable and selectable options.
@@ -1828,6 +2206,31 @@ where
     MouseArea::new(widget)
 }
 
+/// An element that can be dragged to drop the given payload on a
+/// [`DropTarget`].
+pub fn drag_source<'a, T, Message, Theme, Renderer>(
+    payload: T,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragSource<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Renderer: core::Renderer,
+{
+    DragSource::new(payload, content)
+}
+
+/// An element that accepts the payloads of type `T` dropped on it.
+pub fn drop_target<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Theme: drop_target::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    DropTarget::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -1,16 +1,24 @@
 //! Keyed columns distribute content vertically while keeping continuity.
+use crate::core::animation::Animation;
+use crate::core::drag;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
-    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle,
-    Shell, Size, Vector, Widget,
+    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
 /// A container that distributes its contents vertically while keeping continuity.
 ///
 /// # Example
@@ -48,6 +56,9 @@ pub struct Column<
     align_items: Alignment,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(Reorder<Key>) -> Message + 'a>>,
+    payload: Option<fn(Key) -> drag::Payload>,
+    transition: Option<Duration>,
 }
 
 impl<'a, Key, Message, Theme, Renderer>
@@ -81,6 +92,9 @@ where
             align_items: Alignment::Start,
             keys,
             children,
+            on_reorder: None,
+            payload: None,
+            transition: None,
         }
     }
 
@@ -143,6 +157,37 @@ where
         self
     }
 
+    /// Makes the children of the [`Column`] reorderable by dragging them,
+    /// producing the given message when one is dropped in a new position.
+    ///
+    /// While dragging, the key of the child is the [`drag::Payload`], so it
+    /// can also be dropped on a [`DropTarget`] accepting keys.
+    ///
+    /// [`DropTarget`]: crate::DropTarget
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(Reorder<Key>) -> Message + 'a,
+    ) -> Self
+    where
+        Key: Send + Sync + 'static,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self.payload = Some(drag::Payload::new::<Key>);
+        self
+    }
+
+    /// Animates the children of the [`Column`] with the given duration
+    /// whenever they are inserted, removed or reordered.
+    ///
+    /// Moved children slide from their previous bounds to their new ones,
+    /// while new children fade and expand in. The space of removed children
+    /// collapses as the rest of the children take it; their state is kept
+    /// until then, in case they come back.
+    pub fn animate(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -196,11 +241,150 @@ where
     }
 }
 
+/// A request to move a child of a reorderable [`Column`].
+///
+/// The child should be removed at index `from` and then inserted at index
+/// `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder<Key> {
+    /// The key of the moved child.
+    pub key: Key,
+    /// The original index of the child.
+    pub from: usize,
+    /// The new index of the child.
+    pub to: usize,
+}
+
 struct State<Key>
 where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    drag: Drag,
+    motions: Vec<Motion>,
+    removed: Vec<Removed<Key>>,
+    is_stale: bool,
+    now: Instant,
+}
+
+/// The transition of a child of an animated [`Column`].
+#[derive(Debug, Clone, Copy)]
+struct Motion {
+    bounds: Option<Rectangle>,
+    offset: Animation<Vector>,
+    reveal: Animation<f32>,
+}
+
+impl Motion {
+    fn new() -> Self {
+        Self {
+            bounds: None,
+            offset: Animation::new(Vector::ZERO),
+            reveal: Animation::new(1.0),
+        }
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.offset.is_animating(now) || self.reveal.is_animating(now)
+    }
+}
+
+/// A removed child of an animated [`Column`] that is collapsing out.
+struct Removed<Key> {
+    key: Key,
+    tree: Tree,
+    /// The amount of remaining children laid out before it.
+    index: usize,
+    /// The space it took, including spacing.
+    height: f32,
+    collapse: Animation<f32>,
+}
+
+/// Reserves the collapsing space of the removed children of an animated
+/// [`Column`] in between its remaining children.
+fn collapse<Key>(
+    node: layout::Node,
+    removed: &[Removed<Key>],
+    max_height: f32,
+    now: Instant,
+) -> layout::Node {
+    let gap = |removed: &Removed<Key>| {
+        removed.height * removed.collapse.value(now).clamp(0.0, 1.0)
+    };
+
+    let total: f32 = removed.iter().map(gap).sum();
+
+    if total <= 0.0 {
+        return node;
+    }
+
+    let children = node
+        .children()
+        .iter()
+        .enumerate()
+        .map(|(index, child)| {
+            let offset: f32 = removed
+                .iter()
+                .filter(|removed| removed.index <= index)
+                .map(gap)
+                .sum();
+
+            child.clone().translate(Vector::new(0.0, offset))
+        })
+        .collect();
+
+    let size = node.size();
+
+    let mut collapsing = layout::Node::with_children(
+        Size::new(size.width, (size.height + total).min(max_height)),
+        children,
+    );
+
+    if let Some(&record) = node.record() {
+        collapsing.record_with(|new| *new = record);
+    }
+
+    collapsing
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Drag {
+    #[default]
+    Idle,
+    Pressed {
+        index: usize,
+        origin: Point,
+    },
+    Dragging {
+        index: usize,
+        origin: Point,
+        position: Point,
+    },
+}
+
+impl Drag {
+    fn dragging(self) -> Option<(usize, Vector)> {
+        match self {
+            Drag::Dragging {
+                index,
+                origin,
+                position,
+            } => Some((index, Vector::new(0.0, position.y - origin.y))),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the index a dragged child would be moved to if dropped at the
+/// given vertical position.
+fn target_index(layout: Layout<'_>, from: usize, y: f32) -> usize {
+    layout
+        .children()
+        .enumerate()
+        .filter(|(index, layout)| {
+            *index != from && layout.bounds().center_y() < y
+        })
+        .count()
 }
 
 impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -216,6 +400,11 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            drag: Drag::Idle,
+            motions: vec![Motion::new(); self.children.len()],
+            removed: Vec::new(),
+            is_stale: false,
+            now: Instant::now(),
         })
     }
 
@@ -230,6 +419,103 @@ where
 
         let state = state.downcast_mut::<State<Key>>();
 
+        // The dragged child may not exist anymore
+        if state.keys != self.keys {
+            state.drag = Drag::Idle;
+        }
+
+        if let Some(duration) = self.transition {
+            if state.keys == self.keys
+                && state.motions.len() == self.children.len()
+            {
+                for (tree, child) in children.iter_mut().zip(&self.children) {
+                    child.as_widget().diff(tree);
+                }
+
+                return;
+            }
+
+            let now = Instant::now();
+
+            let mut previous: Vec<_> = state
+                .keys
+                .drain(..)
+                .zip(children.drain(..))
+                .zip(
+                    state
+                        .motions
+                        .drain(..)
+                        .chain(std::iter::repeat(Motion::new())),
+                )
+                .map(|((key, tree), motion)| Some((key, tree, motion)))
+                .collect();
+
+            for (key, child) in self.keys.iter().zip(&self.children) {
+                let reused = previous
+                    .iter_mut()
+                    .find(|previous| {
+                        previous.as_ref().is_some_and(|(k, _, _)| k == key)
+                    })
+                    .and_then(Option::take);
+
+                let (tree, motion) = if let Some((_, tree, motion)) = reused {
+                    (tree, motion)
+                } else {
+                    let tree = state
+                        .removed
+                        .iter()
+                        .position(|removed| removed.key == *key)
+                        .map(|index| state.removed.remove(index).tree)
+                        .unwrap_or_else(|| Tree::new(child.as_widget()));
+
+                    let motion = Motion {
+                        reveal: Animation::new(0.0)
+                            .duration(duration)
+                            .go(1.0, now),
+                        ..Motion::new()
+                    };
+
+                    (tree, motion)
+                };
+
+                state.keys.push(*key);
+                children.push(tree);
+                state.motions.push(motion);
+            }
+
+            let mut kept = 0;
+
+            for entry in previous {
+                match entry {
+                    Some((key, tree, motion)) => {
+                        state.removed.push(Removed {
+                            key,
+                            tree,
+                            index: kept,
+                            height: motion
+                                .bounds
+                                .map(|bounds| bounds.height + self.spacing)
+                                .unwrap_or_default(),
+                            collapse: Animation::new(1.0)
+                                .duration(duration)
+                                .go(0.0, now),
+                        });
+                    }
+                    None => {
+                        kept += 1;
+                    }
+                }
+            }
+
+            for (tree, child) in children.iter_mut().zip(&self.children) {
+                child.as_widget().diff(tree);
+            }
+
+            state.is_stale = true;
+
+            return;
+        }
+
         tree::diff_children_custom_with_search(
             children,
             &self.children,
@@ -244,6 +530,9 @@ where
         if state.keys != self.keys {
             state.keys.clone_from(&self.keys);
         }
+
+        state.motions.clear();
+        state.removed.clear();
     }
 
     fn size(&self) -> Size<Length> {
@@ -264,7 +553,7 @@ where
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
+        let mut node = layout::flex::resolve(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
@@ -275,7 +564,37 @@ where
             self.align_items,
             &self.children,
             &mut tree.children,
-        )
+        );
+
+        if let Some(duration) = self.transition {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            let now = Instant::now();
+
+            node = collapse(node, &state.removed, limits.max().height, now);
+
+            for (motion, child) in state.motions.iter_mut().zip(node.children())
+            {
+                let bounds = child.bounds();
+
+                if let Some(previous) = motion.bounds.filter(|_| state.is_stale)
+                {
+                    let offset = previous.position() + motion.offset.value(now)
+                        - bounds.position();
+
+                    if offset != Vector::ZERO {
+                        motion.offset = Animation::new(offset)
+                            .duration(duration)
+                            .go(Vector::ZERO, now);
+                    }
+                }
+
+                motion.bounds = Some(bounds);
+            }
+
+            state.is_stale = false;
+        }
+
+        node
     }
 
     fn operate(
@@ -309,7 +628,92 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.children
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+
+            if !state.removed.is_empty() {
+                state
+                    .removed
+                    .retain(|removed| removed.collapse.is_animating(now));
+
+                shell.invalidate_layout();
+            }
+
+            if !state.removed.is_empty()
+                || state.motions.iter().any(|motion| motion.is_animating(now))
+            {
+                shell.request_redraw(window::RedrawRequest::At(now));
+            }
+        }
+
+        if let (Some(on_reorder), Drag::Dragging { index, origin, .. }) =
+            (&self.on_reorder, state.drag)
+        {
+            // The drag may have been dropped somewhere else
+            if shell.drag().is_none() {
+                state.drag = Drag::Idle;
+
+                return event::Status::Ignored;
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    state.drag = Drag::Dragging {
+                        index,
+                        origin,
+                        position,
+                    };
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) => {
+                    state.drag = Drag::Idle;
+                    shell.cancel_drag();
+                }
+                _ if drag::is_drop(&event) => {
+                    state.drag = Drag::Idle;
+
+                    if let Some(position) =
+                        cursor.position_over(layout.bounds())
+                    {
+                        let to = target_index(layout, index, position.y);
+
+                        if let Some(&key) =
+                            self.keys.get(index).filter(|_| to != index)
+                        {
+                            if let (Some(duration), Some(motion)) =
+                                (self.transition, state.motions.get_mut(index))
+                            {
+                                let translation =
+                                    Vector::new(0.0, position.y - origin.y);
+
+                                motion.offset = Animation::new(translation)
+                                    .duration(duration)
+                                    .go(Vector::ZERO, Instant::now());
+                            }
+
+                            shell.publish(on_reorder(Reorder {
+                                key,
+                                from: index,
+                                to,
+                            }));
+                        }
+                    }
+                }
+                _ => return event::Status::Ignored,
+            }
+
+            return event::Status::Captured;
+        }
+
+        let status = self
+            .children
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
@@ -325,7 +729,55 @@ where
                     viewport,
                 )
             })
-            .fold(event::Status::Ignored, event::Status::merge)
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let Some(payload) = self.payload else {
+            return status;
+        };
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored =>
+            {
+                if let Some(origin) = cursor.position() {
+                    if let Some(index) = layout
+                        .children()
+                        .position(|layout| layout.bounds().contains(origin))
+                    {
+                        state.drag = Drag::Pressed { index, origin };
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Drag::Pressed { index, origin } = state.drag {
+                    let key = self.keys.get(index).filter(|_| {
+                        position.distance(origin) > DRAG_DEADBAND_DISTANCE
+                    });
+
+                    if let Some(&key) = key {
+                        state.drag = Drag::Dragging {
+                            index,
+                            origin,
+                            position,
+                        };
+
+                        shell.start_drag(payload(key));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ if drag::is_drop(&event) => {
+                state.drag = Drag::Idle;
+            }
+            _ => {}
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -336,7 +788,14 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.children
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        if state.drag.dragging().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self
+            .children
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
@@ -346,7 +805,18 @@ where
                 )
             })
             .max()
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        if self.on_reorder.is_some()
+            && interaction == mouse::Interaction::None
+            && layout
+                .children()
+                .any(|layout| cursor.is_over(layout.bounds()))
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
     }
 
     fn draw(
@@ -359,16 +829,131 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((child, state), layout) in self
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let dragging = state.drag.dragging();
+
+        for (index, ((child, tree), layout)) in self
             .children
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
+            .enumerate()
         {
-            child
-                .as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
+            if dragging.is_some_and(|(dragged, _)| dragged == index) {
+                continue;
+            }
+
+            let motion = state.motions.get(index).filter(|motion| {
+                self.transition.is_some() && motion.is_animating(state.now)
+            });
+
+            let Some(motion) = motion else {
+                child.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+
+                continue;
+            };
+
+            let offset = motion.offset.value(state.now);
+            let reveal = motion.reveal.value(state.now).clamp(0.0, 1.0);
+
+            let bounds = layout.bounds();
+            let clip = Rectangle {
+                height: bounds.height * reveal,
+                ..bounds
+            } + offset;
+
+            renderer.with_layer(
+                clip.intersection(viewport)
+                    .unwrap_or(Rectangle::new(clip.position(), Size::ZERO)),
+                |renderer| {
+                    renderer.with_opacity(reveal, |renderer| {
+                        renderer.with_translation(offset, |renderer| {
+                            child.as_widget().draw(
+                                tree, renderer, theme, style, layout, cursor,
+                                viewport,
+                            );
+                        });
+                    });
+                },
+            );
         }
+
+        let Some((index, translation)) = dragging else {
+            return;
+        };
+
+        let (Some(child), Some(child_tree), Some(child_layout)) = (
+            self.children.get(index),
+            tree.children.get(index),
+            layout.children().nth(index),
+        ) else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let dragged = child_layout.bounds();
+
+        if let Some(position) = cursor.position_over(bounds) {
+            let to = target_index(layout, index, position.y);
+
+            let mut others = layout
+                .children()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, layout)| layout.bounds());
+
+            let y = match others.nth(to) {
+                Some(next) => next.y - self.spacing / 2.0,
+                None => layout
+                    .children()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .last()
+                    .map(|(_, last)| {
+                        let last = last.bounds();
+
+                        last.y + last.height + self.spacing / 2.0
+                    })
+                    .unwrap_or(dragged.y),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + self.padding.left,
+                        y: y - 1.0,
+                        width: bounds.width - self.padding.horizontal(),
+                        height: 2.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.text_color,
+            );
+        }
+
+        let translation = Vector::new(
+            0.0,
+            translation
+                .y
+                .min(bounds.y + bounds.height - dragged.y - dragged.height)
+                .max(bounds.y - dragged.y),
+        );
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                child.as_widget().draw(
+                    child_tree,
+                    renderer,
+                    theme,
+                    style,
+                    child_layout,
+                    cursor,
+                    viewport,
+                );
+            });
+        });
     }
 
     fn overlay<'b>(
@@ -401,3 +986,64 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    type Element = crate::core::Element<'static, (), crate::Theme, ()>;
+
+    fn column(keys: &[u32]) -> Element {
+        Column::with_children(
+            keys.iter()
+                .map(|key| (*key, Space::new(Length::Fill, 10).into())),
+        )
+        .spacing(5)
+        .animate(Duration::from_secs(60))
+        .into()
+    }
+
+    fn layout(column: &Element, tree: &mut Tree) -> layout::Node {
+        column.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 1000.0)),
+        )
+    }
+
+    #[test]
+    fn animates_insertions_and_removals() {
+        let before = column(&[1, 2, 3]);
+        let mut tree = Tree::new(&before);
+        let _ = layout(&before, &mut tree);
+
+        let after = column(&[1, 3, 4]);
+        after.as_widget().diff(&mut tree);
+
+        let now = Instant::now();
+        let state = tree.state.downcast_ref::<State<u32>>();
+
+        assert_eq!(state.removed.len(), 1);
+        assert_eq!(state.removed[0].key, 2);
+        assert_eq!(state.removed[0].index, 1);
+        assert_eq!(state.removed[0].height, 15.0);
+        assert!(state.removed[0].collapse.is_animating(now));
+
+        assert!(!state.motions[0].is_animating(now));
+        assert!(state.motions[2].reveal.is_animating(now));
+        assert!(state.motions[2].reveal.value(now) < 0.1);
+
+        let node = layout(&after, &mut tree);
+        let offsets: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| child.bounds().y)
+            .collect();
+
+        assert_eq!(offsets[0], 0.0);
+        assert!((offsets[1] - 30.0).abs() < 0.1);
+        assert!((offsets[2] - 45.0).abs() < 0.1);
+        assert!((node.size().height - 55.0).abs() < 0.1);
+    }
+}
@@ -323,7 +323,8 @@ where
         viewport: &Rectangle,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag());
 
         let t = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
         let event_status = self.with_element_mut(|element| {
@@ -598,7 +599,8 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag());
 
         let event_status = self
             .with_overlay_mut_maybe(|overlay| {
@@ -195,7 +195,8 @@ where
         let mut content = self.content.borrow_mut();
 
         let mut local_messages = vec![];
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell =
+            Shell::new(&mut local_messages).with_drag(shell.drag());
 
         let status = content.resolve(
             &mut state.tree.borrow_mut(),
@@ -0,0 +1,664 @@
+//! Lists display very large collections by only building the visible rows.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{list, scrollable, text};
+//!
+//! struct State {
+//!    lines: Vec<String>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(
+//!         list(state.lines.len(), |i| text(&state.lines[i]).into())
+//!             .row_height(20),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A vertical list of rows that are built lazily, as they become visible.
+///
+/// Only the rows inside the current viewport—plus some overscan—are built
+/// and laid out. Place it inside a [`Scrollable`] to browse collections of
+/// any size.
+///
+/// By default, rows are identified by their index. Use [`List::key`] to keep
+/// the state of rows with the identity of their items instead.
+///
+/// [`Scrollable`]: crate::Scrollable
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{list, scrollable, text};
+///
+/// struct State {
+///    lines: Vec<String>,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(
+///         list(state.lines.len(), |i| text(&state.lines[i]).into())
+///             .row_height(20),
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct List<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    len: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Box<dyn Fn(usize) -> Key + 'a>,
+    row_height: RowHeight,
+    overscan: usize,
+    width: Length,
+    rows: RefCell<Rows<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> List<'a, usize, Message, Theme, Renderer> {
+    /// The default amount of rows built outside of the viewport.
+    pub const DEFAULT_OVERSCAN: usize = 5;
+
+    /// Creates a new [`List`] with the given amount of rows and a function
+    /// that builds the row at a given index.
+    pub fn new(
+        len: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            len,
+            view: Box::new(view),
+            key: Box::new(|index| index),
+            row_height: RowHeight::default(),
+            overscan: Self::DEFAULT_OVERSCAN,
+            width: Length::Fill,
+            rows: RefCell::new(Rows::default()),
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    List<'a, Key, Message, Theme, Renderer>
+{
+    /// Identifies the row at a given index with the returned key.
+    ///
+    /// The widget state of a row follows its key when rows are inserted,
+    /// removed, or scrolled out of view and back.
+    pub fn key<K>(
+        self,
+        key: impl Fn(usize) -> K + 'a,
+    ) -> List<'a, K, Message, Theme, Renderer> {
+        List {
+            len: self.len,
+            view: self.view,
+            key: Box::new(key),
+            row_height: self.row_height,
+            overscan: self.overscan,
+            width: self.width,
+            rows: self.rows,
+        }
+    }
+
+    /// Sets a fixed height for every row of the [`List`].
+    ///
+    /// Rows are laid out with exactly this height.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`List`].
+    ///
+    /// Rows are measured once built; the estimation is only used for the
+    /// rows that have never been visible.
+    ///
+    /// Measurements are remembered by the key of each row, so use
+    /// [`List::key`] to keep them when rows are inserted or removed.
+    pub fn estimated_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Estimated(height.into().0);
+        self
+    }
+
+    /// Sets the amount of rows built before and after the viewport.
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+/// The height strategy of the rows of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RowHeight {
+    Fixed(f32),
+    Estimated(f32),
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Estimated(30.0)
+    }
+}
+
+/// The rows currently built by a [`List`].
+struct Rows<'a, Message, Theme, Renderer> {
+    range: Range<usize>,
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<Message, Theme, Renderer> Default for Rows<'_, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self {
+            range: 0..0,
+            elements: Vec::new(),
+        }
+    }
+}
+
+struct State<Key> {
+    range: Range<usize>,
+    keys: Vec<Key>,
+    heights: Vec<Option<(Key, f32)>>,
+    offsets: Vec<f32>,
+    viewport: Option<(f32, f32)>,
+}
+
+impl<Key> State<Key> {
+    fn offset(&self, index: usize) -> f32 {
+        self.offsets.get(index).copied().unwrap_or_default()
+    }
+
+    /// Returns the rows visible between the given vertical offsets.
+    fn visible(&self, top: f32, bottom: f32) -> Range<usize> {
+        let len = self.offsets.len().saturating_sub(1);
+
+        let start = self.offsets.partition_point(|offset| *offset <= top);
+        let end = self.offsets.partition_point(|offset| *offset < bottom);
+
+        start.saturating_sub(1).min(len)..end.min(len)
+    }
+
+    /// Returns the rows that must be built to cover the last known
+    /// viewport, if they are not built already.
+    fn outdated(&self, overscan: usize, len: usize) -> Option<Range<usize>> {
+        let (top, bottom) = self.viewport?;
+        let visible = self.visible(top, bottom);
+
+        if visible.is_empty()
+            || (visible.start >= self.range.start
+                && visible.end <= self.range.end)
+        {
+            return None;
+        }
+
+        Some(
+            visible.start.saturating_sub(overscan)
+                ..(visible.end + overscan).min(len),
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: crate::core::Renderer,
+{
+    /// Builds the rows in the current range of the [`State`], reusing the
+    /// widget state of rows with matching keys.
+    fn build(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        state.heights.resize(self.len, None);
+        state.range.end = state.range.end.min(self.len);
+        state.range.start = state.range.start.min(state.range.end);
+
+        let mut rows = self.rows.borrow_mut();
+
+        if rows.range == state.range && rows.elements.len() == rows.range.len()
+        {
+            return;
+        }
+
+        let mut old_rows = std::mem::take(&mut *rows);
+        let mut old_trees = std::mem::take(&mut tree.children);
+        let old_keys = std::mem::take(&mut state.keys);
+
+        for index in state.range.clone() {
+            let key = (self.key)(index);
+
+            let element = if old_rows.range.contains(&index) {
+                let position = index - old_rows.range.start;
+
+                std::mem::replace(
+                    &mut old_rows.elements[position],
+                    Element::new(crate::Space::new(0, 0)),
+                )
+            } else {
+                (self.view)(index)
+            };
+
+            let row = match old_keys.iter().position(|old| *old == key) {
+                Some(position) => {
+                    let mut row = std::mem::replace(
+                        &mut old_trees[position],
+                        Tree::empty(),
+                    );
+
+                    row.diff(element.as_widget());
+                    row
+                }
+                None => Tree::new(element.as_widget()),
+            };
+
+            state.keys.push(key);
+            rows.elements.push(element);
+            tree.children.push(row);
+        }
+
+        rows.range = state.range.clone();
+    }
+
+    /// Lays out the built rows and updates the offsets of every row.
+    fn measure(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        width: f32,
+    ) -> Vec<layout::Node> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+        let rows = self.rows.borrow();
+
+        let (row_limits, estimation) = match self.row_height {
+            RowHeight::Fixed(height) => (
+                layout::Limits::new(
+                    Size::new(0.0, height),
+                    Size::new(width, height),
+                ),
+                height,
+            ),
+            RowHeight::Estimated(height) => (
+                layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width, f32::INFINITY),
+                ),
+                height,
+            ),
+        };
+
+        let nodes: Vec<_> = rows
+            .elements
+            .iter()
+            .zip(children.iter_mut())
+            .map(|(row, tree)| {
+                row.as_widget().layout(tree, renderer, &row_limits)
+            })
+            .collect();
+
+        for ((index, key), node) in
+            state.range.clone().zip(&state.keys).zip(&nodes)
+        {
+            state.heights[index] = Some((*key, node.size().height));
+        }
+
+        state.offsets.clear();
+        state.offsets.push(0.0);
+
+        let mut offset = 0.0;
+
+        for (index, height) in state.heights.iter().enumerate() {
+            offset += match (self.row_height, height) {
+                (RowHeight::Fixed(height), _) => height,
+                (RowHeight::Estimated(_), Some((key, height)))
+                    if *key == (self.key)(index) =>
+                {
+                    *height
+                }
+                (RowHeight::Estimated(_), _) => estimation,
+            };
+
+            state.offsets.push(offset);
+        }
+
+        nodes
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key> {
+            range: 0..self.overscan * 2,
+            keys: Vec::new(),
+            heights: Vec::new(),
+            offsets: Vec::new(),
+            viewport: None,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.build(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.build(tree);
+
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+
+        let mut nodes = self.measure(tree, renderer, width);
+
+        // The measured rows may move the viewport over rows that are not built
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        if let Some(range) = state.outdated(self.overscan, self.len) {
+            state.range = range;
+
+            self.build(tree);
+            nodes = self.measure(tree, renderer, width);
+        }
+
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let height = state.offsets.last().copied().unwrap_or_default();
+
+        let children = nodes
+            .into_iter()
+            .zip(state.range.clone())
+            .map(|(node, index)| node.move_to((0.0, state.offset(index))))
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(0.0, height));
+
+        layout::Node::with_children(Size::new(size.width, height), children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let rows = self.rows.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            rows.elements
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self
+            .rows
+            .get_mut()
+            .elements
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let bounds = layout.bounds();
+
+        state.viewport = Some((
+            viewport.y - bounds.y,
+            viewport.y + viewport.height - bounds.y,
+        ));
+
+        if let Some(range) = state.outdated(self.overscan, self.len) {
+            state.range = range;
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .borrow()
+            .elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((row, state), layout) in self
+            .rows
+            .borrow()
+            .elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            if layout.bounds().intersects(viewport) {
+                row.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.rows.get_mut().elements,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<List<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    type Element = crate::core::Element<'static, (), crate::Theme, ()>;
+
+    fn list(keys: Vec<u32>) -> Element {
+        let heights = keys.clone();
+
+        List::new(keys.len(), move |index| {
+            Space::new(Length::Fill, heights[index] as f32).into()
+        })
+        .key(move |index| keys[index])
+        .estimated_row_height(5)
+        .overscan(1)
+        .into()
+    }
+
+    fn layout(list: &Element, tree: &mut Tree) -> layout::Node {
+        list.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        )
+    }
+
+    #[test]
+    fn visible_rows() {
+        let state = State::<usize> {
+            range: 0..0,
+            keys: Vec::new(),
+            heights: vec![None; 4],
+            offsets: vec![0.0, 10.0, 20.0, 30.0, 40.0],
+            viewport: None,
+        };
+
+        assert_eq!(state.visible(0.0, 10.0), 0..1);
+        assert_eq!(state.visible(5.0, 25.0), 0..3);
+        assert_eq!(state.visible(10.0, 20.0), 1..2);
+        assert_eq!(state.visible(35.0, 100.0), 3..4);
+        assert_eq!(state.visible(100.0, 200.0), 4..4);
+    }
+
+    #[test]
+    fn layout_builds_rows_under_the_last_viewport() {
+        let list = list(vec![5; 200]);
+        let mut tree = Tree::new(&list);
+        let _ = layout(&list, &mut tree);
+
+        // The viewport moved past the built rows since the last layout
+        tree.state.downcast_mut::<State<u32>>().viewport = Some((500.0, 530.0));
+
+        let node = layout(&list, &mut tree);
+        let state = tree.state.downcast_ref::<State<u32>>();
+
+        assert_eq!(state.range, 99..107);
+        assert_eq!(node.children().len(), 8);
+        assert_eq!(node.children()[0].bounds().y, 495.0);
+    }
+
+    #[test]
+    fn heights_follow_keys() {
+        let before = list((1..=10).collect());
+        let mut tree = Tree::new(&before);
+        let _ = layout(&before, &mut tree);
+
+        let state = tree.state.downcast_ref::<State<u32>>();
+        assert_eq!(&state.offsets[..4], &[0.0, 1.0, 3.0, 8.0]);
+
+        // Remove the first row while the rows at the start are not built
+        let after = list((2..=10).collect());
+        tree.state.downcast_mut::<State<u32>>().range = 6..8;
+        after.as_widget().diff(&mut tree);
+        let _ = layout(&after, &mut tree);
+
+        let state = tree.state.downcast_ref::<State<u32>>();
+        assert_eq!(&state.offsets[..3], &[0.0, 5.0, 10.0]);
+    }
+}
@@ -47,14 +47,19 @@
 //!     }
 //! }
 //! ```
+use crate::core::alignment;
 use crate::core::border;
 use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme;
 use crate::core::{
-    self, color, Color, Element, Length, Padding, Pixels, Theme,
+    self, color, Background, Border, Color, Element, Length, Padding, Pixels,
+    Theme,
+};
+use crate::{
+    checkbox, column, container, horizontal_rule, rich_text, row, rule,
+    scrollable, span, text,
 };
-use crate::{column, container, rich_text, row, scrollable, span, text};
 
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
@@ -79,8 +84,66 @@ pub enum Item {
         /// The first number of the list, if it is ordered.
         start: Option<u64>,
         /// The items of the list.
-        items: Vec<Vec<Item>>,
+        items: Vec<Bullet>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A table.
+    Table {
+        /// The horizontal alignment of each column.
+        alignments: Vec<alignment::Horizontal>,
+        /// The cells of the header row.
+        header: Vec<Text>,
+        /// The cells of each row of the body.
+        rows: Vec<Vec<Text>>,
+    },
+    /// An image.
+    ///
+    /// Fetching and decoding the image is left to the application;
+    /// [`view`] displays its alternative text, while [`view_with`]
+    /// lets you display the image itself.
+    Image {
+        /// The source of the image, as written in the Markdown.
+        source: String,
+        /// The title of the image.
+        title: String,
+        /// The alternative text of the image.
+        alt: Text,
     },
+    /// A horizontal rule.
+    Rule,
+}
+
+/// A bullet of a Markdown list.
+#[derive(Debug, Clone)]
+pub enum Bullet {
+    /// A simple bullet point.
+    Point {
+        /// The items of the bullet.
+        items: Vec<Item>,
+    },
+    /// A task of a task list.
+    Task {
+        /// The items of the task.
+        items: Vec<Item>,
+        /// Whether the task is done or not.
+        done: bool,
+    },
+}
+
+impl Bullet {
+    /// Returns the items of the [`Bullet`].
+    pub fn items(&self) -> &[Item] {
+        match self {
+            Bullet::Point { items } | Bullet::Task { items, .. } => items,
+        }
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Item> {
+        match self {
+            Bullet::Point { items } | Bullet::Task { items, .. } => items,
+        }
+    }
 }
 
 /// A bunch of parsed Markdown text.
@@ -234,7 +297,24 @@ impl Span {
 pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     struct List {
         start: Option<u64>,
-        items: Vec<Vec<Item>>,
+        items: Vec<Bullet>,
+    }
+
+    struct Table {
+        alignments: Vec<alignment::Horizontal>,
+        header: Vec<Text>,
+        rows: Vec<Vec<Text>>,
+    }
+
+    struct Image {
+        source: String,
+        title: String,
+        first_span: usize,
+    }
+
+    enum Scope {
+        List(List),
+        Quote(Vec<Item>),
     }
 
     let mut spans = Vec::new();
@@ -242,9 +322,10 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     let mut emphasis = false;
     let mut strikethrough = false;
     let mut metadata = false;
-    let mut table = false;
+    let mut table = None;
+    let mut image = None;
     let mut link = None;
-    let mut lists = Vec::new();
+    let mut scopes = Vec::new();
 
     #[cfg(feature = "highlighter")]
     let mut highlighter = None;
@@ -254,21 +335,24 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
         pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_TABLES
-            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH
+            | pulldown_cmark::Options::ENABLE_TASKLISTS,
     );
 
-    let produce = |lists: &mut Vec<List>, item| {
-        if lists.is_empty() {
-            Some(item)
-        } else {
-            lists
+    let produce = |scopes: &mut Vec<Scope>, item| match scopes.last_mut() {
+        None => Some(item),
+        Some(Scope::List(list)) => {
+            list.items
                 .last_mut()
-                .expect("list context")
-                .items
-                .last_mut()
-                .expect("item context")
+                .expect("bullet context")
+                .items_mut()
                 .push(item);
 
+            None
+        }
+        Some(Scope::Quote(items)) => {
+            items.push(item);
+
             None
         }
     };
@@ -277,21 +361,19 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     #[allow(clippy::drain_collect)]
     parser.filter_map(move |event| match event {
         pulldown_cmark::Event::Start(tag) => match tag {
-            pulldown_cmark::Tag::Strong if !metadata && !table => {
+            pulldown_cmark::Tag::Strong if !metadata => {
                 strong = true;
                 None
             }
-            pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+            pulldown_cmark::Tag::Emphasis if !metadata => {
                 emphasis = true;
                 None
             }
-            pulldown_cmark::Tag::Strikethrough if !metadata && !table => {
+            pulldown_cmark::Tag::Strikethrough if !metadata => {
                 strikethrough = true;
                 None
             }
-            pulldown_cmark::Tag::Link { dest_url, .. }
-                if !metadata && !table =>
-            {
+            pulldown_cmark::Tag::Link { dest_url, .. } if !metadata => {
                 match Url::parse(&dest_url) {
                     Ok(url)
                         if url.scheme() == "http"
@@ -304,25 +386,39 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
                 None
             }
-            pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
-                lists.push(List {
+            pulldown_cmark::Tag::Image {
+                dest_url, title, ..
+            } if !metadata => {
+                image = Some(Image {
+                    source: dest_url.into_string(),
+                    title: title.into_string(),
+                    first_span: spans.len(),
+                });
+
+                None
+            }
+            pulldown_cmark::Tag::List(first_item) if !metadata => {
+                scopes.push(Scope::List(List {
                     start: first_item,
                     items: Vec::new(),
-                });
+                }));
 
                 None
             }
             pulldown_cmark::Tag::Item => {
-                lists
-                    .last_mut()
-                    .expect("list context")
-                    .items
-                    .push(Vec::new());
+                if let Some(Scope::List(list)) = scopes.last_mut() {
+                    list.items.push(Bullet::Point { items: Vec::new() });
+                }
+
+                None
+            }
+            pulldown_cmark::Tag::BlockQuote(_) if !metadata => {
+                scopes.push(Scope::Quote(Vec::new()));
                 None
             }
             pulldown_cmark::Tag::CodeBlock(
                 pulldown_cmark::CodeBlockKind::Fenced(_language),
-            ) if !metadata && !table => {
+            ) if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     use iced_highlighter::Highlighter;
@@ -341,70 +437,133 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 metadata = true;
                 None
             }
-            pulldown_cmark::Tag::Table(_) => {
-                table = true;
+            pulldown_cmark::Tag::Table(alignments) if !metadata => {
+                table = Some(Table {
+                    alignments: alignments
+                        .into_iter()
+                        .map(|alignment| match alignment {
+                            pulldown_cmark::Alignment::None
+                            | pulldown_cmark::Alignment::Left => {
+                                alignment::Horizontal::Left
+                            }
+                            pulldown_cmark::Alignment::Center => {
+                                alignment::Horizontal::Center
+                            }
+                            pulldown_cmark::Alignment::Right => {
+                                alignment::Horizontal::Right
+                            }
+                        })
+                        .collect(),
+                    header: Vec::new(),
+                    rows: Vec::new(),
+                });
+
+                None
+            }
+            pulldown_cmark::Tag::TableHead if !metadata => {
+                strong = true;
+                None
+            }
+            pulldown_cmark::Tag::TableRow if !metadata => {
+                if let Some(table) = &mut table {
+                    table.rows.push(Vec::new());
+                }
+
                 None
             }
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => {
-                produce(
-                    &mut lists,
-                    Item::Heading(level, Text::new(spans.drain(..).collect())),
-                )
-            }
-            pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => produce(
+                &mut scopes,
+                Item::Heading(level, Text::new(spans.drain(..).collect())),
+            ),
+            pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
             }
-            pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+            pulldown_cmark::TagEnd::Emphasis if !metadata => {
                 emphasis = false;
                 None
             }
-            pulldown_cmark::TagEnd::Strikethrough if !metadata && !table => {
+            pulldown_cmark::TagEnd::Strikethrough if !metadata => {
                 strikethrough = false;
                 None
             }
-            pulldown_cmark::TagEnd::Link if !metadata && !table => {
+            pulldown_cmark::TagEnd::Link if !metadata => {
                 link = None;
                 None
             }
-            pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
-                produce(
-                    &mut lists,
-                    Item::Paragraph(Text::new(spans.drain(..).collect())),
-                )
+            pulldown_cmark::TagEnd::Image if !metadata => {
+                let Image {
+                    source,
+                    title,
+                    first_span,
+                } = image.take()?;
+
+                // Images starting a block are produced on their own;
+                // otherwise, their alternative text is kept inline
+                if first_span == 0 && table.is_none() {
+                    produce(
+                        &mut scopes,
+                        Item::Image {
+                            source,
+                            title,
+                            alt: Text::new(spans.drain(..).collect()),
+                        },
+                    )
+                } else {
+                    None
+                }
             }
-            pulldown_cmark::TagEnd::Item if !metadata && !table => {
+            pulldown_cmark::TagEnd::Paragraph if !metadata => {
                 if spans.is_empty() {
                     None
                 } else {
                     produce(
-                        &mut lists,
+                        &mut scopes,
                         Item::Paragraph(Text::new(spans.drain(..).collect())),
                     )
                 }
             }
-            pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-                let list = lists.pop().expect("list context");
+            pulldown_cmark::TagEnd::Item if !metadata => {
+                if spans.is_empty() {
+                    None
+                } else {
+                    produce(
+                        &mut scopes,
+                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                    )
+                }
+            }
+            pulldown_cmark::TagEnd::List(_) if !metadata => {
+                let Some(Scope::List(list)) = scopes.pop() else {
+                    return None;
+                };
 
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::List {
                         start: list.start,
                         items: list.items,
                     },
                 )
             }
-            pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+            pulldown_cmark::TagEnd::BlockQuote if !metadata => {
+                let Some(Scope::Quote(items)) = scopes.pop() else {
+                    return None;
+                };
+
+                produce(&mut scopes, Item::Quote(items))
+            }
+            pulldown_cmark::TagEnd::CodeBlock if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     highlighter = None;
                 }
 
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::CodeBlock(Text::new(spans.drain(..).collect())),
                 )
             }
@@ -412,13 +571,36 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 metadata = false;
                 None
             }
-            pulldown_cmark::TagEnd::Table => {
-                table = false;
+            pulldown_cmark::TagEnd::TableCell if !metadata => {
+                let table = table.as_mut()?;
+                let cell = Text::new(spans.drain(..).collect());
+
+                match table.rows.last_mut() {
+                    Some(row) => row.push(cell),
+                    None => table.header.push(cell),
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::TableHead if !metadata => {
+                strong = false;
                 None
             }
+            pulldown_cmark::TagEnd::Table if !metadata => {
+                let table = table.take()?;
+
+                produce(
+                    &mut scopes,
+                    Item::Table {
+                        alignments: table.alignments,
+                        header: table.header,
+                        rows: table.rows,
+                    },
+                )
+            }
             _ => None,
         },
-        pulldown_cmark::Event::Text(text) if !metadata && !table => {
+        pulldown_cmark::Event::Text(text) if !metadata => {
             #[cfg(feature = "highlighter")]
             if let Some(highlighter) = &mut highlighter {
                 use text::Highlighter as _;
@@ -451,7 +633,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
             None
         }
-        pulldown_cmark::Event::Code(code) if !metadata && !table => {
+        pulldown_cmark::Event::Code(code) if !metadata => {
             let span = Span::Standard {
                 text: code.into_string(),
                 strong,
@@ -464,7 +646,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             spans.push(span);
             None
         }
-        pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+        pulldown_cmark::Event::SoftBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from(" "),
                 strikethrough,
@@ -475,7 +657,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
-        pulldown_cmark::Event::HardBreak if !metadata && !table => {
+        pulldown_cmark::Event::HardBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from("\n"),
                 strikethrough,
@@ -486,6 +668,20 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
+        pulldown_cmark::Event::TaskListMarker(done) if !metadata => {
+            if let Some(Scope::List(list)) = scopes.last_mut() {
+                if let Some(bullet) = list.items.last_mut() {
+                    let items = std::mem::take(bullet.items_mut());
+
+                    *bullet = Bullet::Task { items, done };
+                }
+            }
+
+            None
+        }
+        pulldown_cmark::Event::Rule if !metadata => {
+            produce(&mut scopes, Item::Rule)
+        }
         _ => None,
     })
 }
@@ -550,11 +746,19 @@ pub struct Style {
     pub inline_code_color: Color,
     /// The [`Color`] to be applied to links.
     pub link_color: Color,
+    /// The [`Background`] of block quotes.
+    pub quote_background: Background,
+    /// The [`Border`] of block quotes.
+    pub quote_border: Border,
+    /// The [`Border`] of tables.
+    pub table_border: Border,
 }
 
 impl Style {
     /// Creates a new [`Style`] from the given [`theme::Palette`].
     pub fn from_palette(palette: theme::Palette) -> Self {
+        let extended = theme::palette::Extended::generate(palette);
+
         Self {
             inline_code_padding: padding::left(1).right(1),
             inline_code_highlight: Highlight {
@@ -563,6 +767,11 @@ impl Style {
             },
             inline_code_color: Color::WHITE,
             link_color: palette.primary,
+            quote_background: extended.background.weak.color.into(),
+            quote_border: border::rounded(2),
+            table_border: border::rounded(5)
+                .width(1)
+                .color(extended.background.strong.color),
         }
     }
 }
@@ -620,6 +829,49 @@ pub fn view<'a, Theme, Renderer>(
 ) -> Element<'a, Url, Theme, Renderer>
 where
     Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with(items, settings, style, |_source, _title, alt| {
+        rich_text(alt.spans(style)).size(settings.text_size).into()
+    })
+}
+
+/// Display a bunch of Markdown items, using the given function to
+/// display each [`Item::Image`].
+///
+/// The function receives the source, the title, and the alternative
+/// text of the image.
+pub fn view_with<'a, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    style: Style,
+    image: impl Fn(&'a str, &'a str, &'a Text) -> Element<'a, Url, Theme, Renderer>,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    blocks(items, settings, style, &image)
+}
+
+fn blocks<'a, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    style: Style,
+    image: &dyn Fn(
+        &'a str,
+        &'a str,
+        &'a Text,
+    ) -> Element<'a, Url, Theme, Renderer>,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
     Renderer: core::text::Renderer<Font = Font> + 'a,
 {
     let Settings {
@@ -655,28 +907,75 @@ where
         Item::Paragraph(paragraph) => {
             rich_text(paragraph.spans(style)).size(text_size).into()
         }
-        Item::List { start: None, items } => {
-            column(items.iter().map(|items| {
-                row![text("•").size(text_size), view(items, settings, style)]
+        Item::List { start, items } => {
+            column(items.iter().enumerate().map(|(i, bullet)| {
+                let task = match bullet {
+                    Bullet::Point { .. } => None,
+                    Bullet::Task { done, .. } => {
+                        Some(checkbox("", *done).size(text_size).spacing(0))
+                    }
+                };
+
+                // Unordered tasks are marked by their checkbox alone
+                let marker = match start {
+                    Some(start) => Some(text!("{}.", i as u64 + *start)),
+                    None if task.is_some() => None,
+                    None => Some(text("•")),
+                };
+
+                row![]
+                    .push_maybe(marker.map(|marker| marker.size(text_size)))
+                    .push_maybe(task)
+                    .push(blocks(bullet.items(), settings, style, image))
                     .spacing(spacing)
                     .into()
             }))
             .spacing(spacing)
             .into()
         }
-        Item::List {
-            start: Some(start),
-            items,
-        } => column(items.iter().enumerate().map(|(i, items)| {
-            row![
-                text!("{}.", i as u64 + *start).size(text_size),
-                view(items, settings, style)
-            ]
-            .spacing(spacing)
+        Item::Quote(items) => container(blocks(items, settings, style, image))
+            .width(Length::Fill)
+            .padding(spacing.0)
+            .style(move |_theme| container::Style {
+                background: Some(style.quote_background),
+                border: style.quote_border,
+                ..container::Style::default()
+            })
+            .into(),
+        Item::Table {
+            alignments,
+            header,
+            rows,
+        } => {
+            let cells = |cells: &'a [Text]| {
+                row(cells.iter().zip(alignments).map(|(cell, alignment)| {
+                    container(rich_text(cell.spans(style)).size(text_size))
+                        .width(Length::Fill)
+                        .align_x(*alignment)
+                        .padding(spacing.0 / 2.0)
+                        .into()
+                }))
+                .width(Length::Fill)
+                .into()
+            };
+
+            let body = rows
+                .iter()
+                .flat_map(|row| [horizontal_rule(1).into(), cells(row)]);
+
+            container(
+                column(std::iter::once(cells(header)).chain(body))
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .style(move |_theme| container::Style {
+                border: style.table_border,
+                ..container::Style::default()
+            })
             .into()
-        }))
-        .spacing(spacing)
-        .into(),
+        }
+        Item::Image { source, title, alt } => image(source, title, alt),
+        Item::Rule => horizontal_rule(1).into(),
         Item::CodeBlock(code) => container(
             scrollable(
                 container(
@@ -703,7 +1002,11 @@ where
 
 /// The theme catalog of Markdown items.
 pub trait Catalog:
-    container::Catalog + scrollable::Catalog + text::Catalog
+    container::Catalog
+    + scrollable::Catalog
+    + text::Catalog
+    + rule::Catalog
+    + checkbox::Catalog
 {
     /// The styling class of a Markdown code block.
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a>;
@@ -714,3 +1017,142 @@ impl Catalog for Theme {
         Box::new(container::dark)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &Text) -> String {
+        text.spans
+            .iter()
+            .map(|span| match span {
+                Span::Standard { text, .. } => text.as_str(),
+                #[cfg(feature = "highlighter")]
+                Span::Highlight { text, .. } => text.as_str(),
+            })
+            .collect()
+    }
+
+    fn paragraph(items: &[Item]) -> String {
+        match items {
+            [Item::Paragraph(paragraph)] => plain(paragraph),
+            _ => panic!("expected a single paragraph, found {items:?}"),
+        }
+    }
+
+    #[test]
+    fn task_lists() {
+        let items: Vec<_> = parse("- [x] done\n- [ ] todo\n- plain").collect();
+
+        let [Item::List { start: None, items }] = items.as_slice() else {
+            panic!("expected an unordered list, found {items:?}");
+        };
+
+        assert!(matches!(items[0], Bullet::Task { done: true, .. }));
+        assert!(matches!(items[1], Bullet::Task { done: false, .. }));
+        assert!(matches!(items[2], Bullet::Point { .. }));
+
+        let texts: Vec<_> =
+            items.iter().map(|item| paragraph(item.items())).collect();
+
+        assert_eq!(texts, ["done", "todo", "plain"]);
+    }
+
+    #[test]
+    fn ordered_task_lists() {
+        let items: Vec<_> = parse("3. [x] first\n4. second").collect();
+
+        let [Item::List {
+            start: Some(3),
+            items,
+        }] = items.as_slice()
+        else {
+            panic!("expected an ordered list, found {items:?}");
+        };
+
+        assert!(matches!(items[0], Bullet::Task { done: true, .. }));
+        assert!(matches!(items[1], Bullet::Point { .. }));
+    }
+
+    #[test]
+    fn images() {
+        let items: Vec<_> =
+            parse("![An *iced* logo](logo.png \"Iced\")").collect();
+
+        let [Item::Image { source, title, alt }] = items.as_slice() else {
+            panic!("expected an image, found {items:?}");
+        };
+
+        assert_eq!(source, "logo.png");
+        assert_eq!(title, "Iced");
+        assert_eq!(plain(alt), "An iced logo");
+    }
+
+    #[test]
+    fn inline_images_keep_their_alternative_text() {
+        let items: Vec<_> =
+            parse("Look at ![this](https://iced.rs/logo.png)!").collect();
+
+        let [Item::Paragraph(paragraph)] = items.as_slice() else {
+            panic!("expected a paragraph, found {items:?}");
+        };
+
+        assert_eq!(plain(paragraph), "Look at this!");
+        assert!(paragraph
+            .spans
+            .iter()
+            .all(|span| matches!(span, Span::Standard { link: None, .. })));
+    }
+
+    #[test]
+    fn quotes() {
+        let items: Vec<_> = parse("> Quoted\n>\n> > Nested\n\nAfter").collect();
+
+        let [Item::Quote(quote), Item::Paragraph(after)] = items.as_slice()
+        else {
+            panic!("expected a quote and a paragraph, found {items:?}");
+        };
+
+        let [Item::Paragraph(quoted), Item::Quote(nested)] = quote.as_slice()
+        else {
+            panic!("expected a paragraph and a quote, found {quote:?}");
+        };
+
+        assert_eq!(plain(quoted), "Quoted");
+        assert_eq!(paragraph(nested), "Nested");
+        assert_eq!(plain(after), "After");
+    }
+
+    #[test]
+    fn tables() {
+        let items: Vec<_> =
+            parse("| Name | Value |\n| :--- | ----: |\n| a | 1 |\n| b | 2 |")
+                .collect();
+
+        let [Item::Table {
+            alignments,
+            header,
+            rows,
+        }] = items.as_slice()
+        else {
+            panic!("expected a table, found {items:?}");
+        };
+
+        assert_eq!(
+            alignments,
+            &[alignment::Horizontal::Left, alignment::Horizontal::Right]
+        );
+
+        assert_eq!(
+            header.iter().map(plain).collect::<Vec<_>>(),
+            ["Name", "Value"]
+        );
+
+        let rows: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| row.iter().map(plain).collect())
+            .collect();
+
+        assert_eq!(rows, [["a", "1"], ["b", "2"]]);
+    }
+}
@@ -0,0 +1,1560 @@
+//! Menus let users pick an action from a list of nested items.
+//!
+//! A [`MenuBar`] displays a row of menus at the top of a window, while a
+//! [`ContextMenu`] opens a menu at the cursor when its content is right
+//! clicked.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::menu::Item;
+//! use iced::widget::{column, menu_bar, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Save,
+//!     ToggleWrap,
+//! }
+//!
+//! fn view(is_wrapping: bool) -> Element<'static, Message> {
+//!     column![
+//!         menu_bar([
+//!             Item::submenu(
+//!                 "File",
+//!                 [
+//!                     Item::new("Open").on_select(Message::Open),
+//!                     Item::new("Save")
+//!                         .on_select(Message::Save)
+//!                         .accelerator("Ctrl+S"),
+//!                 ],
+//!             ),
+//!             Item::submenu(
+//!                 "View",
+//!                 [Item::new("Word wrap")
+//!                     .on_select(Message::ToggleWrap)
+//!                     .checked(is_wrapping)],
+//!             ),
+//!         ]),
+//!         text("Hello!"),
+//!     ]
+//!     .into()
+//! }
+//! ```
+//!
+//! [`ContextMenu`]: crate::ContextMenu
+use crate::core::accessibility;
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+/// An item of a menu.
+///
+/// An [`Item`] can be an action, a submenu, or a separator.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    kind: Kind<Message>,
+    accelerator: Option<String>,
+    is_checked: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new action [`Item`] with the given label.
+    ///
+    /// Unless [`Item::on_select`] is called, the [`Item`] will be disabled.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Action(None),
+            accelerator: None,
+            is_checked: None,
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Self>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Submenu(items.into_iter().collect()),
+            accelerator: None,
+            is_checked: None,
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            kind: Kind::Separator,
+            accelerator: None,
+            is_checked: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the action [`Item`] is
+    /// selected.
+    pub fn on_select(self, message: Message) -> Self {
+        self.on_select_maybe(Some(message))
+    }
+
+    /// Sets the message that will be produced when the action [`Item`] is
+    /// selected, if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_select_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Action(on_select) = &mut self.kind {
+            *on_select = message;
+        }
+
+        self
+    }
+
+    /// Sets the accelerator label of the [`Item`].
+    ///
+    /// The label is only displayed next to the [`Item`]; handling the
+    /// actual keyboard shortcut is up to your application.
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Sets whether the [`Item`] is checked, displaying a checkmark next to
+    /// it.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        self.is_checked = Some(is_checked);
+        self
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action(on_select) => on_select.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn accessibility_node(&self) -> accessibility::Node {
+        let node = accessibility::Node::new(accessibility::Role::MenuItem)
+            .label(self.label.clone())
+            .disabled(!self.is_enabled());
+
+        match self.is_checked {
+            Some(is_checked) => node.toggled(is_checked),
+            None => node,
+        }
+    }
+
+    fn is_submenu(&self) -> bool {
+        matches!(&self.kind, Kind::Submenu(items) if !items.is_empty())
+    }
+}
+
+/// Returns the items of the submenu found by following the given path.
+fn submenu<'a, Message>(
+    items: &'a [Item<Message>],
+    path: &[usize],
+) -> &'a [Item<Message>] {
+    path.iter().fold(items, |items, index| {
+        match items.get(*index).map(|item| &item.kind) {
+            Some(Kind::Submenu(items)) => items,
+            _ => &[],
+        }
+    })
+}
+
+/// Returns the next enabled item after the given one, wrapping around.
+fn next_enabled<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    is_reverse: bool,
+) -> Option<usize> {
+    let len = items.len();
+
+    (1..=len)
+        .map(|step| match (current, is_reverse) {
+            (None, false) => step - 1,
+            (None, true) => len - step,
+            (Some(current), false) => (current + step) % len,
+            (Some(current), true) => (current + len - step) % len,
+        })
+        .find(|index| items[*index].is_enabled())
+}
+
+/// The open submenus and the active item of a menu.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Open {
+    submenus: Vec<usize>,
+    active: Option<usize>,
+}
+
+impl Open {
+    /// Opens the root of a [`MenuBar`] at the given index.
+    fn root(index: usize) -> Self {
+        Self {
+            submenus: vec![index],
+            active: None,
+        }
+    }
+
+    /// Forgets the open submenus and the active item that do not exist
+    /// anymore in the given items.
+    fn retain<Message>(&mut self, items: &[Item<Message>]) {
+        let mut menu = items;
+
+        let valid = self
+            .submenus
+            .iter()
+            .take_while(|index| {
+                match menu.get(**index).map(|item| &item.kind) {
+                    Some(Kind::Submenu(children)) => {
+                        menu = children;
+                        true
+                    }
+                    _ => false,
+                }
+            })
+            .count();
+
+        if valid < self.submenus.len() {
+            self.submenus.truncate(valid);
+            self.active = None;
+        }
+
+        if self.active.is_some_and(|active| active >= menu.len()) {
+            self.active = None;
+        }
+    }
+}
+
+/// The text settings of a menu.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Settings<Font> {
+    pub(crate) text_size: Option<Pixels>,
+    pub(crate) text_line_height: text::LineHeight,
+    pub(crate) text_shaping: text::Shaping,
+    pub(crate) font: Option<Font>,
+    pub(crate) padding: Padding,
+}
+
+impl<Font> Default for Settings<Font> {
+    fn default() -> Self {
+        Self {
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            padding: Padding::from([4, 10]),
+        }
+    }
+}
+
+impl<Font: Copy> Settings<Font> {
+    fn text<Content>(
+        &self,
+        content: Content,
+        font: Font,
+        size: Pixels,
+    ) -> Text<Content, Font> {
+        Text {
+            content,
+            bounds: Size::new(
+                f32::INFINITY,
+                self.text_line_height.to_absolute(size).0,
+            ),
+            size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+/// A horizontal bar of menus.
+///
+/// Every [`Item`] of a [`MenuBar`] is displayed as a title that opens its
+/// submenu when clicked. Once a menu is open, hovering a different title
+/// switches to its submenu.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu::Item;
+/// use iced::widget::menu_bar;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Undo,
+///     Redo,
+/// }
+///
+/// fn view(can_redo: bool) -> Element<'static, Message> {
+///     menu_bar([Item::submenu(
+///         "Edit",
+///         [
+///             Item::new("Undo").on_select(Message::Undo),
+///             Item::new("Redo").on_select_maybe(can_redo.then_some(Message::Redo)),
+///         ],
+///     )])
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    items: Vec<Item<Message>>,
+    width: Length,
+    settings: Settings<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MenuBar`] with the given items.
+    pub fn new(items: impl IntoIterator<Item = Item<Message>>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            width: Length::Fill,
+            settings: Settings::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`MenuBar`] and its menus.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.settings.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`] and its menus.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.settings.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`MenuBar`] and its menus.
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.settings.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`MenuBar`] and its menus.
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.settings.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`] and its menus.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.settings.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`] and its menus.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`] and its menus.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Option<Open>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(None::<Open>)
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self
+            .settings
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self
+            .settings
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+
+        let height =
+            f32::from(self.settings.text_line_height.to_absolute(text_size))
+                + self.settings.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .items
+            .iter()
+            .map(|item| {
+                let paragraph = Renderer::Paragraph::with_text(
+                    self.settings.text(&item.label, font, text_size),
+                );
+
+                let width =
+                    paragraph.min_width() + self.settings.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to((x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for (item, title) in self.items.iter().zip(layout.children()) {
+            operation.accessible(
+                None,
+                title.bounds(),
+                &item.accessibility_node(),
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let open = tree.state.downcast_mut::<Option<Open>>();
+
+        let hovered = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(index) = hovered else {
+                    return event::Status::Ignored;
+                };
+
+                let item = &self.items[index];
+
+                let is_open = open
+                    .as_ref()
+                    .is_some_and(|open| open.submenus.first() == Some(&index));
+
+                if is_open {
+                    *open = None;
+                } else if item.is_submenu() {
+                    *open = Some(Open::root(index));
+                } else if let Kind::Action(Some(message)) = &item.kind {
+                    *open = None;
+
+                    shell.publish(message.clone());
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(current), Some(index)) = (open.as_mut(), hovered) {
+                    if current.submenus.first() != Some(&index)
+                        && self.items[index].is_submenu()
+                    {
+                        *current = Open::root(index);
+
+                        shell.invalidate_layout();
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_title =
+            self.items
+                .iter()
+                .zip(layout.children())
+                .any(|(item, title)| {
+                    item.is_enabled() && cursor.is_over(title.bounds())
+                });
+
+        if is_over_title {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let open = tree.state.downcast_ref::<Option<Open>>();
+        let style = theme.style(&self.class);
+
+        let text_size = self
+            .settings
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self
+            .settings
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+
+        if let Some(background) = style.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (item, title)) in
+            self.items.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title.bounds();
+
+            let is_open = open
+                .as_ref()
+                .is_some_and(|open| open.submenus.first() == Some(&index));
+
+            let is_selected =
+                is_open || (item.is_enabled() && cursor.is_over(bounds));
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                self.settings.text(item.label.clone(), font, text_size),
+                Point::new(
+                    bounds.x + self.settings.padding.left,
+                    bounds.center_y(),
+                ),
+                if !item.is_enabled() {
+                    style.disabled_text_color
+                } else if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let open = tree.state.downcast_mut::<Option<Open>>();
+
+        let index = *open.as_ref()?.submenus.first()?;
+        let title = layout.children().nth(index)?.bounds();
+
+        Some(
+            Overlay {
+                items: &self.items,
+                open,
+                levels: 1,
+                position: Point::new(title.x, title.y + title.height)
+                    + translation,
+                anchor: Some(layout.bounds() + translation),
+                settings: self.settings,
+                class: &self.class,
+            }
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+/// The overlay displaying the open menus of a [`MenuBar`] or a
+/// [`ContextMenu`].
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub(crate) struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The root items of the menu.
+    pub(crate) items: &'a [Item<Message>],
+    pub(crate) open: &'a mut Option<Open>,
+    /// The amount of levels of the menu that are not displayed by the
+    /// overlay.
+    pub(crate) levels: usize,
+    pub(crate) position: Point,
+    /// The bounds where clicks are left to the widget owning the overlay.
+    pub(crate) anchor: Option<Rectangle>,
+    pub(crate) settings: Settings<Renderer::Font>,
+    pub(crate) class: &'a Theme::Class<'b>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    /// The minimum width of a menu.
+    const MIN_WIDTH: f32 = 120.0;
+
+    /// The height of a separator.
+    const SEPARATOR_HEIGHT: f32 = 9.0;
+
+    /// The vertical space around the items of a menu.
+    const MARGIN: f32 = 4.0;
+
+    /// The space between the label and the accelerator of an item.
+    const GAP: f32 = 24.0;
+
+    pub(crate) fn overlay(
+        self,
+    ) -> overlay::Element<'a, Message, Theme, Renderer> {
+        if let Some(open) = self.open.as_mut() {
+            open.retain(self.items);
+        }
+
+        overlay::Element::new(Box::new(self))
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> Pixels {
+        self.settings
+            .text_size
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the items of every displayed menu, with their level.
+    fn menus(&self) -> impl Iterator<Item = (usize, &'a [Item<Message>])> + '_ {
+        let submenus = self
+            .open
+            .as_ref()
+            .map(|open| open.submenus.as_slice())
+            .unwrap_or_default();
+
+        let items = self.items;
+
+        (self.levels..=submenus.len())
+            .map(move |level| (level, submenu(items, &submenus[..level])))
+            .take_while(|(_, items)| !items.is_empty())
+    }
+
+    /// Returns the highlighted item of the menu at the given level.
+    fn highlighted(&self, level: usize) -> Option<usize> {
+        let open = self.open.as_ref()?;
+
+        open.submenus
+            .get(level)
+            .copied()
+            .or(if level == open.submenus.len() {
+                open.active
+            } else {
+                None
+            })
+    }
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        *self.open = None;
+
+        shell.invalidate_layout();
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    /// Selects the item at the given level and index, publishing its message
+    /// or opening its submenu.
+    fn select(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let items = self
+            .open
+            .as_ref()
+            .map(|open| submenu(self.items, &open.submenus[..level]))
+            .unwrap_or_default();
+
+        match items.get(index).map(|item| &item.kind) {
+            Some(Kind::Action(Some(message))) => {
+                shell.publish(message.clone());
+
+                self.close(shell);
+            }
+            Some(Kind::Submenu(children)) if !children.is_empty() => {
+                if let Some(open) = self.open.as_mut() {
+                    open.submenus.truncate(level);
+                    open.submenus.push(index);
+                    open.active = next_enabled(children, None, false);
+                }
+
+                shell.invalidate_layout();
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the menu of a [`MenuBar`] to the next or previous root.
+    fn cycle_root(&mut self, is_reverse: bool) -> bool {
+        let Some(open) = self.open.as_mut() else {
+            return false;
+        };
+
+        if self.levels == 0 {
+            return false;
+        }
+
+        let len = self.items.len();
+        let current = open.submenus.first().copied().unwrap_or_default();
+
+        let next = (1..len)
+            .map(|step| {
+                if is_reverse {
+                    (current + len - step) % len
+                } else {
+                    (current + step) % len
+                }
+            })
+            .find(|index| self.items[*index].is_submenu());
+
+        if let Some(next) = next {
+            *open = Open::root(next);
+            open.active =
+                next_enabled(submenu(self.items, &[next]), None, false);
+        }
+
+        next.is_some()
+    }
+
+    /// Closes the deepest open submenu, activating its item.
+    fn back(&mut self) {
+        if let Some(open) = self.open.as_mut() {
+            open.active = open.submenus.pop();
+        }
+    }
+
+    fn on_key(
+        &mut self,
+        key: key::Named,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(open) = self.open.as_ref() else {
+            return event::Status::Ignored;
+        };
+
+        let level = open.submenus.len();
+        let active = open.active;
+        let items = submenu(self.items, &open.submenus);
+
+        match key {
+            key::Named::ArrowDown | key::Named::ArrowUp => {
+                let next =
+                    next_enabled(items, active, key == key::Named::ArrowUp);
+
+                if let Some(open) = self.open.as_mut() {
+                    open.active = next;
+                }
+            }
+            key::Named::ArrowRight => {
+                match active.filter(|active| {
+                    items.get(*active).is_some_and(Item::is_submenu)
+                }) {
+                    Some(active) => self.select(level, active, shell),
+                    None => {
+                        let _ = self.cycle_root(false);
+                    }
+                }
+            }
+            key::Named::ArrowLeft => {
+                if level > self.levels {
+                    self.back();
+                } else if !self.cycle_root(true) {
+                    return event::Status::Ignored;
+                }
+            }
+            key::Named::Enter | key::Named::Space => {
+                if let Some(active) = active {
+                    self.select(level, active, shell);
+                }
+            }
+            key::Named::Escape => {
+                if level > self.levels {
+                    self.back();
+                } else {
+                    self.close(shell);
+                }
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+        event::Status::Captured
+    }
+
+    fn draw_menu(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        level: usize,
+        items: &[Item<Message>],
+        layout: Layout<'_>,
+    ) {
+        let bounds = layout.bounds();
+        let text_size = self.text_size(renderer);
+        let font = self
+            .settings
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+        let padding = self.settings.padding;
+        let highlighted = self.highlighted(level);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        for (index, (item, layout)) in
+            items.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+
+            if let Kind::Separator = item.kind {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + padding.left,
+                            y: bounds.center_y().floor(),
+                            width: bounds.width - padding.horizontal(),
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.separator,
+                );
+
+                continue;
+            }
+
+            let is_selected = highlighted == Some(index) && item.is_enabled();
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + style.border.width,
+                            width: bounds.width - style.border.width * 2.0,
+                            ..bounds
+                        },
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            let color = if !item.is_enabled() {
+                style.disabled_text_color
+            } else if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let check = Size::new(text_size.0, bounds.height);
+
+            if item.is_checked == Some(true) {
+                renderer.fill_text(
+                    Text {
+                        bounds: check,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        ..self.settings.text(
+                            Renderer::CHECKMARK_ICON.to_string(),
+                            Renderer::ICON_FONT,
+                            text_size,
+                        )
+                    },
+                    Point::new(
+                        bounds.x + padding.left + check.width / 2.0,
+                        bounds.center_y(),
+                    ),
+                    color,
+                    bounds,
+                );
+            }
+
+            let offset = if items.iter().any(|item| item.is_checked.is_some()) {
+                check.width + padding.left
+            } else {
+                0.0
+            };
+
+            renderer.fill_text(
+                self.settings.text(item.label.clone(), font, text_size),
+                Point::new(bounds.x + padding.left + offset, bounds.center_y()),
+                color,
+                bounds,
+            );
+
+            if let Some(accelerator) = &item.accelerator {
+                renderer.fill_text(
+                    Text {
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        ..self.settings.text(
+                            accelerator.clone(),
+                            font,
+                            text_size,
+                        )
+                    },
+                    Point::new(
+                        bounds.x + bounds.width
+                            - padding.right
+                            - arrow_width(items, text_size),
+                        bounds.center_y(),
+                    ),
+                    if item.is_enabled() && !is_selected {
+                        style.accelerator_text_color
+                    } else {
+                        color
+                    },
+                    bounds,
+                );
+            }
+
+            if item.is_submenu() {
+                draw_arrow(
+                    renderer,
+                    Point::new(
+                        bounds.x + bounds.width
+                            - padding.right
+                            - text_size.0 / 4.0,
+                        bounds.center_y(),
+                    ),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the horizontal space reserved for submenu arrows.
+fn arrow_width<Message>(items: &[Item<Message>], text_size: Pixels) -> f32 {
+    if items.iter().any(Item::is_submenu) {
+        text_size.0
+    } else {
+        0.0
+    }
+}
+
+/// Draws a small triangle pointing to the right.
+fn draw_arrow<Renderer>(renderer: &mut Renderer, center: Point, color: Color)
+where
+    Renderer: crate::core::Renderer,
+{
+    const STEPS: usize = 4;
+
+    for step in 0..STEPS {
+        let height = 2.0 * (STEPS - step) as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: (center.x - STEPS as f32 / 2.0).round() + step as f32,
+                    y: center.y - height / 2.0,
+                    width: 1.0,
+                    height,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// Snaps the given bounds within the viewport, flipping them to the other
+/// side of the given origin when they overflow.
+fn snap_within_viewport(
+    mut bounds: Rectangle,
+    viewport: Size,
+    flip: Vector,
+) -> Rectangle {
+    if bounds.x + bounds.width > viewport.width && bounds.x - flip.x >= 0.0 {
+        bounds.x -= flip.x;
+    }
+
+    if bounds.y + bounds.height > viewport.height && bounds.y - flip.y >= 0.0 {
+        bounds.y -= flip.y;
+    }
+
+    bounds.x = bounds.x.min(viewport.width - bounds.width).max(0.0);
+    bounds.y = bounds.y.min(viewport.height - bounds.height).max(0.0);
+
+    bounds
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = self.text_size(renderer);
+        let font = self
+            .settings
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+        let padding = self.settings.padding;
+
+        let item_height =
+            f32::from(self.settings.text_line_height.to_absolute(text_size))
+                + padding.vertical();
+
+        let measure = |content: &str| {
+            Renderer::Paragraph::with_text(
+                self.settings.text(content, font, text_size),
+            )
+            .min_width()
+        };
+
+        let mut parent: Option<(Rectangle, Rectangle)> = None;
+        let mut menus = Vec::new();
+
+        for (level, items) in self.menus() {
+            let label = items
+                .iter()
+                .map(|item| measure(&item.label))
+                .fold(0.0, f32::max);
+
+            let accelerator = items
+                .iter()
+                .filter_map(|item| item.accelerator.as_deref())
+                .map(measure)
+                .fold(0.0, f32::max);
+
+            let check = if items.iter().any(|item| item.is_checked.is_some()) {
+                text_size.0 + padding.left
+            } else {
+                0.0
+            };
+
+            let width = (padding.horizontal()
+                + check
+                + label
+                + if accelerator > 0.0 {
+                    Self::GAP + accelerator
+                } else {
+                    0.0
+                }
+                + arrow_width(items, text_size))
+            .max(Self::MIN_WIDTH);
+
+            let mut y = Self::MARGIN;
+
+            let nodes: Vec<_> = items
+                .iter()
+                .map(|item| {
+                    let height = if let Kind::Separator = item.kind {
+                        Self::SEPARATOR_HEIGHT
+                    } else {
+                        item_height
+                    };
+
+                    let node = layout::Node::new(Size::new(width, height))
+                        .move_to((0.0, y));
+
+                    y += height;
+
+                    node
+                })
+                .collect();
+
+            let size = Size::new(width, y + Self::MARGIN);
+
+            let menu = match parent {
+                None => snap_within_viewport(
+                    Rectangle::new(self.position, size),
+                    bounds,
+                    Vector::new(
+                        if self.levels == 0 { size.width } else { 0.0 },
+                        size.height,
+                    ),
+                ),
+                Some((menu, item)) => snap_within_viewport(
+                    Rectangle::new(
+                        Point::new(menu.x + menu.width, item.y - Self::MARGIN),
+                        size,
+                    ),
+                    bounds,
+                    Vector::new(menu.width + size.width, 0.0),
+                ),
+            };
+
+            parent = self.open.as_ref().and_then(|open| {
+                let index = *open.submenus.get(level)?;
+                let item = nodes.get(index)?.bounds();
+
+                Some((menu, item + Vector::new(menu.x, menu.y)))
+            });
+
+            menus.push(
+                layout::Node::with_children(size, nodes)
+                    .move_to(menu.position()),
+            );
+        }
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for ((_, items), menu) in self.menus().zip(layout.children()) {
+            for (item, layout) in items.iter().zip(menu.children()) {
+                if let Kind::Separator = item.kind {
+                    continue;
+                }
+
+                operation.accessible(
+                    None,
+                    layout.bounds(),
+                    &item.accessibility_node(),
+                );
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let hovered = self.menus().zip(layout.children()).find_map(
+            |((level, items), menu)| {
+                let index = items
+                    .iter()
+                    .zip(menu.children())
+                    .position(|(_, item)| cursor.is_over(item.bounds()))?;
+
+                Some((level, index))
+            },
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let Some(((level, index), open)) =
+                    hovered.zip(self.open.as_mut())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                if open.submenus.get(level) == Some(&index) {
+                    open.submenus.truncate(level + 1);
+                    open.active = None;
+                } else {
+                    open.submenus.truncate(level);
+                    open.active = Some(index);
+
+                    let items = submenu(self.items, &open.submenus);
+
+                    if items[index].is_submenu() {
+                        open.submenus.push(index);
+                        open.active = None;
+                    }
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if let Some((level, index)) = hovered {
+                    self.select(level, index, shell);
+
+                    return event::Status::Captured;
+                }
+
+                let is_over_menu =
+                    layout.children().any(|menu| cursor.is_over(menu.bounds()));
+
+                if is_over_menu {
+                    return event::Status::Captured;
+                }
+
+                let is_over_anchor =
+                    self.anchor.is_some_and(|anchor| cursor.is_over(anchor));
+
+                if !is_over_anchor {
+                    self.close(shell);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                return self.on_key(key, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_item =
+            self.menus()
+                .zip(layout.children())
+                .any(|((_, items), menu)| {
+                    items.iter().zip(menu.children()).any(|(item, layout)| {
+                        item.is_enabled() && cursor.is_over(layout.bounds())
+                    })
+                });
+
+        if is_over_item {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|menu| menu.bounds().contains(cursor_position))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+
+        for ((level, items), menu) in self.menus().zip(layout.children()) {
+            renderer.with_layer(
+                menu.bounds().expand(style.shadow.blur_radius),
+                |renderer| {
+                    self.draw_menu(renderer, &style, level, items, menu);
+                },
+            );
+        }
+    }
+}
+
+/// The appearance of a menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of a [`MenuBar`], if any.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of a menu.
+    pub background: Background,
+    /// The [`Border`] of a menu.
+    pub border: Border,
+    /// The [`Shadow`] of a menu.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the items.
+    pub text_color: Color,
+    /// The text [`Color`] of the disabled items.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the accelerators of the items.
+    pub accelerator_text_color: Color,
+    /// The text [`Color`] of a selected item.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a selected item.
+    pub selected_background: Background,
+    /// The [`Color`] of the separators.
+    pub separator: Color,
+}
+
+/// The theme catalog of a menu.
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a menu.
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a menu.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        bar_background: Some(palette.background.weak.color.into()),
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        disabled_text_color: palette.background.strong.color,
+        accelerator_text_color: palette.background.base.text.scale_alpha(0.6),
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        separator: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_disabled_items() {
+        let items = [
+            Item::new("Cut"),
+            Item::new("Copy").on_select(()),
+            Item::separator(),
+            Item::submenu("Recent", []),
+            Item::new("Paste").on_select(()),
+        ];
+
+        assert_eq!(next_enabled(&items, None, false), Some(1));
+        assert_eq!(next_enabled(&items, Some(1), false), Some(4));
+        assert_eq!(next_enabled(&items, Some(4), false), Some(1));
+        assert_eq!(next_enabled(&items, None, true), Some(4));
+        assert_eq!(next_enabled(&items, Some(1), true), Some(4));
+        assert_eq!(next_enabled(&items[..1], None, false), None);
+    }
+
+    #[test]
+    fn snaps_within_viewport() {
+        let viewport = Size::new(100.0, 100.0);
+        let size = Size::new(40.0, 30.0);
+
+        let bounds = snap_within_viewport(
+            Rectangle::new(Point::new(80.0, 90.0), size),
+            viewport,
+            Vector::new(size.width, size.height),
+        );
+
+        assert_eq!(bounds.position(), Point::new(40.0, 60.0));
+
+        let bounds = snap_within_viewport(
+            Rectangle::new(Point::new(80.0, 10.0), size),
+            viewport,
+            Vector::ZERO,
+        );
+
+        assert_eq!(bounds.position(), Point::new(60.0, 10.0));
+    }
+
+    #[test]
+    fn forgets_stale_items() {
+        let items: [Item<()>; 2] = [
+            Item::new("Open").on_select(()),
+            Item::submenu("Recent", [Item::new("a.txt").on_select(())]),
+        ];
+
+        let mut open = Open {
+            submenus: vec![1],
+            active: Some(3),
+        };
+
+        open.retain(&items);
+
+        assert_eq!(
+            open,
+            Open {
+                submenus: vec![1],
+                active: None,
+            }
+        );
+
+        let mut open = Open {
+            submenus: vec![1, 0],
+            active: Some(0),
+        };
+
+        open.retain(&items[..1]);
+
+        assert_eq!(open, Open::default());
+    }
+}
@@ -0,0 +1,548 @@
+//! Number inputs let users type and step through numeric values.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::number_input;
+//!
+//! struct State {
+//!    quantity: u32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     QuantityChanged(u32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     number_input(state.quantity, Message::QuantityChanged)
+//!         .min(1)
+//!         .max(99)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::QuantityChanged(quantity) => {
+//!             state.quantity = quantity;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::clipboard::Clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Element, Layout, Length, Padding, Pixels, Rectangle, Shell, Size, Widget,
+};
+use crate::text_input::{self, TextInput, Value};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A field to type and step through a numeric value.
+///
+/// The text typed into a [`NumberInput`] is kept by the widget while it is
+/// being edited; so partial numbers, like `-` or `1.`, do not get lost. A
+/// message is only produced when the text is a number within the bounds.
+///
+/// While focused, the value can be stepped with the arrow keys or by
+/// scrolling over the [`NumberInput`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::number_input;
+///
+/// struct State {
+///    quantity: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     number_input(state.quantity, Message::QuantityChanged)
+///         .min(1)
+///         .max(99)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::QuantityChanged(quantity) => {
+///             state.quantity = quantity;
+///         }
+///     }
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    input: TextInput<'a, String, Theme, Renderer>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + Display + FromStr + num_traits::ToPrimitive + 'static,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value and must produce a `Message`.
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        NumberInput {
+            value,
+            min: None,
+            max: None,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            input: TextInput::new("", &value.to_string())
+                .on_input(std::convert::identity)
+                .filter(is_partial::<T>),
+        }
+    }
+
+    /// Sets the [`text_input::Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: impl Into<text_input::Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.input = self.input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`NumberInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.input = self.input.class(class);
+        self
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + num_traits::ToPrimitive
+        + num_traits::FromPrimitive,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn contains(&self, value: T) -> bool {
+        is_finite(&value)
+            && !self.min.is_some_and(|min| value < min)
+            && !self.max.is_some_and(|max| value > max)
+    }
+
+    /// Returns the given value moved by the given amount of steps, rounded
+    /// to the decimals of the step and clamped within the bounds.
+    fn stepped(&self, value: T, steps: f64) -> Option<T> {
+        let decimals = self
+            .step
+            .to_string()
+            .split_once('.')
+            .map(|(_, decimals)| decimals.len())
+            .unwrap_or(0);
+
+        let factor = 10f64.powi(decimals as i32);
+        let new_value = value.to_f64()? + self.step.to_f64()? * steps;
+        let mut new_value = (new_value * factor).round() / factor;
+
+        if let Some(min) = self.min {
+            new_value = new_value.max(min.to_f64()?);
+        }
+
+        if let Some(max) = self.max {
+            new_value = new_value.min(max.to_f64()?);
+        }
+
+        T::from_f64(new_value)
+    }
+}
+
+/// Returns whether the given contents are a finite number of type `T`, or
+/// may become one as more characters are typed.
+fn is_partial<T: FromStr + num_traits::ToPrimitive>(contents: &str) -> bool {
+    let is_number = |contents: &str| {
+        contents.parse::<T>().is_ok_and(|value| is_finite(&value))
+    };
+
+    is_number(contents) || is_number(&format!("{contents}0"))
+}
+
+/// Returns whether the given value is a finite number.
+fn is_finite<T: num_traits::ToPrimitive>(value: &T) -> bool {
+    value.to_f64().is_some_and(f64::is_finite)
+}
+
+struct State<T> {
+    value: T,
+    buffer: Value,
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::ToPrimitive
+        + num_traits::FromPrimitive
+        + 'static,
+    Message: Clone,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            value: self.value,
+            buffer: Value::new(&self.value.to_string()),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // Only replace the text being edited when the value changes
+        // externally
+        if state.value != self.value {
+            state.value = self.value;
+            state.buffer = Value::new(&self.value.to_string());
+        }
+
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<String, Theme, Renderer>::size(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        self.input.layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+            Some(&state.buffer),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let was_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        if was_focused {
+            let steps = match &event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::ArrowUp),
+                    ..
+                }) => Some(1.0),
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::ArrowDown),
+                    ..
+                }) => Some(-1.0),
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if cursor.is_over(layout.bounds()) =>
+                {
+                    let (mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. }) = *delta;
+
+                    (y != 0.0).then(|| f64::from(y.signum()))
+                }
+                _ => None,
+            };
+
+            if let Some(steps) = steps {
+                if let Some(value) = self.stepped(state.value, steps) {
+                    if value != state.value {
+                        state.value = value;
+                        shell.publish((self.on_change)(value));
+                    }
+
+                    state.buffer = Value::new(&value.to_string());
+
+                    tree.children[0]
+                        .state
+                        .downcast_mut::<text_input::State<Renderer::Paragraph>>(
+                        )
+                        .move_cursor_to_end();
+
+                    shell.invalidate_layout();
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.input.set_value(state.buffer.clone());
+
+        let mut contents = Vec::new();
+        let mut local_shell = Shell::new(&mut contents).with_drag(shell.drag());
+
+        let status = self.input.on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        for contents in contents {
+            state.buffer = Value::new(&contents);
+
+            if let Ok(value) = contents.parse::<T>() {
+                if self.contains(value) && value != state.value {
+                    state.value = value;
+                    shell.publish((self.on_change)(value));
+                }
+            }
+        }
+
+        // Reformat any partial number once the user is done editing
+        if was_focused && !is_focused::<Renderer>(&tree.children[0]) {
+            state.buffer = Value::new(&state.value.to_string());
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input.mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        self.input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            Some(&state.buffer),
+            viewport,
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::ToPrimitive
+        + num_traits::FromPrimitive
+        + 'static,
+    Message: Clone + 'a,
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(number_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_partial_numbers() {
+        assert!(is_partial::<f32>(""));
+        assert!(is_partial::<f32>("-"));
+        assert!(is_partial::<f32>("1."));
+        assert!(is_partial::<f32>("1.5"));
+        assert!(!is_partial::<f32>("1.5."));
+        assert!(!is_partial::<f32>("a"));
+
+        assert!(is_partial::<i32>("-"));
+        assert!(!is_partial::<i32>("1."));
+        assert!(!is_partial::<u32>("-"));
+
+        assert!(is_partial::<i64>("-42"));
+        assert!(is_partial::<u64>("18446744073709551615"));
+        assert!(is_partial::<usize>("7"));
+    }
+
+    #[test]
+    fn rejects_non_finite_numbers() {
+        assert!(!is_partial::<f32>("NaN"));
+        assert!(!is_partial::<f32>("inf"));
+        assert!(!is_partial::<f64>("-infinity"));
+        assert!(!is_partial::<f32>("1e39"));
+
+        assert!(!is_finite(&f32::NAN));
+        assert!(!is_finite(&f64::NEG_INFINITY));
+        assert!(is_finite(&u64::MAX));
+    }
+}
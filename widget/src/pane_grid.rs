@@ -12,6 +12,7 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Tabs that can be reordered and dragged between panes
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -110,6 +111,7 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Tabs that can be reordered and dragged between panes
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -194,6 +196,7 @@ where
                 state
                     .panes
                     .iter()
+                    .filter(|(pane, _)| !state.internal.is_hidden(**pane))
                     .map(|(pane, pane_state)| {
                         (*pane, view(*pane, pane_state, false))
                     })
@@ -234,6 +237,9 @@ where
 
     /// Sets the message that will be produced when a [`Pane`] of the
     /// [`PaneGrid`] is clicked.
+    ///
+    /// When a tab is clicked, the message is produced for the [`Pane`] of
+    /// the tab. You may want to [`State::activate`] it.
     pub fn on_click<F>(mut self, f: F) -> Self
     where
         F: 'a + Fn(Pane) -> Message,
@@ -471,7 +477,51 @@ where
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some((pane, origin)) = action.picked_pane() {
+                if let Some((pane, origin)) = action.picked_tab() {
+                    if let Some(on_drag) = on_drag {
+                        if let Some(cursor_position) = cursor.position() {
+                            if cursor_position.distance(origin)
+                                > DRAG_DEADBAND_DISTANCE
+                            {
+                                let event = match tab_drop(
+                                    layout,
+                                    &self.contents,
+                                    cursor_position,
+                                    pane,
+                                ) {
+                                    Some(TabDrop::Strip {
+                                        target,
+                                        index,
+                                        ..
+                                    }) => DragEvent::TabMoved {
+                                        pane,
+                                        target,
+                                        index,
+                                    },
+                                    Some(TabDrop::Edge(edge)) => {
+                                        DragEvent::TabDropped {
+                                            pane,
+                                            target: Target::Edge(edge),
+                                        }
+                                    }
+                                    Some(TabDrop::Region {
+                                        target,
+                                        region,
+                                        ..
+                                    }) => DragEvent::TabDropped {
+                                        pane,
+                                        target: Target::Pane(target, region),
+                                    },
+                                    None => DragEvent::Canceled { pane },
+                                };
+
+                                shell.publish(on_drag(event));
+                            }
+                        }
+                    }
+
+                    event_status = event::Status::Captured;
+                } else if let Some((pane, origin)) = action.picked_pane() {
                     if let Some(on_drag) = on_drag {
                         if let Some(cursor_position) = cursor.position() {
                             if cursor_position.distance(origin)
@@ -604,7 +654,7 @@ where
     ) -> mouse::Interaction {
         let action = tree.state.downcast_ref::<state::Action>();
 
-        if action.picked_pane().is_some() {
+        if action.picked_pane().is_some() || action.picked_tab().is_some() {
             return mouse::Interaction::Grabbing;
         }
 
@@ -729,7 +779,15 @@ where
                 None => None,
             });
 
-        let pane_cursor = if picked_pane.is_some() {
+        let picked_tab = action.picked_tab().filter(|(_, origin)| {
+            cursor
+                .position()
+                .map(|position| position.distance(*origin))
+                .unwrap_or_default()
+                > DRAG_DEADBAND_DISTANCE
+        });
+
+        let pane_cursor = if picked_pane.is_some() || picked_tab.is_some() {
             mouse::Cursor::Unavailable
         } else {
             cursor
@@ -837,7 +895,91 @@ where
             }
         }
 
-        if picked_pane.is_none() {
+        if let Some((tab, origin)) = picked_tab {
+            if let Some(cursor_position) = cursor.position() {
+                let highlight = match tab_drop(
+                    layout,
+                    &self.contents,
+                    cursor_position,
+                    tab,
+                ) {
+                    Some(TabDrop::Strip {
+                        position, height, ..
+                    }) => {
+                        let line = style.tab_insertion;
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: (position.x - line.width / 2.0).round(),
+                                    y: position.y,
+                                    width: line.width,
+                                    height,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            line.color,
+                        );
+
+                        None
+                    }
+                    Some(TabDrop::Edge(edge)) => {
+                        Some(edge_bounds(layout, edge))
+                    }
+                    Some(TabDrop::Region { bounds, .. }) => Some(bounds),
+                    None => None,
+                };
+
+                if let Some(bounds) = highlight {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: style.hovered_region.border,
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_region.background,
+                    );
+                }
+
+                // Render picked tab last
+                let picked = self
+                    .contents
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .find(|(((_, content), _), _)| content.has_tab(tab));
+
+                if let Some((((_, content), tree), layout)) = picked {
+                    let bounds =
+                        content.tab_bounds(layout).and_then(|(_, tabs)| {
+                            tabs.into_iter()
+                                .find(|(pane, _)| *pane == tab)
+                                .map(|(_, bounds)| bounds)
+                        });
+
+                    if let Some(bounds) = bounds {
+                        let translation = cursor_position - origin;
+
+                        renderer.with_translation(translation, |renderer| {
+                            renderer.with_layer(bounds, |renderer| {
+                                content.draw_tab(
+                                    tree,
+                                    renderer,
+                                    theme,
+                                    defaults,
+                                    layout,
+                                    pane_cursor,
+                                    viewport,
+                                    tab,
+                                );
+                            });
+                        });
+                    }
+                }
+            }
+        }
+
+        if picked_pane.is_none() && picked_tab.is_none() {
             if let Some((axis, split_region, is_picked)) = picked_split {
                 let highlight = if is_picked {
                     style.picked_split
@@ -948,12 +1090,21 @@ fn click_pane<'a, Message, T>(
         .filter(|(_, layout)| layout.bounds().contains(cursor_position));
 
     if let Some(((pane, content), layout)) = clicked_region.next() {
+        let tab = content.tab_at(layout, cursor_position);
+
         if let Some(on_click) = &on_click {
-            shell.publish(on_click(pane));
+            shell.publish(on_click(tab.unwrap_or(pane)));
         }
 
         if let Some(on_drag) = &on_drag {
-            if content.can_be_dragged_at(layout, cursor_position) {
+            if let Some(tab) = tab {
+                *action = state::Action::DraggingTab {
+                    pane: tab,
+                    origin: cursor_position,
+                };
+
+                shell.publish(on_drag(DragEvent::TabPicked { pane: tab }));
+            } else if content.can_be_dragged_at(layout, cursor_position) {
                 *action = state::Action::Dragging {
                     pane,
                     origin: cursor_position,
@@ -965,6 +1116,94 @@ fn click_pane<'a, Message, T>(
     }
 }
 
+/// The place where a dragged tab would be dropped.
+enum TabDrop {
+    /// The tab strip of a [`Pane`].
+    Strip {
+        target: Pane,
+        index: usize,
+        position: Point,
+        height: f32,
+    },
+    /// An [`Edge`] of the [`PaneGrid`].
+    Edge(Edge),
+    /// The [`Region`] of a [`Pane`].
+    Region {
+        target: Pane,
+        region: Region,
+        bounds: Rectangle,
+    },
+}
+
+fn tab_drop<Message, Theme, Renderer>(
+    layout: Layout<'_>,
+    contents: &Contents<'_, Content<'_, Message, Theme, Renderer>>,
+    cursor_position: Point,
+    tab: Pane,
+) -> Option<TabDrop>
+where
+    Theme: container::Catalog,
+    Renderer: core::Renderer,
+{
+    for ((target, content), pane_layout) in
+        contents.iter().zip(layout.children())
+    {
+        let Some((title_bar, tabs)) = content.tab_bounds(pane_layout) else {
+            continue;
+        };
+
+        if title_bar.contains(cursor_position) {
+            let index = tabs
+                .iter()
+                .filter(|(_, bounds)| bounds.center_x() < cursor_position.x)
+                .count();
+
+            let (_, first) = tabs[0];
+            let x = match tabs.get(index) {
+                Some((_, bounds)) => bounds.x,
+                None => tabs
+                    .last()
+                    .map(|(_, bounds)| bounds.x + bounds.width)
+                    .unwrap_or(first.x),
+            };
+
+            return Some(TabDrop::Strip {
+                target,
+                index,
+                position: Point::new(x, first.y),
+                height: first.height,
+            });
+        }
+    }
+
+    if let Some(edge) = in_edge(layout, cursor_position) {
+        return Some(TabDrop::Edge(edge));
+    }
+
+    let ((target, content), pane_layout, region) = contents
+        .iter()
+        .zip(layout.children())
+        .find_map(|(content, layout)| {
+            layout_region(layout, cursor_position)
+                .map(|region| (content, layout, region))
+        })?;
+
+    let is_same_group = target == tab || content.has_tab(tab);
+    let has_other_tabs = content
+        .tab_bounds(pane_layout)
+        .is_some_and(|(_, tabs)| tabs.len() > 1);
+
+    match region {
+        Region::Center if is_same_group => None,
+        Region::Edge(_) if target == tab && !has_other_tabs => None,
+        _ => Some(TabDrop::Region {
+            target,
+            region,
+            bounds: layout_region_bounds(pane_layout, region),
+        }),
+    }
+}
+
 fn in_edge(layout: Layout<'_>, cursor: Point) -> Option<Edge> {
     let bounds = layout.bounds();
 
@@ -1066,10 +1305,43 @@ pub enum DragEvent {
 
     /// A [`Pane`] was picked and then dropped outside of other [`Pane`]
     /// boundaries.
+    ///
+    /// It is also produced when a tab is dropped where it cannot be moved.
     Canceled {
         /// The picked [`Pane`].
         pane: Pane,
     },
+
+    /// A tab was picked for dragging.
+    TabPicked {
+        /// The [`Pane`] of the picked tab.
+        pane: Pane,
+    },
+
+    /// A tab was dropped outside of any tab strip.
+    ///
+    /// You can call [`State::drop_tab`] to move the tab.
+    TabDropped {
+        /// The [`Pane`] of the picked tab.
+        pane: Pane,
+
+        /// The [`Target`] where the picked tab was dropped on.
+        target: Target,
+    },
+
+    /// A tab was dropped into the tab strip of a [`Pane`].
+    ///
+    /// You can call [`State::move_tab`] to move the tab.
+    TabMoved {
+        /// The [`Pane`] of the picked tab.
+        pane: Pane,
+
+        /// The [`Pane`] owning the tab strip.
+        target: Pane,
+
+        /// The index of the tab strip where the tab was dropped.
+        index: usize,
+    },
 }
 
 /// The [`Target`] area a pane can be dropped on.
@@ -1191,6 +1463,8 @@ pub struct Style {
     pub picked_split: Line,
     /// The appearance of a hovered split.
     pub hovered_split: Line,
+    /// The appearance of the insertion point of a dragged tab.
+    pub tab_insertion: Line,
 }
 
 /// The appearance of a highlight of the [`PaneGrid`].
@@ -1266,5 +1540,9 @@ pub fn default(theme: &Theme) -> Style {
             color: palette.primary.strong.color,
             width: 2.0,
         },
+        tab_insertion: Line {
+            color: palette.primary.strong.color,
+            width: 2.0,
+        },
     }
 }
@@ -4,6 +4,7 @@ use crate::pane_grid::Axis;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -23,4 +24,24 @@ pub enum Configuration<T> {
     ///
     /// [`Pane`]: super::Pane
     Pane(T),
+    /// A group of tabs sharing the same region.
+    ///
+    /// Only the `active` tab is displayed. Empty groups are skipped.
+    ///
+    /// [`Pane`]: super::Pane
+    Tabs {
+        /// The tabs of the group, in order.
+        tabs: Vec<T>,
+
+        /// The index of the active tab.
+        active: usize,
+    },
+    /// A maximized [`Pane`] or group of tabs.
+    ///
+    /// A maximized [`Configuration::Split`] is not supported and is restored
+    /// to its normal size. If more than one [`Configuration`] is maximized,
+    /// the last one wins.
+    ///
+    /// [`Pane`]: super::Pane
+    Maximized(Box<Configuration<T>>),
 }
@@ -8,7 +8,7 @@ use crate::core::widget::{self, Tree};
 use crate::core::{
     self, Clipboard, Element, Layout, Point, Rectangle, Shell, Size, Vector,
 };
-use crate::pane_grid::{Draggable, TitleBar};
+use crate::pane_grid::{Draggable, Pane, TitleBar};
 
 /// The content of a [`Pane`].
 ///
@@ -164,6 +164,56 @@ where
         }
     }
 
+    /// Returns true if the [`TitleBar`] of the [`Content`] contains the
+    /// given tab.
+    pub(super) fn has_tab(&self, tab: Pane) -> bool {
+        self.title_bar
+            .as_ref()
+            .is_some_and(|title_bar| title_bar.has_tab(tab))
+    }
+
+    /// Returns the bounds of the [`TitleBar`] and of each of its visible
+    /// tabs, if the [`Content`] has any.
+    pub(super) fn tab_bounds(
+        &self,
+        layout: Layout<'_>,
+    ) -> Option<(Rectangle, Vec<(Pane, Rectangle)>)> {
+        let title_bar = self.title_bar.as_ref()?;
+        let title_bar_layout = layout.children().next()?;
+
+        let tabs = title_bar.tab_bounds(title_bar_layout);
+
+        (!tabs.is_empty()).then(|| (title_bar_layout.bounds(), tabs))
+    }
+
+    /// Draws the given tab of the [`TitleBar`] of the [`Content`].
+    pub(super) fn draw_tab(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        tab: Pane,
+    ) {
+        if let (Some(title_bar), Some(title_bar_layout)) =
+            (&self.title_bar, layout.children().next())
+        {
+            title_bar.draw_tab(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                title_bar_layout,
+                cursor,
+                viewport,
+                tab,
+            );
+        }
+    }
+
     pub(crate) fn layout(
         &self,
         tree: &mut Tree,
@@ -313,7 +363,14 @@ where
                 })
                 .unwrap_or_default();
 
-            if is_over_pick_area && drag_enabled {
+            let is_over_tab = cursor
+                .position()
+                .and_then(|cursor_position| {
+                    title_bar.tab_at(title_bar_layout, cursor_position)
+                })
+                .is_some();
+
+            if (is_over_pick_area || is_over_tab) && drag_enabled {
                 return mouse::Interaction::Grab;
             }
 
@@ -402,6 +459,17 @@ where
             false
         }
     }
+
+    fn tab_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Pane> {
+        let title_bar = self.title_bar.as_ref()?;
+        let title_bar_layout = layout.children().next()?;
+
+        title_bar.tab_at(title_bar_layout, cursor_position)
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<T>
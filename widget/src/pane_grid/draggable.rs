@@ -1,8 +1,15 @@
 use crate::core::{Layout, Point};
+use crate::pane_grid::Pane;
 
 /// A pane that can be dragged.
 pub trait Draggable {
     /// Returns whether the [`Draggable`] with the given [`Layout`] can be picked
     /// at the provided cursor position.
     fn can_be_dragged_at(&self, layout: Layout<'_>, cursor: Point) -> bool;
+
+    /// Returns the tab of the [`Draggable`] with the given [`Layout`] that can
+    /// be picked at the provided cursor position, if any.
+    fn tab_at(&self, _layout: Layout<'_>, _cursor: Point) -> Option<Pane> {
+        None
+    }
 }
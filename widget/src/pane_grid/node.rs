@@ -5,8 +5,15 @@ use std::collections::BTreeMap;
 
 /// A layout node of a [`PaneGrid`].
 ///
+/// With the `serde` feature, a [`Node`] can be serialized but not
+/// deserialized, since its identifiers are only valid for the [`State`]
+/// that produced them. Use a [`Configuration`] to restore a layout.
+///
 /// [`PaneGrid`]: super::PaneGrid
+/// [`State`]: super::State
+/// [`Configuration`]: super::Configuration
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Node {
     /// The region of this [`Node`] is split into two.
     Split {
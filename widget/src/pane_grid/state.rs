@@ -51,17 +51,94 @@ impl<T> State<T> {
     }
 
     /// Creates a new [`State`] with the given [`Configuration`].
+    ///
+    /// Empty groups of tabs are skipped.
+    ///
+    /// # Panics
+    /// Panics if the [`Configuration`] does not contain any pane. Use
+    /// [`State::try_with_configuration`] to restore untrusted configurations.
     pub fn with_configuration(config: impl Into<Configuration<T>>) -> Self {
-        let mut panes = FxHashMap::default();
+        Self::try_with_configuration(config)
+            .expect("a configuration must contain at least one pane")
+    }
 
-        let internal =
-            Internal::from_configuration(&mut panes, config.into(), 0);
+    /// Creates a new [`State`] with the given [`Configuration`], if it
+    /// contains at least one pane.
+    ///
+    /// Empty groups of tabs are skipped.
+    pub fn try_with_configuration(
+        config: impl Into<Configuration<T>>,
+    ) -> Option<Self> {
+        let mut panes = FxHashMap::default();
+        let mut tabs = FxHashMap::default();
+        let mut maximized = None;
+
+        let (layout, last_id) = Internal::build(
+            &mut panes,
+            &mut tabs,
+            &mut maximized,
+            config.into(),
+            0,
+        );
+
+        let internal = Internal {
+            layout: layout?,
+            last_id,
+            tabs,
+        };
 
-        State {
+        Some(State {
             panes,
             internal,
-            maximized: None,
+            maximized,
+        })
+    }
+
+    /// Returns the [`Configuration`] of the [`State`].
+    ///
+    /// The axes and ratios of every [`Split`] are preserved, as well as
+    /// the tabs of every group and the maximized [`Pane`] or group, if any.
+    /// Restoring the [`Configuration`] with [`State::with_configuration`]
+    /// produces an equivalent [`State`].
+    pub fn to_configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        fn build<T: Clone>(node: &Node, state: &State<T>) -> Configuration<T> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(build(a, state)),
+                    b: Box::new(build(b, state)),
+                },
+                Node::Pane(pane) => {
+                    let configuration = match state.internal.tabs(*pane) {
+                        Some(tabs) => Configuration::Tabs {
+                            tabs: tabs
+                                .iter()
+                                .map(|tab| state.panes[tab].clone())
+                                .collect(),
+                            active: tabs
+                                .iter()
+                                .position(|tab| tab == pane)
+                                .unwrap_or_default(),
+                        },
+                        None => Configuration::Pane(state.panes[pane].clone()),
+                    };
+
+                    if state.maximized == Some(*pane) {
+                        Configuration::Maximized(Box::new(configuration))
+                    } else {
+                        configuration
+                    }
+                }
+            }
         }
+
+        build(&self.internal.layout, self)
     }
 
     /// Returns the total amount of panes in the [`State`].
@@ -110,7 +187,7 @@ impl<T> State<T> {
             .layout
             .pane_regions(0.0, Size::new(4096.0, 4096.0));
 
-        let current_region = regions.get(&pane)?;
+        let current_region = regions.get(&self.visible(pane))?;
 
         let target = match direction {
             Direction::Left => {
@@ -188,12 +265,11 @@ impl<T> State<T> {
         state: T,
         inverse: bool,
     ) -> Option<(Pane, Split)> {
-        let node = if let Some(pane) = pane {
-            self.internal.layout.find(pane)?
-        } else {
-            // Major node
-            &mut self.internal.layout
-        };
+        let pane = pane.map(|pane| self.visible(pane));
+
+        if let Some(pane) = pane {
+            let _ = self.internal.layout.find(pane)?;
+        }
 
         let new_pane = {
             self.internal.last_id = self.internal.last_id.checked_add(1)?;
@@ -201,6 +277,26 @@ impl<T> State<T> {
             Pane(self.internal.last_id)
         };
 
+        let new_split = self.insert(axis, pane, new_pane, inverse)?;
+        let _ = self.panes.insert(new_pane, state);
+
+        Some((new_pane, new_split))
+    }
+
+    fn insert(
+        &mut self,
+        axis: Axis,
+        target: Option<Pane>,
+        pane: Pane,
+        inverse: bool,
+    ) -> Option<Split> {
+        let node = if let Some(target) = target {
+            self.internal.layout.find(target)?
+        } else {
+            // Major node
+            &mut self.internal.layout
+        };
+
         let new_split = {
             self.internal.last_id = self.internal.last_id.checked_add(1)?;
 
@@ -208,15 +304,14 @@ impl<T> State<T> {
         };
 
         if inverse {
-            node.split_inverse(new_split, axis, new_pane);
+            node.split_inverse(new_split, axis, pane);
         } else {
-            node.split(new_split, axis, new_pane);
+            node.split(new_split, axis, pane);
         }
 
-        let _ = self.panes.insert(new_pane, state);
         let _ = self.maximized.take();
 
-        Some((new_pane, new_split))
+        Some(new_split)
     }
 
     fn split_and_swap(
@@ -226,12 +321,11 @@ impl<T> State<T> {
         pane: Pane,
         swap: bool,
     ) {
-        if let Some((state, _)) = self.close(pane) {
-            if let Some((new_pane, _)) = self.split(axis, target, state) {
-                if swap {
-                    self.swap(target, new_pane);
-                }
-            }
+        let target = self.visible(target);
+        let pane = self.visible(pane);
+
+        if target != pane && self.take(pane) {
+            let _ = self.insert(axis, Some(target), pane, swap);
         }
     }
 
@@ -261,9 +355,28 @@ impl<T> State<T> {
         pane: Pane,
         swap: bool,
     ) {
-        if let Some((state, _)) = self.close(pane) {
-            let _ = self.split_node(axis, None, state, swap);
+        let pane = self.visible(pane);
+
+        if self.take(pane) {
+            let _ = self.insert(axis, None, pane, swap);
+        }
+    }
+
+    /// Takes the given visible [`Pane`] out of the layout, keeping its
+    /// internal state and tabs.
+    ///
+    /// A [`Pane`] that is not part of the layout, like a detached tab, is
+    /// already out of it.
+    fn take(&mut self, pane: Pane) -> bool {
+        if self.internal.layout.find(pane).is_none() {
+            return self.panes.contains_key(&pane);
+        }
+
+        if self.maximized == Some(pane) {
+            let _ = self.maximized.take();
         }
+
+        self.internal.layout.remove(pane).is_some()
     }
 
     /// Swaps the position of the provided panes in the [`State`].
@@ -274,6 +387,8 @@ impl<T> State<T> {
     /// [`PaneGrid`]: super::PaneGrid
     /// [`DragEvent`]: super::DragEvent
     pub fn swap(&mut self, a: Pane, b: Pane) {
+        let (a, b) = (self.visible(a), self.visible(b));
+
         self.internal.layout.update(&|node| match node {
             Node::Split { .. } => {}
             Node::Pane(pane) => {
@@ -302,7 +417,14 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// If the [`Pane`] is a tab, the closest sibling is the new active tab of
+    /// its group.
     pub fn close(&mut self, pane: Pane) -> Option<(T, Pane)> {
+        if let Some(active) = self.detach(pane) {
+            return self.panes.remove(&pane).map(|state| (state, active));
+        }
+
         if self.maximized == Some(pane) {
             let _ = self.maximized.take();
         }
@@ -319,7 +441,7 @@ impl<T> State<T> {
     ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn maximize(&mut self, pane: Pane) {
-        self.maximized = Some(pane);
+        self.maximized = Some(self.visible(pane));
     }
 
     /// Restore the currently maximized [`Pane`] to it's normal size. All panes
@@ -336,6 +458,205 @@ impl<T> State<T> {
     pub fn maximized(&self) -> Option<Pane> {
         self.maximized
     }
+
+    /// Returns the tabs of the group the given [`Pane`] belongs to, in order.
+    ///
+    /// A [`Pane`] that is not part of a group is its only tab.
+    pub fn tabs(&self, pane: Pane) -> &[Pane] {
+        match self.internal.group(pane) {
+            Some((_, tabs)) => tabs,
+            None => self
+                .panes
+                .get_key_value(&pane)
+                .map(|(pane, _)| std::slice::from_ref(pane))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the active tab of the group the given [`Pane`] belongs to.
+    ///
+    /// The active tab is the only [`Pane`] of a group displayed by the
+    /// [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub fn active_tab(&self, pane: Pane) -> Option<Pane> {
+        match self.internal.group(pane) {
+            Some((active, _)) => Some(active),
+            None => self.panes.contains_key(&pane).then_some(pane),
+        }
+    }
+
+    /// Adds a new tab to the group of the given [`Pane`], initializing it
+    /// with the provided internal state.
+    ///
+    /// The new tab is placed last and becomes the active tab of the group.
+    pub fn add_tab(&mut self, pane: Pane, state: T) -> Option<Pane> {
+        let active = self.active_tab(pane)?;
+
+        let tab = {
+            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+
+            Pane(self.internal.last_id)
+        };
+
+        let _ = self.panes.insert(tab, state);
+
+        self.internal
+            .tabs
+            .entry(active)
+            .or_insert_with(|| vec![active])
+            .push(tab);
+
+        self.replace_active(active, tab);
+
+        Some(tab)
+    }
+
+    /// Makes the given tab the active tab of its group.
+    pub fn activate(&mut self, tab: Pane) {
+        if let Some((active, _)) = self.internal.group(tab) {
+            if active != tab {
+                self.replace_active(active, tab);
+            }
+        }
+    }
+
+    /// Moves the given tab into the group of the target [`Pane`], at the
+    /// given index, and makes it active.
+    ///
+    /// The index is relative to the tabs of the target group before the move
+    /// and it is clamped to its amount of tabs.
+    ///
+    /// If you want to enable tab dragging in your [`PaneGrid`], you will
+    /// need to call this method when handling a [`DragEvent::TabMoved`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`DragEvent::TabMoved`]: super::DragEvent::TabMoved
+    pub fn move_tab(&mut self, tab: Pane, target: Pane, index: usize) {
+        let (Some(source), Some(target)) =
+            (self.active_tab(tab), self.active_tab(target))
+        else {
+            return;
+        };
+
+        if source == target {
+            if let Some(tabs) = self.internal.tabs.get_mut(&target) {
+                let from = tabs.iter().position(|pane| *pane == tab).unwrap();
+                let to = if index > from { index - 1 } else { index };
+
+                let tab = tabs.remove(from);
+                tabs.insert(to.min(tabs.len()), tab);
+            }
+
+            self.activate(tab);
+
+            return;
+        }
+
+        if self.detach(tab).is_none() && !self.take(tab) {
+            return;
+        }
+
+        let tabs = self
+            .internal
+            .tabs
+            .entry(target)
+            .or_insert_with(|| vec![target]);
+
+        tabs.insert(index.min(tabs.len()), tab);
+
+        self.replace_active(target, tab);
+    }
+
+    /// Drops the given tab into the provided [`Target`].
+    ///
+    /// Dropping a tab in the center of a [`Pane`] adds it to its group.
+    /// Otherwise, the tab is taken out of its group and placed in a new
+    /// split.
+    ///
+    /// If you want to enable tab dragging in your [`PaneGrid`], you will
+    /// need to call this method when handling a [`DragEvent::TabDropped`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`DragEvent::TabDropped`]: super::DragEvent::TabDropped
+    pub fn drop_tab(&mut self, tab: Pane, target: Target) {
+        match target {
+            Target::Pane(target, Region::Center) => {
+                self.move_tab(tab, target, usize::MAX);
+            }
+            Target::Pane(target, region) => {
+                let target = self.visible(target);
+                let active = self.detach(tab);
+
+                let target = match active {
+                    Some(active) if target == tab => active,
+                    _ => target,
+                };
+
+                self.split_with(target, tab, region);
+            }
+            Target::Edge(edge) => {
+                let _ = self.detach(tab);
+
+                self.move_to_edge(tab, edge);
+            }
+        }
+    }
+
+    /// Returns the visible [`Pane`] of the group the given [`Pane`] belongs
+    /// to, or the [`Pane`] itself if it is not part of one.
+    fn visible(&self, pane: Pane) -> Pane {
+        self.internal
+            .group(pane)
+            .map(|(active, _)| active)
+            .unwrap_or(pane)
+    }
+
+    /// Removes the given tab from its group and returns the new active tab
+    /// of the group.
+    ///
+    /// Returns `None` if the [`Pane`] is not part of a group.
+    fn detach(&mut self, tab: Pane) -> Option<Pane> {
+        let (active, _) = self.internal.group(tab)?;
+        let tabs = self.internal.tabs.get_mut(&active)?;
+
+        let index = tabs.iter().position(|pane| *pane == tab)?;
+        let _ = tabs.remove(index);
+
+        let new_active = if tab == active {
+            tabs[index.min(tabs.len() - 1)]
+        } else {
+            active
+        };
+
+        if tabs.len() == 1 {
+            let _ = self.internal.tabs.remove(&active);
+        }
+
+        if tab == active {
+            self.replace_active(active, new_active);
+        }
+
+        Some(new_active)
+    }
+
+    /// Replaces the active tab of a group, keeping its position in the
+    /// layout.
+    fn replace_active(&mut self, active: Pane, tab: Pane) {
+        self.internal.layout.update(&|node| {
+            if matches!(node, Node::Pane(pane) if *pane == active) {
+                *node = Node::Pane(tab);
+            }
+        });
+
+        if let Some(tabs) = self.internal.tabs.remove(&active) {
+            let _ = self.internal.tabs.insert(tab, tabs);
+        }
+
+        if self.maximized == Some(active) {
+            self.maximized = Some(tab);
+        }
+    }
 }
 
 /// The internal state of a [`PaneGrid`].
@@ -345,52 +666,126 @@ impl<T> State<T> {
 pub struct Internal {
     layout: Node,
     last_id: usize,
+    tabs: FxHashMap<Pane, Vec<Pane>>,
 }
 
 impl Internal {
     /// Initializes the [`Internal`] state of a [`PaneGrid`] from a
     /// [`Configuration`].
     ///
+    /// Empty groups of tabs are skipped.
+    ///
+    /// # Panics
+    /// Panics if the [`Configuration`] does not contain any pane.
+    ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn from_configuration<T>(
         panes: &mut FxHashMap<Pane, T>,
         content: Configuration<T>,
         next_id: usize,
     ) -> Self {
-        let (layout, last_id) = match content {
+        let mut tabs = FxHashMap::default();
+
+        let (layout, last_id) =
+            Self::build(panes, &mut tabs, &mut None, content, next_id);
+
+        Self {
+            layout: layout
+                .expect("a configuration must contain at least one pane"),
+            last_id,
+            tabs,
+        }
+    }
+
+    fn build<T>(
+        panes: &mut FxHashMap<Pane, T>,
+        tabs: &mut FxHashMap<Pane, Vec<Pane>>,
+        maximized: &mut Option<Pane>,
+        content: Configuration<T>,
+        next_id: usize,
+    ) -> (Option<Node>, usize) {
+        match content {
             Configuration::Split { axis, ratio, a, b } => {
-                let Internal {
-                    layout: a,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *a, next_id);
-
-                let Internal {
-                    layout: b,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *b, next_id);
-
-                (
-                    Node::Split {
-                        id: Split(next_id),
-                        axis,
-                        ratio,
-                        a: Box::new(a),
-                        b: Box::new(b),
-                    },
-                    next_id + 1,
-                )
+                let (a, next_id) =
+                    Self::build(panes, tabs, maximized, *a, next_id);
+
+                let (b, next_id) =
+                    Self::build(panes, tabs, maximized, *b, next_id);
+
+                match (a, b) {
+                    (Some(a), Some(b)) => (
+                        Some(Node::Split {
+                            id: Split(next_id),
+                            axis,
+                            ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        }),
+                        next_id + 1,
+                    ),
+                    (a, b) => (a.or(b), next_id),
+                }
             }
             Configuration::Pane(state) => {
                 let id = Pane(next_id);
                 let _ = panes.insert(id, state);
 
-                (Node::Pane(id), next_id + 1)
+                (Some(Node::Pane(id)), next_id + 1)
             }
-        };
+            Configuration::Tabs {
+                tabs: states,
+                active,
+            } => {
+                if states.is_empty() {
+                    return (None, next_id);
+                }
+
+                let group: Vec<_> =
+                    (next_id..next_id + states.len()).map(Pane).collect();
+
+                panes.extend(group.iter().copied().zip(states));
+
+                let id = group[active.min(group.len() - 1)];
+                let next_id = next_id + group.len();
+
+                if group.len() > 1 {
+                    let _ = tabs.insert(id, group);
+                }
+
+                (Some(Node::Pane(id)), next_id)
+            }
+            Configuration::Maximized(content) => {
+                let (node, next_id) =
+                    Self::build(panes, tabs, maximized, *content, next_id);
+
+                if let Some(Node::Pane(pane)) = node {
+                    *maximized = Some(pane);
+                }
+
+                (node, next_id)
+            }
+        }
+    }
+
+    /// Returns the active tab and the tabs of the group containing the given
+    /// [`Pane`], if it is part of one.
+    fn group(&self, pane: Pane) -> Option<(Pane, &[Pane])> {
+        self.tabs
+            .iter()
+            .find(|(_, tabs)| tabs.contains(&pane))
+            .map(|(active, tabs)| (*active, tabs.as_slice()))
+    }
+
+    /// Returns true if the given [`Pane`] is a tab hidden behind the active
+    /// tab of its group.
+    pub(super) fn is_hidden(&self, pane: Pane) -> bool {
+        self.group(pane).is_some_and(|(active, _)| active != pane)
+    }
 
-        Self { layout, last_id }
+    /// Returns the tabs of the given visible [`Pane`], if it has more than
+    /// one.
+    pub(super) fn tabs(&self, pane: Pane) -> Option<&[Pane]> {
+        self.tabs.get(&pane).map(Vec::as_slice)
     }
 }
 
@@ -412,6 +807,15 @@ pub enum Action {
         /// The starting [`Point`] of the drag interaction.
         origin: Point,
     },
+    /// A tab in the [`PaneGrid`] is being dragged.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    DraggingTab {
+        /// The [`Pane`] of the tab being dragged.
+        pane: Pane,
+        /// The starting [`Point`] of the drag interaction.
+        origin: Point,
+    },
     /// A [`Split`] in the [`PaneGrid`] is being dragged.
     ///
     /// [`PaneGrid`]: super::PaneGrid
@@ -432,6 +836,14 @@ impl Action {
         }
     }
 
+    /// Returns the current tab that is being dragged, if any.
+    pub fn picked_tab(&self) -> Option<(Pane, Point)> {
+        match *self {
+            Action::DraggingTab { pane, origin } => Some((pane, origin)),
+            _ => None,
+        }
+    }
+
     /// Returns the current [`Split`] that is being dragged, if any.
     pub fn picked_split(&self) -> Option<(Split, Axis)> {
         match *self {
@@ -447,3 +859,127 @@ impl Internal {
         &self.layout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configuration_round_trip() {
+        let (mut state, first) = State::new(1);
+        let (second, split) = state.split(Axis::Vertical, first, 2).unwrap();
+        let _ = state.split(Axis::Horizontal, second, 3).unwrap();
+        let _ = state.add_tab(first, 4).unwrap();
+
+        state.resize(split, 0.3);
+        state.maximize(second);
+
+        let configuration = state.to_configuration();
+        let restored = State::with_configuration(configuration.clone());
+
+        assert_eq!(
+            format!("{configuration:?}"),
+            format!("{:?}", restored.to_configuration())
+        );
+        assert_eq!(
+            restored.maximized().map(|pane| restored.panes[&pane]),
+            Some(2)
+        );
+
+        let Configuration::Split { axis, ratio, .. } = configuration else {
+            panic!("root should be a split");
+        };
+
+        assert_eq!(axis, Axis::Vertical);
+        assert_eq!(ratio, 0.3);
+    }
+
+    #[test]
+    fn maximized_tabs_round_trip() {
+        let (mut state, first) = State::new(1);
+        let (second, _) = state.split(Axis::Horizontal, first, 2).unwrap();
+        let third = state.add_tab(second, 3).unwrap();
+
+        state.maximize(third);
+
+        let Configuration::Split { b, .. } = state.to_configuration() else {
+            panic!("root should be a split");
+        };
+
+        let Configuration::Maximized(group) = *b else {
+            panic!("the group should be maximized");
+        };
+
+        let Configuration::Tabs { tabs, active } = *group else {
+            panic!("the maximized configuration should be a group");
+        };
+
+        assert_eq!(tabs, [2, 3]);
+        assert_eq!(active, 1);
+
+        let restored = State::with_configuration(Configuration::Maximized(
+            Box::new(Configuration::Tabs { tabs, active }),
+        ));
+
+        let maximized = restored.maximized().expect("maximized group");
+
+        assert_eq!(restored.panes[&maximized], 3);
+        assert_eq!(restored.tabs(maximized).len(), 2);
+    }
+
+    #[test]
+    fn empty_tabs_are_skipped() {
+        let empty = || Configuration::Tabs {
+            tabs: Vec::new(),
+            active: 0,
+        };
+
+        let state = State::with_configuration(Configuration::Split {
+            axis: Axis::Vertical,
+            ratio: 0.5,
+            a: Box::new(empty()),
+            b: Box::new(Configuration::Maximized(Box::new(
+                Configuration::Pane(1),
+            ))),
+        });
+
+        assert_eq!(state.len(), 1);
+        assert!(matches!(state.layout(), Node::Pane(_)));
+        assert!(state.maximized().is_some());
+
+        assert!(State::<i32>::try_with_configuration(empty()).is_none());
+    }
+
+    #[test]
+    fn tabs() {
+        let (mut state, first) = State::new("a");
+        let (second, _) = state.split(Axis::Vertical, first, "b").unwrap();
+        let third = state.add_tab(first, "c").unwrap();
+
+        assert_eq!(state.tabs(first), &[first, third]);
+        assert_eq!(state.active_tab(first), Some(third));
+        assert!(state
+            .layout()
+            .pane_regions(0.0, Size::new(100.0, 100.0))
+            .contains_key(&third));
+
+        state.activate(first);
+        state.move_tab(second, first, 1);
+
+        assert_eq!(state.tabs(first), &[first, second, third]);
+        assert_eq!(state.active_tab(third), Some(second));
+        assert_eq!(state.layout().splits().count(), 0);
+
+        state.drop_tab(second, Target::Pane(second, Region::Edge(Edge::Left)));
+
+        assert_eq!(state.tabs(first), &[first, third]);
+        assert_eq!(state.tabs(second), &[second]);
+        assert_eq!(state.layout().splits().count(), 1);
+
+        let (closed, active) = state.close(first).unwrap();
+
+        assert_eq!(closed, "a");
+        assert_eq!(active, third);
+        assert_eq!(state.tabs(third), &[third]);
+    }
+}
@@ -10,6 +10,7 @@ use crate::core::{
     Vector,
 };
 use crate::pane_grid::controls::Controls;
+use crate::pane_grid::Pane;
 
 /// The title bar of a [`Pane`].
 ///
@@ -26,6 +27,7 @@ pub struct TitleBar<
 {
     content: Element<'a, Message, Theme, Renderer>,
     controls: Option<Controls<'a, Message, Theme, Renderer>>,
+    tabs: Vec<(Pane, Element<'a, Message, Theme, Renderer>)>,
     padding: Padding,
     always_show_controls: bool,
     class: Theme::Class<'a>,
@@ -43,6 +45,7 @@ where
         Self {
             content: content.into(),
             controls: None,
+            tabs: Vec::new(),
             padding: Padding::ZERO,
             always_show_controls: false,
             class: Theme::default(),
@@ -58,6 +61,29 @@ where
         self
     }
 
+    /// Sets the tabs of the [`TitleBar`].
+    ///
+    /// Each tab is the label of a [`Pane`] in the group of the [`TitleBar`],
+    /// normally obtained with [`State::tabs`]. The tabs are placed in a strip
+    /// after the content of the [`TitleBar`].
+    ///
+    /// Tabs can be dragged around if [`PaneGrid::on_drag`] is set.
+    ///
+    /// [`State::tabs`]: super::State::tabs
+    /// [`PaneGrid::on_drag`]: super::PaneGrid::on_drag
+    pub fn tabs(
+        mut self,
+        tabs: impl IntoIterator<
+            Item = (Pane, impl Into<Element<'a, Message, Theme, Renderer>>),
+        >,
+    ) -> Self {
+        self.tabs = tabs
+            .into_iter()
+            .map(|(pane, tab)| (pane, tab.into()))
+            .collect();
+        self
+    }
+
     /// Sets the [`Padding`] of the [`TitleBar`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -124,13 +150,16 @@ where
         };
 
         Tree {
-            children,
+            children: children
+                .into_iter()
+                .chain(self.tabs.iter().map(|(_, tab)| Tree::new(tab)))
+                .collect(),
             ..Tree::empty()
         }
     }
 
     pub(super) fn diff(&self, tree: &mut Tree) {
-        if tree.children.len() == 3 {
+        if tree.children.len() >= 3 {
             if let Some(controls) = self.controls.as_ref() {
                 if let Some(compact) = controls.compact.as_ref() {
                     tree.children[2].diff(compact);
@@ -140,11 +169,108 @@ where
             }
 
             tree.children[0].diff(&self.content);
+
+            tree.children.truncate(3 + self.tabs.len());
+
+            for (i, (_, tab)) in self.tabs.iter().enumerate() {
+                if let Some(state) = tree.children.get_mut(3 + i) {
+                    state.diff(tab);
+                } else {
+                    tree.children.push(Tree::new(tab));
+                }
+            }
         } else {
             *tree = self.state();
         }
     }
 
+    /// Returns the [`Layout`] of the tab strip of the [`TitleBar`], if any.
+    fn strip<'b>(&self, layout: Layout<'b>) -> Option<Layout<'b>> {
+        if self.tabs.is_empty() {
+            return None;
+        }
+
+        layout.children().next()?.children().last()
+    }
+
+    /// Returns true if the [`TitleBar`] contains the given tab.
+    pub(super) fn has_tab(&self, tab: Pane) -> bool {
+        self.tabs.iter().any(|(pane, _)| *pane == tab)
+    }
+
+    /// Returns the bounds of each visible tab of the [`TitleBar`].
+    pub(super) fn tab_bounds(
+        &self,
+        layout: Layout<'_>,
+    ) -> Vec<(Pane, Rectangle)> {
+        let Some(strip) = self.strip(layout) else {
+            return Vec::new();
+        };
+
+        let strip_bounds = strip.bounds();
+
+        self.tabs
+            .iter()
+            .zip(strip.children())
+            .filter_map(|((pane, _), layout)| {
+                Some((*pane, layout.bounds().intersection(&strip_bounds)?))
+            })
+            .collect()
+    }
+
+    /// Returns the tab of the [`TitleBar`] at the given cursor position, if
+    /// any.
+    pub(super) fn tab_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Pane> {
+        self.tab_bounds(layout)
+            .into_iter()
+            .find(|(_, bounds)| bounds.contains(cursor_position))
+            .map(|(pane, _)| pane)
+    }
+
+    /// Draws the given tab of the [`TitleBar`].
+    pub(super) fn draw_tab(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        tab: Pane,
+    ) {
+        let Some(strip) = self.strip(layout) else {
+            return;
+        };
+
+        let style = theme.style(&self.class);
+
+        let inherited_style = renderer::Style {
+            text_color: style.text_color.unwrap_or(inherited_style.text_color),
+        };
+
+        if let Some(((_, tab), (state, layout))) = self
+            .tabs
+            .iter()
+            .zip(tree.children.iter().skip(3).zip(strip.children()))
+            .find(|((pane, _), _)| *pane == tab)
+        {
+            tab.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &inherited_style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
     /// Draws the [`TitleBar`] with the provided [`Renderer`] and [`Layout`].
     ///
     /// [`Renderer`]: core::Renderer
@@ -231,17 +357,41 @@ where
                 viewport,
             );
         }
+
+        if let Some(strip) = self.strip(layout) {
+            renderer.with_layer(strip.bounds(), |renderer| {
+                for ((_, tab), (state, layout)) in self
+                    .tabs
+                    .iter()
+                    .zip(tree.children.iter().skip(3).zip(strip.children()))
+                {
+                    tab.as_widget().draw(
+                        state,
+                        renderer,
+                        theme,
+                        &inherited_style,
+                        layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            });
+        }
     }
 
     /// Returns whether the mouse cursor is over the pick area of the
     /// [`TitleBar`] or not.
     ///
-    /// The whole [`TitleBar`] is a pick area, except its controls.
+    /// The whole [`TitleBar`] is a pick area, except its controls and tabs.
     pub fn is_over_pick_area(
         &self,
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> bool {
+        if self.tab_at(layout, cursor_position).is_some() {
+            return false;
+        }
+
         if layout.bounds().contains(cursor_position) {
             let mut children = layout.children();
             let padded = children.next().unwrap();
@@ -283,10 +433,47 @@ where
         let limits = limits.shrink(self.padding);
         let max_size = limits.max();
 
+        // The title only gets the space left by the tabs and the narrowest
+        // controls, so a title that fills the bar does not hide the tabs
+        let (tabs_width, reserved) = if self.tabs.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let tabs_width = self
+                .layout_tabs(&mut tree.children[3..], renderer, max_size)
+                .size()
+                .width;
+
+            let controls_width =
+                self.controls.as_ref().map_or(0.0, |controls| {
+                    let (element, tree) = match &controls.compact {
+                        Some(compact) => (compact, &mut tree.children[2]),
+                        None => (&controls.full, &mut tree.children[1]),
+                    };
+
+                    element
+                        .as_widget()
+                        .layout(
+                            tree,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, max_size),
+                        )
+                        .size()
+                        .width
+                });
+
+            (tabs_width, tabs_width + controls_width)
+        };
+
         let title_layout = self.content.as_widget().layout(
             &mut tree.children[0],
             renderer,
-            &layout::Limits::new(Size::ZERO, max_size),
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (max_size.width - reserved).max(0.0),
+                    max_size.height,
+                ),
+            ),
         );
 
         let title_size = title_layout.size();
@@ -299,7 +486,7 @@ where
             );
 
             if title_layout.bounds().width + controls_layout.bounds().width
-                > max_size.width
+                > max_size.width - tabs_width
             {
                 if let Some(compact) = controls.compact.as_ref() {
                     let compact_layout = compact.as_widget().layout(
@@ -366,9 +553,83 @@ where
             )
         };
 
+        let node = if self.tabs.is_empty() {
+            node
+        } else {
+            let children = node.children();
+
+            let title_width = children[0].size().width;
+            let controls_width = match children {
+                [_, controls] | [_, _, controls] => controls.size().width,
+                _ => 0.0,
+            };
+
+            let strip = self
+                .layout_tabs(
+                    &mut tree.children[3..],
+                    renderer,
+                    Size::new(
+                        (max_size.width - title_width - controls_width)
+                            .max(0.0),
+                        max_size.height,
+                    ),
+                )
+                .move_to(Point::new(title_width, 0.0));
+
+            let size = node.size();
+
+            layout::Node::with_children(
+                Size::new(size.width, size.height.max(strip.size().height)),
+                children
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(strip))
+                    .collect(),
+            )
+        };
+
         layout::Node::container(node, self.padding)
     }
 
+    fn layout_tabs(
+        &self,
+        tree: &mut [Tree],
+        renderer: &Renderer,
+        max_size: Size,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, max_size.height),
+        );
+
+        let mut x = 0.0;
+        let mut height: f32 = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .zip(tree)
+            .map(|((_, tab), tree)| {
+                let node = tab
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(x, 0.0));
+
+                let size = node.size();
+
+                x += size.width;
+                height = height.max(size.height);
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(
+            Size::new(x.min(max_size.width), height),
+            tabs,
+        )
+    }
+
     pub(crate) fn operate(
         &self,
         tree: &mut Tree,
@@ -426,6 +687,16 @@ where
                 operation,
             );
         }
+
+        if let Some(strip) = self.strip(layout) {
+            for ((_, tab), (state, layout)) in self
+                .tabs
+                .iter()
+                .zip(tree.children.iter_mut().skip(3).zip(strip.children()))
+            {
+                tab.as_widget().operate(state, layout, renderer, operation);
+            }
+        }
     }
 
     pub(crate) fn on_event(
@@ -497,7 +768,7 @@ where
         let title_status = if show_title {
             self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
-                event,
+                event.clone(),
                 title_layout,
                 cursor,
                 renderer,
@@ -509,7 +780,28 @@ where
             event::Status::Ignored
         };
 
-        control_status.merge(title_status)
+        let tabs_status = if let Some(strip) = self.strip(layout) {
+            self.tabs
+                .iter_mut()
+                .zip(tree.children.iter_mut().skip(3).zip(strip.children()))
+                .map(|((_, tab), (state, layout))| {
+                    tab.as_widget_mut().on_event(
+                        state,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge)
+        } else {
+            event::Status::Ignored
+        };
+
+        control_status.merge(title_status).merge(tabs_status)
     }
 
     pub(crate) fn mouse_interaction(
@@ -534,7 +826,7 @@ where
             renderer,
         );
 
-        if let Some(controls) = &self.controls {
+        let interaction = if let Some(controls) = &self.controls {
             let controls_layout = children.next().unwrap();
             let controls_interaction =
                 controls.full.as_widget().mouse_interaction(
@@ -568,7 +860,21 @@ where
             }
         } else {
             title_interaction
-        }
+        };
+
+        let Some(strip) = self.strip(layout) else {
+            return interaction;
+        };
+
+        self.tabs
+            .iter()
+            .zip(tree.children.iter().skip(3).zip(strip.children()))
+            .map(|((_, tab), (state, layout))| {
+                tab.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .fold(interaction, mouse::Interaction::max)
     }
 
     pub(crate) fn overlay<'b>(
@@ -584,11 +890,17 @@ where
         let mut children = padded.children();
         let title_layout = children.next()?;
 
+        let strip = self.strip(layout);
+
         let Self {
-            content, controls, ..
+            content,
+            controls,
+            tabs,
+            ..
         } = self;
 
-        let mut states = tree.children.iter_mut();
+        let (states, tab_states) = tree.children.split_at_mut(3);
+        let mut states = states.iter_mut();
         let title_state = states.next().unwrap();
         let controls_state = states.next().unwrap();
 
@@ -631,5 +943,74 @@ where
                     }
                 })
             })
+            .or_else(move || {
+                tabs.iter_mut()
+                    .zip(tab_states.iter_mut().zip(strip?.children()))
+                    .find_map(|((_, tab), (state, layout))| {
+                        tab.as_widget_mut().overlay(
+                            state,
+                            layout,
+                            renderer,
+                            translation,
+                        )
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Length;
+    use crate::pane_grid::Controls;
+    use crate::Space;
+
+    fn layout(
+        title_bar: &TitleBar<'_, (), crate::Theme, ()>,
+    ) -> Vec<Rectangle> {
+        let mut tree = title_bar.state();
+
+        let node = title_bar.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        node.children()[0]
+            .children()
+            .iter()
+            .map(layout::Node::bounds)
+            .collect()
+    }
+
+    #[test]
+    fn tabs_keep_their_width() {
+        let tabs =
+            || [(Pane(0), Space::new(30, 20)), (Pane(1), Space::new(30, 20))];
+
+        let bounds =
+            layout(&TitleBar::new(Space::new(Length::Fill, 20)).tabs(tabs()));
+
+        assert_eq!(bounds[0].width, 140.0);
+        assert_eq!(
+            bounds[1],
+            Rectangle::new(Point::new(140.0, 0.0), Size::new(60.0, 20.0))
+        );
+
+        let bounds = layout(
+            &TitleBar::new(Space::new(Length::Fill, 20))
+                .controls(Controls::dynamic(
+                    Space::new(50, 20),
+                    Space::new(20, 20),
+                ))
+                .tabs(tabs()),
+        );
+
+        assert_eq!(bounds[0].width, 120.0);
+        assert_eq!(bounds[2].x, 180.0);
+        assert_eq!(
+            bounds[3],
+            Rectangle::new(Point::new(120.0, 0.0), Size::new(60.0, 20.0))
+        );
     }
 }
@@ -60,6 +60,7 @@
 //!     }
 //! }
 //! ```
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -71,6 +72,7 @@ use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -425,6 +427,28 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut node = accessibility::Node::new(accessibility::Role::ComboBox);
+
+        if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder);
+        }
+
+        if let Some(selected) = &self.selected {
+            node = node.value(accessibility::Value::Text(
+                selected.borrow().to_string(),
+            ));
+        }
+
+        operation.accessible(None, layout.bounds(), &node);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -20,6 +20,8 @@
 //! }
 //! ```
 use crate::container;
+use crate::core::accessibility;
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -485,6 +487,12 @@ where
         let translation =
             state.translation(self.direction, bounds, content_bounds);
 
+        operation.accessible(
+            self.id.as_ref().map(|id| &id.0),
+            bounds,
+            &accessibility::Node::new(accessibility::Role::ScrollView),
+        );
+
         operation.scrollable(
             state,
             self.id.as_ref().map(|id| &id.0),
@@ -866,7 +874,7 @@ where
 
                 event::Status::Captured
             }
-            Event::Window(window::Event::RedrawRequested(_)) => {
+            Event::Window(window::Event::RedrawRequested(now)) => {
                 let _ = notify_viewport(
                     state,
                     &self.on_scroll,
@@ -875,6 +883,23 @@ where
                     shell,
                 );
 
+                let status = state.status(
+                    cursor_over_scrollable.is_some(),
+                    mouse_over_x_scrollbar,
+                    mouse_over_y_scrollbar,
+                );
+
+                state.now = now;
+
+                let animation =
+                    state.status.get_or_insert_with(|| Animation::new(status));
+
+                animation.go_mut(status, now);
+
+                if let Some(request) = animation.redraw_request(now) {
+                    shell.request_redraw(request);
+                }
+
                 event::Status::Ignored
             }
             _ => event::Status::Ignored,
@@ -920,27 +945,20 @@ where
             _ => mouse::Cursor::Unavailable,
         };
 
-        let status = if state.y_scroller_grabbed_at.is_some()
-            || state.x_scroller_grabbed_at.is_some()
-        {
-            Status::Dragged {
-                is_horizontal_scrollbar_dragged: state
-                    .x_scroller_grabbed_at
-                    .is_some(),
-                is_vertical_scrollbar_dragged: state
-                    .y_scroller_grabbed_at
-                    .is_some(),
-            }
-        } else if cursor_over_scrollable.is_some() {
-            Status::Hovered {
-                is_horizontal_scrollbar_hovered: mouse_over_x_scrollbar,
-                is_vertical_scrollbar_hovered: mouse_over_y_scrollbar,
-            }
-        } else {
-            Status::Active
-        };
+        let status = state.status(
+            cursor_over_scrollable.is_some(),
+            mouse_over_x_scrollbar,
+            mouse_over_y_scrollbar,
+        );
 
-        let style = theme.style(&self.class, status);
+        let style = match &state.status {
+            Some(animation) if *animation.target() == status => animation
+                .interpolate_with(
+                    |status| theme.style(&self.class, *status),
+                    state.now,
+                ),
+            _ => theme.style(&self.class, status),
+        };
 
         container::draw_background(renderer, &style.container, layout.bounds());
 
@@ -1287,6 +1305,8 @@ struct State {
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
+    status: Option<Animation<Status>>,
+    now: Instant,
 }
 
 impl Default for State {
@@ -1300,6 +1320,8 @@ impl Default for State {
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
             last_scrolled: None,
+            status: None,
+            now: Instant::now(),
         }
     }
 }
@@ -1419,6 +1441,33 @@ impl State {
         State::default()
     }
 
+    fn status(
+        &self,
+        is_mouse_over: bool,
+        mouse_over_x_scrollbar: bool,
+        mouse_over_y_scrollbar: bool,
+    ) -> Status {
+        if self.y_scroller_grabbed_at.is_some()
+            || self.x_scroller_grabbed_at.is_some()
+        {
+            Status::Dragged {
+                is_horizontal_scrollbar_dragged: self
+                    .x_scroller_grabbed_at
+                    .is_some(),
+                is_vertical_scrollbar_dragged: self
+                    .y_scroller_grabbed_at
+                    .is_some(),
+            }
+        } else if is_mouse_over {
+            Status::Hovered {
+                is_horizontal_scrollbar_hovered: mouse_over_x_scrollbar,
+                is_vertical_scrollbar_hovered: mouse_over_y_scrollbar,
+            }
+        } else {
+            Status::Active
+        }
+    }
+
     /// Apply a scrolling offset to the current [`State`], given the bounds of
     /// the [`Scrollable`] and its contents.
     pub fn scroll(
@@ -1834,6 +1883,15 @@ pub(super) mod internals {
     }
 }
 
+impl Interpolate for Scroller {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Scroller {
+            color: self.color.interpolate(&other.color, amount),
+            border: self.border.interpolate(&other.border, amount),
+        }
+    }
+}
+
 /// The possible status of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -1868,6 +1926,21 @@ pub struct Style {
     pub gap: Option<Background>,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Style {
+            container: self.container.interpolate(&other.container, amount),
+            vertical_rail: self
+                .vertical_rail
+                .interpolate(&other.vertical_rail, amount),
+            horizontal_rail: self
+                .horizontal_rail
+                .interpolate(&other.horizontal_rail, amount),
+            gap: self.gap.interpolate(&other.gap, amount),
+        }
+    }
+}
+
 /// The appearance of the scrollbar of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Rail {
@@ -1879,6 +1952,16 @@ pub struct Rail {
     pub scroller: Scroller,
 }
 
+impl Interpolate for Rail {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Rail {
+            background: self.background.interpolate(&other.background, amount),
+            border: self.border.interpolate(&other.border, amount),
+            scroller: self.scroller.interpolate(&other.scroller, amount),
+        }
+    }
+}
+
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scroller {
@@ -109,7 +109,7 @@ where
             core::Event::Window(window::Event::RedrawRequested(instant)) => {
                 Some(Event::RedrawRequested(instant))
             }
-            core::Event::Window(_) => None,
+            core::Event::Window(_) | core::Event::InputMethod(_) => None,
         };
 
         if let Some(custom_shader_event) = custom_shader_event {
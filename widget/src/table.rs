@@ -0,0 +1,1417 @@
+//! Tables display data in rows that share the same columns.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{scrollable, table, text};
+//!
+//! struct User {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Sort(usize),
+//!     Select(usize),
+//! }
+//!
+//! fn view(users: &[User]) -> Element<'_, Message> {
+//!     let columns = [
+//!         table::column(text("Name"), |user: &User| text(&user.name).into()),
+//!         table::column(text("Age"), |user: &User| text(user.age).into())
+//!             .width(80)
+//!             .sortable(true),
+//!     ];
+//!
+//!     scrollable(
+//!         table(columns, users)
+//!             .on_sort(Message::Sort)
+//!             .on_select(Message::Select),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A grid of rows and columns with a fixed header.
+///
+/// Every [`Column`] of a [`Table`] has a header and a function that builds
+/// its cell for a given row. Only the rows inside the current viewport—plus
+/// some overscan—are built and laid out, so a [`Table`] can be placed inside
+/// a [`Scrollable`] to browse data sets of any size. The header stays at the
+/// top of the viewport while the rows are scrolled.
+///
+/// Columns can be resized by dragging the edges of their headers. The
+/// [`Table`] produces a message when the header of a sortable [`Column`] is
+/// clicked, but sorting the rows is up to your application.
+///
+/// [`Scrollable`]: crate::Scrollable
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{scrollable, table, text};
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize),
+///     Select(usize),
+/// }
+///
+/// fn view(users: &[User]) -> Element<'_, Message> {
+///     let columns = [
+///         table::column(text("Name"), |user: &User| text(&user.name).into()),
+///         table::column(text("Age"), |user: &User| text(user.age).into())
+///             .width(80)
+///             .sortable(true),
+///     ];
+///
+///     scrollable(
+///         table(columns, users)
+///             .on_sort(Message::Sort)
+///             .on_select(Message::Select),
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    columns: Vec<Column<'a, T, Message, Theme, Renderer>>,
+    rows: Vec<T>,
+    row_height: f32,
+    padding: Padding,
+    overscan: usize,
+    selection: Vec<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    cells: RefCell<Cells<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 30.0;
+
+    /// The minimum height of the rows of a [`Table`].
+    pub const MIN_ROW_HEIGHT: f32 = 1.0;
+
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// The default amount of rows built outside of the viewport.
+    pub const DEFAULT_OVERSCAN: usize = 5;
+
+    /// The minimum width a [`Column`] can be resized to.
+    pub const MIN_COLUMN_WIDTH: f32 = 30.0;
+
+    /// Creates a new [`Table`] with the given columns and rows.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, T, Message, Theme, Renderer>>,
+        rows: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            overscan: Self::DEFAULT_OVERSCAN,
+            selection: Vec::new(),
+            on_select: None,
+            on_sort: None,
+            class: Theme::default(),
+            cells: RefCell::new(Cells::default()),
+        }
+    }
+
+    /// Sets the height of every row of the [`Table`].
+    ///
+    /// The height is clamped to at least [`Table::MIN_ROW_HEIGHT`].
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = height.into().0.max(Self::MIN_ROW_HEIGHT);
+        self
+    }
+
+    /// Sets the [`Padding`] of every cell of the [`Table`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the amount of rows built before and after the viewport.
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Sets the indices of the selected rows of the [`Table`].
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that should be produced when a row of the [`Table`]
+    /// is clicked.
+    ///
+    /// The closure receives the index of the row.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable [`Column`] is clicked.
+    ///
+    /// The closure receives the index of the [`Column`].
+    pub fn on_sort(mut self, on_sort: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the vertical offset of the header, keeping it inside the
+    /// viewport while the rows are scrolled.
+    fn header_offset(&self, layout: Layout<'_>, viewport: &Rectangle) -> f32 {
+        let bounds = layout.bounds();
+        let header = layout.children().next().map(|header| header.bounds());
+
+        let max = header.map_or(0.0, |header| bounds.height - header.height);
+
+        (viewport.y - bounds.y).min(max).max(0.0)
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    header: Element<'a, Message, Theme, Renderer>,
+    cell: Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    is_sortable: bool,
+    order: Option<Order>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Column<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`Column`] with the given header and a function that
+    /// builds its cell for a given row.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        cell: impl Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            cell: Box::new(cell),
+            width: Length::Fill,
+            is_sortable: false,
+            order: None,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// A [`Column`] can not shrink to fit its cells, since only the visible
+    /// rows of a [`Table`] are ever built. Any width other than
+    /// [`Length::Fixed`] shares the space left by the fixed columns, like
+    /// [`Length::FillPortion`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets whether the [`Column`] can be sorted by clicking its header.
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+
+    /// Sets the current sort [`Order`] of the [`Column`], if any.
+    ///
+    /// The header of a sorted [`Column`] displays an indicator.
+    pub fn sorted(mut self, order: impl Into<Option<Order>>) -> Self {
+        self.order = order.into();
+        self
+    }
+}
+
+/// Creates a new [`Column`] with the given header and a function that builds
+/// its cell for a given row.
+pub fn column<'a, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    cell: impl Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Column<'a, T, Message, Theme, Renderer> {
+    Column::new(header, cell)
+}
+
+/// The sort order of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// The rows are sorted from lowest to highest.
+    Ascending,
+    /// The rows are sorted from highest to lowest.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The cells currently built by a [`Table`], row by row.
+struct Cells<'a, Message, Theme, Renderer> {
+    range: Range<usize>,
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<Message, Theme, Renderer> Default for Cells<'_, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self {
+            range: 0..0,
+            elements: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct State {
+    range: Range<usize>,
+    built: Range<usize>,
+    resized: Vec<Option<f32>>,
+    widths: Vec<f32>,
+    resizing: Option<Resizing>,
+    viewport: Option<(f32, f32)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl State {
+    /// Returns the edges of the columns, relative to the table.
+    fn edges(&self) -> impl Iterator<Item = f32> + '_ {
+        self.widths.iter().scan(0.0, |edge, width| {
+            *edge += width;
+            Some(*edge)
+        })
+    }
+
+    /// Returns the column containing the given horizontal position.
+    fn column_at(&self, x: f32) -> Option<usize> {
+        if x < 0.0 {
+            return None;
+        }
+
+        self.edges().position(|edge| x < edge)
+    }
+
+    /// Returns the column whose right edge is close to the given horizontal
+    /// position.
+    fn edge_at(&self, x: f32) -> Option<usize> {
+        const TOLERANCE: f32 = 4.0;
+
+        self.edges().position(|edge| (x - edge).abs() <= TOLERANCE)
+    }
+
+    /// Returns the rows that must be built to cover the last known
+    /// viewport, if they are not built already.
+    fn outdated(
+        &self,
+        header_height: f32,
+        row_height: f32,
+        overscan: usize,
+        len: usize,
+    ) -> Option<Range<usize>> {
+        let (top, bottom) = self.viewport?;
+
+        let to_row =
+            |y: f32| ((y - header_height) / row_height).max(0.0) as usize;
+
+        let visible = to_row(top).min(len)..(to_row(bottom) + 1).min(len);
+
+        if visible.is_empty()
+            || (visible.start >= self.range.start
+                && visible.end <= self.range.end)
+        {
+            return None;
+        }
+
+        Some(
+            visible.start.saturating_sub(overscan)
+                ..(visible.end + overscan).min(len),
+        )
+    }
+}
+
+/// Resolves the widths of the columns of a [`Table`] for the given amount of
+/// available space.
+fn resolve_widths(
+    columns: impl Iterator<Item = (Length, Option<f32>)>,
+    available: f32,
+    minimum: f32,
+) -> Vec<f32> {
+    let columns: Vec<_> = columns
+        .map(|(width, resized)| match (resized, width) {
+            (Some(resized), _) => Err(resized),
+            (None, Length::Fixed(width)) => Err(width),
+            (None, width) => Ok(width.fill_factor().max(1)),
+        })
+        .collect();
+
+    let fixed: f32 = columns.iter().filter_map(|width| width.err()).sum();
+
+    let portions: u16 = columns.iter().filter_map(|width| width.ok()).sum();
+
+    let remaining = if available.is_finite() {
+        (available - fixed).max(0.0)
+    } else {
+        0.0
+    };
+
+    columns
+        .into_iter()
+        .map(|width| match width {
+            Err(width) => width,
+            Ok(portion) => (remaining * f32::from(portion)
+                / f32::from(portions))
+            .max(minimum),
+        })
+        .collect()
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// Builds the cells of the rows in the current range of the [`State`],
+    /// reusing the widget state of rows that were already built.
+    fn build(&self, tree: &mut Tree) {
+        let columns = self.columns.len();
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        state.range.end = state.range.end.min(self.rows.len());
+        state.range.start = state.range.start.min(state.range.end);
+
+        let mut cells = self.cells.borrow_mut();
+
+        if cells.range == state.range
+            && cells.elements.len() == cells.range.len() * columns
+        {
+            return;
+        }
+
+        let mut old_cells = std::mem::take(&mut *cells);
+        let mut old_trees = children.split_off(columns);
+
+        for index in state.range.clone() {
+            for (column_index, column) in self.columns.iter().enumerate() {
+                let element = if old_cells.range.contains(&index) {
+                    let position = (index - old_cells.range.start) * columns
+                        + column_index;
+
+                    std::mem::replace(
+                        &mut old_cells.elements[position],
+                        Element::new(crate::Space::new(0, 0)),
+                    )
+                } else {
+                    (column.cell)(self.rows[index].clone())
+                };
+
+                let cell = if state.built.contains(&index) {
+                    let position =
+                        (index - state.built.start) * columns + column_index;
+
+                    let mut cell = std::mem::replace(
+                        &mut old_trees[position],
+                        Tree::empty(),
+                    );
+
+                    cell.diff(element.as_widget());
+                    cell
+                } else {
+                    Tree::new(element.as_widget())
+                };
+
+                cells.elements.push(element);
+                children.push(cell);
+            }
+        }
+
+        cells.range = state.range.clone();
+        state.built = state.range.clone();
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            range: 0..self.overscan * 2,
+            resized: vec![None; self.columns.len()],
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.columns
+            .iter()
+            .map(|column| Tree::new(column.header.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.resized.len() == self.columns.len() {
+            for (header, column) in tree.children.iter_mut().zip(&self.columns)
+            {
+                header.diff(column.header.as_widget());
+            }
+        } else {
+            state.resized = vec![None; self.columns.len()];
+            state.built = 0..0;
+            state.resizing = None;
+
+            tree.children = self.children();
+        }
+
+        self.build(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        let is_fill = self
+            .columns
+            .iter()
+            .any(|column| !matches!(column.width, Length::Fixed(_)));
+
+        Size {
+            width: if is_fill {
+                Length::Fill
+            } else {
+                Length::Shrink
+            },
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let columns = self.columns.len();
+        let size = self.size();
+        let limits = limits.width(size.width);
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        state.widths = resolve_widths(
+            self.columns
+                .iter()
+                .zip(&state.resized)
+                .map(|(column, resized)| (column.width, *resized)),
+            limits.max().width,
+            Self::MIN_COLUMN_WIDTH,
+        );
+
+        let width: f32 = state.widths.iter().sum();
+        let edges: Vec<f32> = std::iter::once(0.0)
+            .chain(state.edges())
+            .take(columns)
+            .collect();
+
+        let headers: Vec<_> = self
+            .columns
+            .iter()
+            .zip(&mut children[..columns])
+            .zip(&state.widths)
+            .map(|((column, tree), width)| {
+                let indicator = if column.is_sortable || column.order.is_some()
+                {
+                    INDICATOR_SPACE
+                } else {
+                    0.0
+                };
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (width - self.padding.horizontal() - indicator)
+                            .max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+
+                column.header.as_widget().layout(tree, renderer, &limits)
+            })
+            .collect();
+
+        let header_height = headers
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max)
+            + self.padding.vertical();
+
+        // The viewport may have moved past the built rows since the last
+        // layout
+        if let Some(range) = state.outdated(
+            header_height,
+            self.row_height,
+            self.overscan,
+            self.rows.len(),
+        ) {
+            state.range = range;
+        }
+
+        self.build(tree);
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_ref::<State>();
+        let cell_trees = &mut children[columns..];
+
+        let row = |nodes: Vec<layout::Node>, height: f32, y: f32| {
+            let children = nodes
+                .into_iter()
+                .zip(&edges)
+                .map(|(node, x)| {
+                    let content = height - self.padding.vertical();
+                    let offset =
+                        ((content - node.size().height) / 2.0).max(0.0);
+
+                    node.move_to((
+                        x + self.padding.left,
+                        self.padding.top + offset,
+                    ))
+                })
+                .collect();
+
+            layout::Node::with_children(Size::new(width, height), children)
+                .move_to((0.0, y))
+        };
+
+        let cells = self.cells.borrow();
+
+        let rows = cells
+            .elements
+            .chunks(columns.max(1))
+            .zip(cell_trees.chunks_mut(columns.max(1)))
+            .zip(state.range.clone())
+            .map(|((elements, trees), index)| {
+                let nodes = elements
+                    .iter()
+                    .zip(trees)
+                    .zip(&state.widths)
+                    .map(|((element, tree), width)| {
+                        let limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (width - self.padding.horizontal()).max(0.0),
+                                (self.row_height - self.padding.vertical())
+                                    .max(0.0),
+                            ),
+                        );
+
+                        element.as_widget().layout(tree, renderer, &limits)
+                    })
+                    .collect();
+
+                row(
+                    nodes,
+                    self.row_height,
+                    header_height + self.row_height * index as f32,
+                )
+            });
+
+        let children = std::iter::once(row(headers, header_height, 0.0))
+            .chain(rows)
+            .collect();
+
+        let height = header_height + self.row_height * self.rows.len() as f32;
+
+        layout::Node::with_children(Size::new(width, height), children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let cells = self.cells.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.columns
+                .iter()
+                .map(|column| &column.header)
+                .chain(&cells.elements)
+                .zip(&mut tree.children)
+                .zip(layout.children().flat_map(Layout::children))
+                .for_each(|((element, state), layout)| {
+                    element
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let columns = self.columns.len();
+        let offset = self.header_offset(layout, viewport);
+
+        let mut children = layout.children();
+        let Some(header) = children.next() else {
+            return event::Status::Ignored;
+        };
+
+        let header_bounds = header.bounds() + Vector::new(0.0, offset);
+
+        let header_cursor = match cursor.position_over(header_bounds) {
+            Some(position) => {
+                mouse::Cursor::Available(position - Vector::new(0.0, offset))
+            }
+            None => mouse::Cursor::Unavailable,
+        };
+
+        let body_cursor = if cursor.is_over(header_bounds) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let (headers, cells) = tree.children.split_at_mut(columns);
+
+        let headers = self
+            .columns
+            .iter_mut()
+            .zip(headers)
+            .zip(header.children())
+            .map(|((column, state), layout)| {
+                column.header.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    header_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let cells = self
+            .cells
+            .get_mut()
+            .elements
+            .iter_mut()
+            .zip(cells)
+            .zip(children.flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    body_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let mut status = headers.merge(cells);
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if status == event::Status::Ignored =>
+            {
+                if let Some(position) = header_cursor.position() {
+                    let x = position.x - bounds.x;
+
+                    if let Some(column) = state.edge_at(x) {
+                        state.resizing = Some(Resizing {
+                            column,
+                            origin: position.x,
+                            width: state.widths[column],
+                        });
+
+                        status = event::Status::Captured;
+                    } else if let Some(column) = state.column_at(x) {
+                        if let Some(on_sort) = self
+                            .on_sort
+                            .as_ref()
+                            .filter(|_| self.columns[column].is_sortable)
+                        {
+                            shell.publish(on_sort(column));
+                        }
+
+                        status = event::Status::Captured;
+                    }
+                } else if let Some(on_select) = &self.on_select {
+                    if let Some(index) = layout
+                        .children()
+                        .skip(1)
+                        .position(|row| body_cursor.is_over(row.bounds()))
+                    {
+                        shell.publish(on_select(state.range.start + index));
+
+                        status = event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(resizing) = state.resizing {
+                    let width = (resizing.width + position.x - resizing.origin)
+                        .max(Self::MIN_COLUMN_WIDTH);
+
+                    state.resized[resizing.column] = Some(width);
+
+                    shell.invalidate_layout();
+
+                    status = event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.resizing.is_some() =>
+            {
+                state.resizing = None;
+                status = event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let top = viewport.y - bounds.y;
+
+        state.viewport = Some((top, top + viewport.height));
+
+        if let Some(range) = state.outdated(
+            header.bounds().height,
+            self.row_height,
+            self.overscan,
+            self.rows.len(),
+        ) {
+            state.range = range;
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let offset = self.header_offset(layout, viewport);
+        let bounds = layout.bounds();
+
+        let mut children = layout.children();
+        let Some(header) = children.next() else {
+            return mouse::Interaction::None;
+        };
+
+        let header_bounds = header.bounds() + Vector::new(0.0, offset);
+
+        if let Some(position) = cursor.position_over(header_bounds) {
+            let x = position.x - bounds.x;
+
+            if state.edge_at(x).is_some() {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+
+            let cursor =
+                mouse::Cursor::Available(position - Vector::new(0.0, offset));
+
+            let interaction = self
+                .columns
+                .iter()
+                .zip(&tree.children)
+                .zip(header.children())
+                .map(|((column, state), layout)| {
+                    column.header.as_widget().mouse_interaction(
+                        state, layout, cursor, viewport, renderer,
+                    )
+                })
+                .max()
+                .unwrap_or_default();
+
+            let is_sortable = self.on_sort.is_some()
+                && state
+                    .column_at(x)
+                    .is_some_and(|column| self.columns[column].is_sortable);
+
+            return if interaction == mouse::Interaction::None && is_sortable {
+                mouse::Interaction::Pointer
+            } else {
+                interaction
+            };
+        }
+
+        self.cells
+            .borrow()
+            .elements
+            .iter()
+            .zip(&tree.children[self.columns.len()..])
+            .zip(children.flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let columns = self.columns.len();
+
+        let offset = self.header_offset(layout, viewport);
+        let bounds = layout.bounds();
+
+        let mut children = layout.children();
+        let Some(header) = children.next() else {
+            return;
+        };
+
+        let header_bounds = header.bounds() + Vector::new(0.0, offset);
+        let is_over_header = cursor.is_over(header_bounds);
+
+        let cells = self.cells.borrow();
+
+        for ((index, row), (elements, trees)) in
+            state.range.clone().zip(children).zip(
+                cells
+                    .elements
+                    .chunks(columns.max(1))
+                    .zip(tree.children[columns..].chunks(columns.max(1))),
+            )
+        {
+            let row_bounds = row.bounds();
+
+            if !row_bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = self.selection.contains(&index);
+
+            let background = if is_selected {
+                Some(style.selected_row_background)
+            } else if !is_over_header
+                && self.on_select.is_some()
+                && cursor.is_over(row_bounds)
+            {
+                style.hovered_row_background
+            } else if index % 2 == 1 {
+                style.alternate_row_background
+            } else {
+                style.row_background
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                None
+            };
+
+            let cursor = if is_over_header {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            };
+
+            for ((cell, tree), layout) in
+                elements.iter().zip(trees).zip(row.children())
+            {
+                cell.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: text_color.unwrap_or(defaults.text_color),
+                    },
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+
+        let Some(clip) = header_bounds.intersection(viewport) else {
+            return;
+        };
+
+        let header_cursor = match cursor.position_over(header_bounds) {
+            Some(position) => {
+                mouse::Cursor::Available(position - Vector::new(0.0, offset))
+            }
+            None => mouse::Cursor::Unavailable,
+        };
+
+        let hovered_edge = header_cursor
+            .position()
+            .and_then(|position| state.edge_at(position.x - bounds.x));
+
+        let resized = state.resizing.map(|resizing| resizing.column);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    ..renderer::Quad::default()
+                },
+                style.header_background,
+            );
+
+            for (index, edge) in state.edges().enumerate() {
+                let is_active =
+                    hovered_edge == Some(index) || resized == Some(index);
+
+                let width = if is_active { 2.0 } else { 1.0 };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (bounds.x + edge - width / 2.0).round(),
+                            y: header_bounds.y,
+                            width,
+                            height: header_bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    if is_active {
+                        style.resize_handle
+                    } else {
+                        style.divider
+                    },
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: header_bounds.y + header_bounds.height - 1.0,
+                        height: 1.0,
+                        ..header_bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider,
+            );
+
+            for (column, edge) in self.columns.iter().zip(state.edges()) {
+                let Some(order) = column.order else {
+                    continue;
+                };
+
+                draw_indicator(
+                    renderer,
+                    order,
+                    Point::new(
+                        bounds.x + edge
+                            - self.padding.right
+                            - INDICATOR_SPACE / 2.0,
+                        header_bounds.center_y(),
+                    ),
+                    style.header_text_color.unwrap_or(defaults.text_color),
+                );
+            }
+
+            renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+                for ((column, tree), layout) in self
+                    .columns
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(header.children())
+                {
+                    column.header.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style
+                                .header_text_color
+                                .unwrap_or(defaults.text_color),
+                        },
+                        layout,
+                        header_cursor,
+                        &(*viewport - Vector::new(0.0, offset)),
+                    );
+                }
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .columns
+            .iter_mut()
+            .map(|column| &mut column.header)
+            .chain(&mut self.cells.get_mut().elements)
+            .zip(&mut tree.children)
+            .zip(layout.children().flat_map(Layout::children))
+            .filter_map(|((element, state), layout)| {
+                element.as_widget_mut().overlay(
+                    state,
+                    layout,
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Table<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(table: Table<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(table)
+    }
+}
+
+/// The horizontal space reserved for the sort indicator of a header.
+const INDICATOR_SPACE: f32 = 16.0;
+
+/// Draws a small triangle pointing in the direction of the given [`Order`].
+fn draw_indicator<Renderer>(
+    renderer: &mut Renderer,
+    order: Order,
+    center: Point,
+    color: Color,
+) where
+    Renderer: crate::core::Renderer,
+{
+    const STEPS: usize = 4;
+
+    for step in 0..STEPS {
+        let width = 2.0 * (step + 1) as f32;
+
+        let y = match order {
+            Order::Ascending => step,
+            Order::Descending => STEPS - step - 1,
+        } as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: (center.y - STEPS as f32 / 2.0).round() + y,
+                    width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the header.
+    pub header_background: Background,
+    /// The text [`Color`] of the header, if different from the default one.
+    pub header_text_color: Option<Color>,
+    /// The [`Background`] of the rows, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row, if any.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of the selected rows.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of the selected rows, if different from the
+    /// default one.
+    pub selected_text_color: Option<Color>,
+    /// The [`Color`] of the dividers of the header.
+    pub divider: Color,
+    /// The [`Color`] of the divider of a column being resized.
+    pub resize_handle: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        header_background: palette.background.weak.color.into(),
+        header_text_color: Some(palette.background.weak.text),
+        row_background: None,
+        alternate_row_background: Some(
+            palette.background.weak.color.scale_alpha(0.4).into(),
+        ),
+        hovered_row_background: Some(
+            palette.primary.weak.color.scale_alpha(0.3).into(),
+        ),
+        selected_row_background: palette.primary.weak.color.into(),
+        selected_text_color: Some(palette.primary.weak.text),
+        divider: palette.background.strong.color,
+        resize_handle: palette.primary.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    type Element = crate::core::Element<'static, (), crate::Theme, ()>;
+
+    fn table(row_height: f32) -> Element {
+        Table::new(
+            [Column::new(Space::new(Length::Fill, 20), |_| {
+                Space::new(Length::Fill, Length::Fill).into()
+            })],
+            0..1000,
+        )
+        .row_height(row_height)
+        .padding(0)
+        .overscan(1)
+        .into()
+    }
+
+    fn layout(table: &Element, tree: &mut Tree) -> layout::Node {
+        table.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        )
+    }
+
+    #[test]
+    fn layout_builds_rows_under_the_last_viewport() {
+        let table = table(10.0);
+        let mut tree = Tree::new(&table);
+        let _ = layout(&table, &mut tree);
+
+        tree.state.downcast_mut::<State>().viewport = Some((520.0, 560.0));
+
+        let node = layout(&table, &mut tree);
+
+        assert_eq!(tree.state.downcast_ref::<State>().range, 49..56);
+        assert_eq!(node.children().len(), 8);
+        assert_eq!(node.children()[1].bounds().y, 510.0);
+    }
+
+    #[test]
+    fn clamps_row_height() {
+        let table = table(0.0);
+        let mut tree = Tree::new(&table);
+        let _ = layout(&table, &mut tree);
+
+        tree.state.downcast_mut::<State>().viewport = Some((0.0, 100.0));
+
+        let node = layout(&table, &mut tree);
+
+        assert_eq!(node.size().height, 1020.0);
+        assert_eq!(tree.state.downcast_ref::<State>().range, 0..82);
+    }
+
+    #[test]
+    fn resolves_widths() {
+        let widths = resolve_widths(
+            [
+                (Length::Fixed(100.0), None),
+                (Length::Fill, None),
+                (Length::FillPortion(3), None),
+                (Length::Fill, Some(50.0)),
+            ]
+            .into_iter(),
+            550.0,
+            30.0,
+        );
+
+        assert_eq!(widths, [100.0, 100.0, 300.0, 50.0]);
+
+        let widths = resolve_widths(
+            [(Length::Fixed(100.0), None), (Length::Fill, None)].into_iter(),
+            f32::INFINITY,
+            30.0,
+        );
+
+        assert_eq!(widths, [100.0, 30.0]);
+    }
+}
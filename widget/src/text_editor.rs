@@ -31,9 +31,12 @@
 //!     }
 //! }
 //! ```
+mod history;
+
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod, Preedit};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -50,13 +53,16 @@ use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::text_input;
 
 use std::cell::RefCell;
 use std::fmt;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-pub use text::editor::{Action, Edit, Motion};
+pub use text::editor::{Action, Edit, Motion, Position};
+
+use history::History;
 
 /// A multi-line text input.
 ///
@@ -313,6 +319,7 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
     is_dirty: bool,
 }
 
@@ -329,18 +336,72 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::new(),
             is_dirty: true,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the undo history of the [`Content`].
+    /// Consecutive insertions are grouped word by word.
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        match action {
+            Action::Edit(edit) => {
+                internal.history.edit(&mut internal.editor, edit);
+            }
+            Action::Undo => {
+                internal.history.undo(&mut internal.editor);
+            }
+            Action::Redo => {
+                internal.history.redo(&mut internal.editor);
+            }
+            Action::Scroll { .. } => {
+                internal.editor.perform(action);
+            }
+            _ => {
+                internal.history.seal();
+                internal.editor.perform(action);
+            }
+        }
+
         internal.is_dirty = true;
     }
 
+    /// Reverts the last editing transaction of the [`Content`], if any.
+    ///
+    /// The cursor and selection are restored to where they were before
+    /// the transaction took place.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Reapplies the last reverted editing transaction of the [`Content`],
+    /// if any.
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns whether the [`Content`] has any transaction to undo.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns whether the [`Content`] has any transaction to redo.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
+    }
+
+    /// Sets the maximum amount of transactions kept in the undo history
+    /// of the [`Content`].
+    ///
+    /// By default, the last 100 transactions are kept.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.0.get_mut().history.set_limit(limit);
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -442,6 +503,7 @@ where
 
         f.debug_struct("Content")
             .field("editor", &internal.editor)
+            .field("history", &internal.history)
             .field("is_dirty", &internal.is_dirty)
             .finish()
     }
@@ -457,6 +519,7 @@ pub struct State<Highlighter: text::Highlighter> {
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    preedit: Option<Preedit>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -511,6 +574,54 @@ impl<Highlighter: text::Highlighter> operation::Focusable
     }
 }
 
+impl<'a, Highlighter, Message, Theme, Renderer>
+    TextEditor<'a, Highlighter, Message, Theme, Renderer>
+where
+    Highlighter: text::Highlighter,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the [`InputMethod`] requested by the [`TextEditor`], given
+    /// its current [`State`] and the [`Cursor`] of its editor.
+    fn input_method(
+        &self,
+        state: &State<Highlighter>,
+        renderer: &Renderer,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> InputMethod {
+        let Some(Focus {
+            is_window_focused: true,
+            ..
+        }) = &state.focus
+        else {
+            return InputMethod::Disabled;
+        };
+
+        let text_bounds = layout.bounds().shrink(self.padding);
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        let position = match cursor {
+            Cursor::Caret(position) => position,
+            Cursor::Selection(ranges) => {
+                ranges.first().map_or(Point::ORIGIN, Rectangle::position)
+            }
+        };
+
+        let line_height = self.line_height.to_absolute(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        InputMethod::Enabled {
+            cursor: Rectangle::new(
+                position + translation,
+                Size::new(1.0, line_height.into()),
+            ),
+            purpose: input_method::Purpose::Normal,
+        }
+    }
+}
+
 impl<'a, Highlighter, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for TextEditor<'a, Highlighter, Message, Theme, Renderer>
 where
@@ -533,6 +644,7 @@ where
             )),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            preedit: None,
         })
     }
 
@@ -602,7 +714,7 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
@@ -644,6 +756,41 @@ where
                         ));
                     }
                 }
+
+                shell.request_input_method(&self.input_method(
+                    state,
+                    renderer,
+                    layout,
+                    self.content.0.borrow().editor.cursor(),
+                ));
+            }
+            Event::InputMethod(event) => {
+                let Some(focus) = &mut state.focus else {
+                    return event::Status::Ignored;
+                };
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+
+                        return event::Status::Ignored;
+                    }
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = Preedit::new(content, selection);
+                        focus.updated_at = Instant::now();
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+                        focus.updated_at = Instant::now();
+
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(content),
+                        ))));
+                    }
+                }
+
+                return event::Status::Captured;
             }
             _ => {}
         }
@@ -769,6 +916,12 @@ where
                         Binding::Delete => {
                             publish(Action::Edit(Edit::Delete));
                         }
+                        Binding::Undo => {
+                            publish(Action::Undo);
+                        }
+                        Binding::Redo => {
+                            publish(Action::Redo);
+                        }
                         Binding::Sequence(sequence) => {
                             for binding in sequence {
                                 apply_binding(
@@ -926,6 +1079,27 @@ where
                 Cursor::Caret(_) => {}
             }
         }
+
+        if let Some(preedit) = &state.preedit {
+            if let InputMethod::Enabled { cursor, .. } = self.input_method(
+                state,
+                renderer,
+                layout,
+                internal.editor.cursor(),
+            ) {
+                text_input::draw_preedit(
+                    renderer,
+                    preedit,
+                    cursor.position(),
+                    font,
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    self.line_height,
+                    style.value,
+                    style.background,
+                    text_bounds,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -1007,6 +1181,10 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Undo the last editing transaction.
+    Undo,
+    /// Redo the last undone editing transaction.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -1063,6 +1241,13 @@ impl<Message> Binding<Message> {
             keyboard::Key::Character("a") if modifiers.command() => {
                 Some(Self::SelectAll)
             }
+            keyboard::Key::Character("z" | "Z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
             _ => {
                 if let Some(text) = text {
                     let c = text.chars().find(|c| !c.is_control())?;
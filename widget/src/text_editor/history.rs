@@ -0,0 +1,316 @@
+use crate::core::text::editor::{Action, Edit, Editor, Position};
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// The undo and redo stacks of some [`Content`].
+///
+/// [`Content`]: super::Content
+#[derive(Debug)]
+pub struct History {
+    undo: VecDeque<Transaction>,
+    redo: Vec<Transaction>,
+    limit: usize,
+    last_insert: Option<char>,
+}
+
+impl History {
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    pub fn new() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit: Self::DEFAULT_LIMIT,
+            last_insert: None,
+        }
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+
+        while self.undo.len() > limit {
+            let _ = self.undo.pop_front();
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Closes the current transaction, if any.
+    ///
+    /// The next edit will always start a new transaction.
+    pub fn seal(&mut self) {
+        self.last_insert = None;
+    }
+
+    /// Performs the given [`Edit`] in the [`Editor`], recording it.
+    pub fn edit(&mut self, editor: &mut impl Editor, edit: Edit) {
+        let before = Selection::of(editor);
+        let line_count = editor.line_count();
+
+        let (start, end) = before.lines();
+
+        // An edit can only change the lines touched by the selection and
+        // their immediate neighbors (e.g. when joining lines).
+        let first = start.saturating_sub(1);
+        let last = (end + 1).min(line_count.saturating_sub(1));
+
+        let old = lines(editor, first, last + 1 - first);
+
+        let insert = match edit {
+            Edit::Insert(c) => Some(c),
+            _ => None,
+        };
+
+        editor.perform(Action::Edit(edit));
+
+        let new_length = (old.len() + editor.line_count())
+            .saturating_sub(line_count)
+            .max(1);
+
+        let change = Change {
+            line: first,
+            new: lines(editor, first, new_length),
+            old,
+        };
+
+        if change.old == change.new {
+            return;
+        }
+
+        let after = Selection::of(editor);
+
+        // Insertions are grouped until a new word starts
+        let continues = match (self.last_insert, insert) {
+            (Some(last), Some(c)) => !last.is_whitespace() || c.is_whitespace(),
+            _ => false,
+        };
+
+        self.last_insert = insert;
+        self.redo.clear();
+
+        if continues {
+            if let Some(transaction) = self.undo.back_mut() {
+                if let Some(change) = transaction.change.merge(&change) {
+                    transaction.change = change;
+                    transaction.after = after;
+
+                    return;
+                }
+            }
+        }
+
+        self.undo.push_back(Transaction {
+            change,
+            before,
+            after,
+        });
+
+        if self.undo.len() > self.limit {
+            let _ = self.undo.pop_front();
+        }
+    }
+
+    /// Reverts the last transaction in the [`Editor`].
+    pub fn undo(&mut self, editor: &mut impl Editor) {
+        self.seal();
+
+        let Some(transaction) = self.undo.pop_back() else {
+            return;
+        };
+
+        let change = &transaction.change;
+
+        replace(editor, change.line, change.new.len(), &change.old);
+        transaction.before.restore(editor);
+
+        self.redo.push(transaction);
+    }
+
+    /// Reapplies the last reverted transaction in the [`Editor`].
+    pub fn redo(&mut self, editor: &mut impl Editor) {
+        self.seal();
+
+        let Some(transaction) = self.redo.pop() else {
+            return;
+        };
+
+        let change = &transaction.change;
+
+        replace(editor, change.line, change.old.len(), &change.new);
+        transaction.after.restore(editor);
+
+        self.undo.push_back(transaction);
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+struct Transaction {
+    change: Change,
+    before: Selection,
+    after: Selection,
+}
+
+/// The cursor and selection anchor of an [`Editor`].
+#[derive(Debug, Clone, Copy)]
+struct Selection {
+    cursor: Position,
+    anchor: Option<Position>,
+}
+
+impl Selection {
+    fn of(editor: &impl Editor) -> Self {
+        let (line, column) = editor.cursor_position();
+
+        Self {
+            cursor: Position { line, column },
+            anchor: editor.selection_anchor(),
+        }
+    }
+
+    fn lines(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+
+        (
+            self.cursor.line.min(anchor.line),
+            self.cursor.line.max(anchor.line),
+        )
+    }
+
+    fn restore(&self, editor: &mut impl Editor) {
+        editor.move_to(self.cursor, self.anchor);
+    }
+}
+
+/// A contiguous range of lines that was replaced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Change {
+    line: usize,
+    old: Vec<String>,
+    new: Vec<String>,
+}
+
+impl Change {
+    /// Combines the [`Change`] with the next one, if they touch.
+    fn merge(&self, next: &Self) -> Option<Self> {
+        let (a, a_len) = (self.line, self.new.len());
+        let (b, b_len) = (next.line, next.old.len());
+
+        if b > a + a_len || a > b + b_len {
+            return None;
+        }
+
+        let start = a.min(b);
+        let end = (a + a_len).max(b + b_len);
+
+        // Lines in between both changes, after `self` and before `next`
+        let middle = |i: usize| {
+            if (b..b + b_len).contains(&i) {
+                next.old[i - b].clone()
+            } else {
+                self.new[i - a].clone()
+            }
+        };
+
+        let old = (start..a)
+            .map(middle)
+            .chain(self.old.iter().cloned())
+            .chain((a + a_len..end).map(middle))
+            .collect();
+
+        let new = (start..b)
+            .map(middle)
+            .chain(next.new.iter().cloned())
+            .chain((b + b_len..end).map(middle))
+            .collect();
+
+        Some(Self {
+            line: start,
+            old,
+            new,
+        })
+    }
+}
+
+fn lines(editor: &impl Editor, start: usize, amount: usize) -> Vec<String> {
+    (start..start + amount)
+        .map(|i| editor.line(i).unwrap_or_default().to_owned())
+        .collect()
+}
+
+fn replace(
+    editor: &mut impl Editor,
+    start: usize,
+    amount: usize,
+    lines: &[String],
+) {
+    let last = start + amount.max(1) - 1;
+
+    editor.move_to(
+        Position {
+            line: last,
+            column: editor.line(last).map(str::len).unwrap_or_default(),
+        },
+        Some(Position {
+            line: start,
+            column: 0,
+        }),
+    );
+
+    editor.perform(Action::Edit(Edit::Paste(Arc::new(lines.join("\n")))));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(line: usize, old: &[&str], new: &[&str]) -> Change {
+        Change {
+            line,
+            old: old.iter().map(ToString::to_string).collect(),
+            new: new.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn merge_same_lines() {
+        let first = change(0, &["a", "b"], &["a", "bx"]);
+        let second = change(0, &["a", "bx"], &["a", "bxy"]);
+
+        assert_eq!(
+            first.merge(&second),
+            Some(change(0, &["a", "b"], &["a", "bxy"]))
+        );
+    }
+
+    #[test]
+    fn merge_overlapping_lines() {
+        let first = change(1, &["b", "c"], &["b", "c", ""]);
+        let second = change(2, &["c", "", "d"], &["c", "x", "d"]);
+
+        assert_eq!(
+            first.merge(&second),
+            Some(change(1, &["b", "c", "d"], &["b", "c", "x", "d"]))
+        );
+    }
+
+    #[test]
+    fn merge_disjoint_lines() {
+        let first = change(0, &["a"], &["ab"]);
+        let second = change(5, &["f"], &["fg"]);
+
+        assert_eq!(first.merge(&second), None);
+    }
+}
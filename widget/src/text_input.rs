@@ -31,18 +31,24 @@
 //! }
 //! ```
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
+pub mod mask;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod, Preedit};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -119,6 +125,7 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    constraints: Constraints<'a>,
     class: Theme::Class<'a>,
 }
 
@@ -149,6 +156,7 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            constraints: Constraints::default(),
             class: Theme::default(),
         }
     }
@@ -223,6 +231,36 @@ where
         self
     }
 
+    /// Sets the maximum amount of graphemes of the [`TextInput`].
+    ///
+    /// Any edit exceeding it is rejected before producing a message.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.constraints.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets a predicate the contents of the [`TextInput`] must satisfy.
+    ///
+    /// The predicate is called with the contents resulting from every edit
+    /// and, if it returns `false`, the edit is rejected before producing a
+    /// message; keeping the cursor in place.
+    ///
+    /// Keep in mind that the contents are built one edit at a time. For
+    /// instance, a numeric field should accept an empty string.
+    pub fn filter(mut self, filter: impl Fn(&str) -> bool + 'a) -> Self {
+        self.constraints.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    ///
+    /// Edits are formatted with the [`Mask`], inserting its literals
+    /// automatically, and rejected if they do not fit it.
+    pub fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.constraints.mask = Some(mask.into());
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -290,6 +328,11 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -581,6 +624,85 @@ where
         } else {
             draw(renderer, text_bounds);
         }
+
+        if let Some(preedit) = &state.preedit {
+            if let InputMethod::Enabled { cursor, .. } =
+                self.input_method(state, layout, value)
+            {
+                draw_preedit(
+                    renderer,
+                    preedit,
+                    cursor.position(),
+                    self.font.unwrap_or_else(|| renderer.default_font()),
+                    self.size.unwrap_or_else(|| renderer.default_size()),
+                    self.line_height,
+                    style.value,
+                    style.background,
+                    text_bounds,
+                );
+            }
+        }
+    }
+
+    /// Returns the [`InputMethod`] requested by the [`TextInput`], given its
+    /// current [`State`] and its (possibly secured) [`Value`].
+    fn input_method(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        value: &Value,
+    ) -> InputMethod {
+        let Some(Focus {
+            is_window_focused: true,
+            ..
+        }) = &state.is_focused
+        else {
+            return InputMethod::Disabled;
+        };
+
+        if self.on_input.is_none() {
+            return InputMethod::Disabled;
+        }
+
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let caret = match state.cursor.state(value) {
+            cursor::State::Index(position) => position,
+            cursor::State::Selection { start, end } => start.min(end),
+        };
+
+        let (position, offset) = measure_cursor_and_scroll_offset(
+            state.value.raw(),
+            text_bounds,
+            caret,
+        );
+
+        let paragraph = if value.is_empty() {
+            state.placeholder.raw()
+        } else {
+            state.value.raw()
+        };
+
+        let alignment_offset = alignment_offset(
+            text_bounds.width,
+            paragraph.min_width(),
+            self.alignment,
+        );
+
+        InputMethod::Enabled {
+            cursor: Rectangle {
+                x: (text_bounds.x + position + alignment_offset - offset)
+                    .floor(),
+                y: text_bounds.y,
+                width: 1.0,
+                height: text_bounds.height,
+            },
+            purpose: if self.is_secure {
+                input_method::Purpose::Secure
+            } else {
+                input_method::Purpose::Normal
+            },
+        }
     }
 }
 
@@ -627,12 +749,27 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        let mut node = accessibility::Node::new(accessibility::Role::TextInput)
+            .label(&self.placeholder)
+            .disabled(self.on_input.is_none())
+            .focused(state.is_focused());
+
+        if !self.is_secure {
+            node =
+                node.value(accessibility::Value::Text(self.value.to_string()));
+        }
+
+        operation.accessible(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &node,
+        );
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
     }
@@ -850,14 +987,17 @@ where
                                 );
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                &self.constraints,
+                                Edit::Delete,
+                            ) {
+                                shell.publish((on_input)(contents));
 
-                            update_cache(state, &self.value);
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -883,21 +1023,51 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                &self.constraints,
+                                Edit::Paste(content.clone()),
+                            ) {
+                                let message =
+                                    if let Some(paste) = &self.on_paste {
+                                        (paste)(contents)
+                                    } else {
+                                        (on_input)(contents)
+                                    };
+                                shell.publish(message);
+
+                                update_cache(state, &self.value);
+                            }
+
+                            state.is_pasting = Some(content);
 
-                            editor.paste(content.clone());
+                            return event::Status::Captured;
+                        }
+                        keyboard::Key::Character("z" | "Z")
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let Some(on_input) = &self.on_input else {
+                                return event::Status::Ignored;
+                            };
 
-                            let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
+                            let entry = if state.keyboard_modifiers.shift() {
+                                state.history.redo(&self.value, state.cursor)
                             } else {
-                                (on_input)(editor.contents())
+                                state.history.undo(&self.value, state.cursor)
                             };
-                            shell.publish(message);
 
-                            state.is_pasting = Some(content);
+                            if let Some(entry) = entry {
+                                self.value = entry.value;
+                                state.cursor = entry.cursor;
 
-                            update_cache(state, &self.value);
+                                shell.publish((on_input)(
+                                    self.value.to_string(),
+                                ));
+
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -921,17 +1091,19 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.insert(c);
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
                             focus.updated_at = Instant::now();
 
-                            update_cache(state, &self.value);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                &self.constraints,
+                                Edit::Insert(c),
+                            ) {
+                                shell.publish((on_input)(contents));
+
+                                update_cache(state, &self.value);
+                            }
 
                             return event::Status::Captured;
                         }
@@ -948,59 +1120,73 @@ where
                                 return event::Status::Ignored;
                             };
 
-                            if modifiers.jump()
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
+                            // Words are deleted with the jump key or Ctrl,
+                            // on every platform
+                            let is_word =
+                                modifiers.jump() || modifiers.control();
+
+                            if state.cursor.selection(&self.value).is_none() {
+                                if modifiers.macos_command()
+                                    || is_word && self.is_secure
+                                {
                                     let cursor_pos =
                                         state.cursor.end(&self.value);
                                     state.cursor.select_range(0, cursor_pos);
-                                } else {
+                                } else if is_word {
                                     state
                                         .cursor
                                         .select_left_by_words(&self.value);
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
-
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                &self.constraints,
+                                Edit::Backspace,
+                            ) {
+                                shell.publish((on_input)(contents));
 
-                            update_cache(state, &self.value);
+                                update_cache(state, &self.value);
+                            }
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
                             let Some(on_input) = &self.on_input else {
                                 return event::Status::Ignored;
                             };
 
-                            if modifiers.jump()
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
+                            let is_word =
+                                modifiers.jump() || modifiers.control();
+
+                            if state.cursor.selection(&self.value).is_none() {
+                                if modifiers.macos_command()
+                                    || is_word && self.is_secure
+                                {
                                     let cursor_pos =
                                         state.cursor.end(&self.value);
                                     state.cursor.select_range(
                                         cursor_pos,
                                         self.value.len(),
                                     );
-                                } else {
+                                } else if is_word {
                                     state
                                         .cursor
                                         .select_right_by_words(&self.value);
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            if let Some(contents) = edit(
+                                &mut self.value,
+                                &mut state.cursor,
+                                &mut state.history,
+                                &self.constraints,
+                                Edit::Delete,
+                            ) {
+                                shell.publish((on_input)(contents));
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
-
-                            update_cache(state, &self.value);
+                                update_cache(state, &self.value);
+                            }
                         }
                         keyboard::Key::Named(key::Named::Home) => {
                             if modifiers.shift() {
@@ -1164,6 +1350,56 @@ where
                         ));
                     }
                 }
+
+                let secure_value = self.is_secure.then(|| self.value.secure());
+                let value = secure_value.as_ref().unwrap_or(&self.value);
+
+                shell.request_input_method(
+                    &self.input_method(state, layout, value),
+                );
+            }
+            Event::InputMethod(event) => {
+                let state = state::<Renderer>(tree);
+
+                let Some(focus) = &mut state.is_focused else {
+                    return event::Status::Ignored;
+                };
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = Preedit::new(content, selection);
+                        focus.updated_at = Instant::now();
+
+                        return event::Status::Captured;
+                    }
+                    input_method::Event::Commit(content) => {
+                        let Some(on_input) = &self.on_input else {
+                            return event::Status::Ignored;
+                        };
+
+                        state.preedit = None;
+                        state.is_pasting = None;
+                        focus.updated_at = Instant::now();
+
+                        if let Some(contents) = edit(
+                            &mut self.value,
+                            &mut state.cursor,
+                            &mut state.history,
+                            &self.constraints,
+                            Edit::Paste(Value::new(&content)),
+                        ) {
+                            shell.publish((on_input)(contents));
+
+                            update_cache(state, &self.value);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
             }
             _ => {}
         }
@@ -1327,6 +1563,8 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<Preedit>,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1430,6 +1668,82 @@ impl<P: text::Paragraph> operation::TextInput for State<P> {
     }
 }
 
+/// The constraints on the contents of a [`TextInput`].
+#[derive(Default)]
+struct Constraints<'a> {
+    max_length: Option<usize>,
+    filter: Option<Box<dyn Fn(&str) -> bool + 'a>>,
+    mask: Option<Mask>,
+}
+
+/// An edit of the contents of a [`TextInput`].
+enum Edit {
+    Insert(char),
+    Paste(Value),
+    Backspace,
+    Delete,
+}
+
+/// Applies an [`Edit`] to the given [`Value`] and [`Cursor`], returning the
+/// new contents if they satisfy the [`Constraints`].
+///
+/// Accepted edits are recorded in the [`History`]. Rejected edits leave the [`Value`] and the [`Cursor`] untouched.
+fn edit(
+    value: &mut Value,
+    cursor: &mut Cursor,
+    history: &mut History,
+    constraints: &Constraints<'_>,
+    edit: Edit,
+) -> Option<String> {
+    let kind = match (&edit, cursor.selection(value)) {
+        (Edit::Insert(c), None) => history::Kind::Insert(*c),
+        (Edit::Backspace | Edit::Delete, None) => history::Kind::Delete,
+        _ => history::Kind::Other,
+    };
+
+    let mut new_value = value.clone();
+    let mut new_cursor = *cursor;
+
+    let mut editor = Editor::new(&mut new_value, &mut new_cursor);
+
+    match edit {
+        Edit::Insert(c) => editor.insert(c),
+        Edit::Paste(content) => editor.paste(content),
+        Edit::Backspace => editor.backspace(),
+        Edit::Delete => editor.delete(),
+    }
+
+    if let Some(mask) = &constraints.mask {
+        let (contents, position) =
+            mask.format(&new_value.to_string(), new_cursor.end(&new_value))?;
+
+        new_value = Value::new(&contents);
+        new_cursor.move_to(position);
+    }
+
+    if let Some(max_length) = constraints.max_length {
+        // Edits shrinking an overflowing value are still allowed
+        if new_value.len() > max_length && new_value.len() >= value.len() {
+            return None;
+        }
+    }
+
+    let contents = new_value.to_string();
+
+    if let Some(filter) = &constraints.filter {
+        if !filter(&contents) {
+            return None;
+        }
+    }
+
+    history.record(value, *cursor, kind, new_cursor.end(&new_value));
+
+    *value = new_value;
+    *cursor = new_cursor;
+
+    Some(contents)
+}
+
 fn offset<P: text::Paragraph>(
     text_bounds: Rectangle,
     value: &Value,
@@ -1627,6 +1941,83 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     }
 }
 
+/// Draws the [`Preedit`] of an input method with its top-left corner at the
+/// given position, underlined and on top of any existing contents.
+pub(crate) fn draw_preedit<Renderer>(
+    renderer: &mut Renderer,
+    preedit: &Preedit,
+    position: Point,
+    font: Renderer::Font,
+    size: Pixels,
+    line_height: text::LineHeight,
+    color: Color,
+    background: Background,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let paragraph = Renderer::Paragraph::with_text(Text {
+        content: &preedit.content,
+        bounds: Size::INFINITY,
+        size,
+        line_height,
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::None,
+    });
+
+    let bounds = Rectangle::new(position, paragraph.min_bounds());
+
+    let underline = |start: f32, end: f32, thickness: f32| renderer::Quad {
+        bounds: Rectangle {
+            x: position.x + start,
+            y: bounds.y + bounds.height - thickness,
+            width: end - start,
+            height: thickness,
+        },
+        ..renderer::Quad::default()
+    };
+
+    renderer.with_layer(clip_bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+
+        renderer.fill_paragraph(&paragraph, position, color, clip_bounds);
+        renderer.fill_quad(underline(0.0, bounds.width, 1.0), color);
+
+        if let Some(selection) = preedit
+            .selection
+            .as_ref()
+            .filter(|selection| selection.start < selection.end)
+        {
+            let x = |offset: usize| {
+                let index = preedit.content.get(..offset).map_or(0, |text| {
+                    unicode_segmentation::UnicodeSegmentation::graphemes(
+                        text, true,
+                    )
+                    .count()
+                });
+
+                paragraph
+                    .grapheme_position(0, index)
+                    .map_or(0.0, |position| position.x)
+            };
+
+            renderer.fill_quad(
+                underline(x(selection.start), x(selection.end), 2.0),
+                color,
+            );
+        }
+    });
+}
+
 fn alignment_offset(
     text_bounds_width: f32,
     text_min_width: f32,
@@ -0,0 +1,141 @@
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The undo and redo stacks of a [`TextInput`].
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    last: Option<Group>,
+}
+
+/// The contents of a [`TextInput`] before an edit.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub value: Value,
+    pub cursor: Cursor,
+}
+
+/// The kind of an edit, used to group consecutive edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert(char),
+    Delete,
+    Other,
+}
+
+/// The last recorded edit and the position of the cursor after it.
+#[derive(Debug, Clone, Copy)]
+struct Group {
+    kind: Kind,
+    position: usize,
+}
+
+impl History {
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// Records an edit of the given [`Kind`], given the contents before it
+    /// and the position of the cursor after it.
+    pub fn record(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+        kind: Kind,
+        position: usize,
+    ) {
+        // Insertions are grouped until a new word starts, and deletions
+        // until the cursor is moved
+        let continues = self.last.is_some_and(|last| {
+            let is_same_kind = match (last.kind, kind) {
+                (Kind::Insert(last), Kind::Insert(c)) => {
+                    !last.is_whitespace() || c.is_whitespace()
+                }
+                (Kind::Delete, Kind::Delete) => true,
+                _ => false,
+            };
+
+            is_same_kind
+                && cursor.selection(value).is_none()
+                && cursor.end(value) == last.position
+        });
+
+        self.last = (kind != Kind::Other).then_some(Group { kind, position });
+        self.redo.clear();
+
+        if continues {
+            return;
+        }
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        if self.undo.len() > Self::DEFAULT_LIMIT {
+            let _ = self.undo.pop_front();
+        }
+    }
+
+    /// Pops the last [`Entry`] to undo, saving the given contents to be
+    /// redone.
+    pub fn undo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        let entry = self.undo.pop_back()?;
+
+        self.redo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.last = None;
+
+        Some(entry)
+    }
+
+    /// Pops the last [`Entry`] to redo, saving the given contents to be
+    /// undone.
+    pub fn redo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.last = None;
+
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_words() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for (i, c) in "hello world".chars().enumerate() {
+            history.record(&value, cursor, Kind::Insert(c), i + 1);
+
+            value.insert(i, c);
+            cursor.move_to(i + 1);
+        }
+
+        let entry = history.undo(&value, cursor).unwrap();
+        assert_eq!(entry.value.to_string(), "hello ");
+
+        let entry = history.undo(&entry.value, entry.cursor).unwrap();
+        assert_eq!(entry.value.to_string(), "");
+
+        assert!(history.undo(&entry.value, entry.cursor).is_none());
+
+        let entry = history.redo(&entry.value, entry.cursor).unwrap();
+        assert_eq!(entry.value.to_string(), "hello ");
+    }
+}
@@ -0,0 +1,148 @@
+//! Constrain the contents of a text input to a pattern.
+
+/// An input mask of a [`TextInput`].
+///
+/// A [`Mask`] is described by a pattern, where every character is a slot
+/// of the contents:
+///
+/// - `9` accepts an ASCII digit.
+/// - `A` accepts an alphabetic character.
+/// - `*` accepts an alphanumeric character.
+/// - `H` accepts an ASCII hexadecimal digit.
+/// - `\` escapes the next character of the pattern.
+/// - Any other character is a literal, which is inserted automatically
+///   as soon as the contents reach it.
+///
+/// For instance, `"99/99/9999"` describes a date, `"(999) 999-9999"` a
+/// phone number, and `"#HHHHHH"` a hex color.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Hex,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Hex => c.is_ascii_hexdigit(),
+            Slot::Literal(literal) => c == literal,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut slots = Vec::new();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => Slot::Digit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                'H' => Slot::Hex,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Formats the given contents with the [`Mask`], returning the formatted
+    /// contents and the new position of the cursor, given in characters.
+    ///
+    /// Literals are inserted where missing, and misplaced literals are
+    /// dropped. Returns `None` if the contents cannot fit the [`Mask`].
+    pub(crate) fn format(
+        &self,
+        contents: &str,
+        cursor: usize,
+    ) -> Option<(String, usize)> {
+        let mut slots = self.slots.iter().copied().peekable();
+        let mut formatted = String::new();
+        let mut length = 0;
+        let mut position = 0;
+
+        for (i, c) in contents.chars().enumerate() {
+            let is_literal = self.slots.contains(&Slot::Literal(c));
+
+            loop {
+                let Some(&slot) = slots.peek() else {
+                    if is_literal {
+                        break;
+                    }
+
+                    return None;
+                };
+
+                if slot.accepts(c) {
+                    let _ = slots.next();
+                    formatted.push(c);
+                    length += 1;
+                    break;
+                }
+
+                if let Slot::Literal(literal) = slot {
+                    let _ = slots.next();
+                    formatted.push(literal);
+                    length += 1;
+                } else if is_literal {
+                    break;
+                } else {
+                    return None;
+                }
+            }
+
+            if i + 1 == cursor {
+                position = length;
+            }
+        }
+
+        Some((formatted, position))
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_contents() {
+        let date = Mask::new("99/99/9999");
+
+        assert_eq!(date.format("12", 2), Some((String::from("12"), 2)));
+        assert_eq!(date.format("123", 3), Some((String::from("12/3"), 4)));
+        assert_eq!(
+            date.format("12/4/5678", 4),
+            Some((String::from("12/45/678"), 4))
+        );
+        assert_eq!(date.format("12/a", 4), None);
+        assert_eq!(date.format("12/34/56789", 11), None);
+
+        let color = Mask::new("#HHHHHH");
+
+        assert_eq!(color.format("ff", 2), Some((String::from("#ff"), 3)));
+        assert_eq!(color.format("#fg", 3), None);
+    }
+}
@@ -0,0 +1,793 @@
+//! Time pickers let users select a time of the day from a popup.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::time_picker;
+//! use iced::widget::time_picker::Time;
+//!
+//! struct State {
+//!    alarm: Option<Time>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     AlarmSelected(Time),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     time_picker(state.alarm, Message::AlarmSelected)
+//!         .placeholder("Set an alarm...")
+//!         .step(15)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::AlarmSelected(time) => {
+//!             state.alarm = Some(time);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::accessibility;
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::date_picker::{draw_field, layout_field, position_below};
+
+pub use crate::date_picker::{default, Catalog, Status, Style, StyleFn};
+
+use std::fmt;
+
+/// A field that opens a popup to select a [`Time`] of the day.
+///
+/// The popup displays a grid of hours and a grid of minutes. Picking an
+/// hour and then a minute selects a [`Time`].
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    selected: Option<Time>,
+    on_select: Box<dyn Fn(Time) -> Message + 'a>,
+    placeholder: Option<String>,
+    min: Option<Time>,
+    max: Option<Time>,
+    step: u8,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default amount of minutes between the selectable minutes.
+    pub const DEFAULT_STEP: u8 = 5;
+
+    /// Creates a new [`TimePicker`] with the given selected [`Time`], if any,
+    /// and the message to produce when a [`Time`] is selected.
+    pub fn new(
+        selected: Option<Time>,
+        on_select: impl Fn(Time) -> Message + 'a,
+    ) -> Self {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: None,
+            min: None,
+            max: None,
+            step: Self::DEFAULT_STEP,
+            width: Length::Shrink,
+            padding: crate::button::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the placeholder of the [`TimePicker`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the earliest [`Time`] that can be selected.
+    pub fn min(mut self, min: Time) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Time`] that can be selected.
+    pub fn max(mut self, max: Time) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount of minutes between the selectable minutes.
+    ///
+    /// By default, it is [`Self::DEFAULT_STEP`].
+    pub fn step(mut self, minutes: u8) -> Self {
+        self.step = minutes.clamp(1, 60);
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TimePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_enabled(&self, time: Time) -> bool {
+        let is_before_min = self.min.is_some_and(|min| time < min);
+        let is_after_max = self.max.is_some_and(|max| time > max);
+
+        !is_before_min && !is_after_max
+    }
+
+    fn is_hour_enabled(&self, hour: u8) -> bool {
+        let is_before_min = self.min.is_some_and(|min| hour < min.hour);
+        let is_after_max = self.max.is_some_and(|max| hour > max.hour);
+
+        !is_before_min && !is_after_max
+    }
+
+    fn minutes(&self) -> impl Iterator<Item = u8> {
+        (0..60).step_by(usize::from(self.step))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout_field(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+            [Some("00:00"), self.placeholder.as_deref()],
+        )
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut node = accessibility::Node::new(accessibility::Role::ComboBox);
+
+        if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder);
+        }
+
+        if let Some(selected) = self.selected {
+            node = node.value(accessibility::Value::Text(selected.to_string()));
+        }
+
+        operation.accessible(None, layout.bounds(), &node);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_open {
+                    // The popup did not capture the click, so it happened
+                    // outside of it
+                    state.is_open = false;
+
+                    event::Status::Captured
+                } else if cursor.is_over(layout.bounds()) {
+                    let time = self
+                        .selected
+                        .or(self.min)
+                        .unwrap_or(Time { hour: 0, minute: 0 });
+
+                    *state = State {
+                        is_open: true,
+                        pending: time,
+                        column: Column::Hour,
+                    };
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        draw_field(
+            renderer,
+            &theme.style(&self.class, status),
+            bounds,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.selected.map(|time| time.to_string()).as_deref(),
+            self.placeholder.as_deref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Popup {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_open: bool,
+    pending: Time,
+    column: Column,
+}
+
+/// The grid of a [`Popup`] focused by the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Column {
+    #[default]
+    Hour,
+    Minute,
+}
+
+struct Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a TimePicker<'b, Message, Theme, Renderer>,
+    state: &'a mut State,
+    field: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    const MARGIN: f32 = 8.0;
+    const COLUMNS: usize = 6;
+
+    /// Returns the [`Time`] selected by the cell at the given index, which
+    /// counts the hours first and then the minutes.
+    fn time(&self, index: usize) -> Time {
+        if index < 24 {
+            Time {
+                hour: index as u8,
+                ..self.state.pending
+            }
+        } else {
+            Time {
+                minute: (index - 24) as u8 * self.picker.step,
+                ..self.state.pending
+            }
+        }
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        if index < 24 {
+            self.picker.is_hour_enabled(index as u8)
+        } else {
+            self.picker.is_enabled(self.time(index))
+        }
+    }
+
+    fn is_pending(&self, index: usize) -> bool {
+        let time = self.time(index);
+
+        if index < 24 {
+            time.hour == self.state.pending.hour
+        } else {
+            time.minute == self.state.pending.minute
+        }
+    }
+
+    fn select(&mut self, time: Time, shell: &mut Shell<'_, Message>) {
+        if self.picker.is_enabled(time) {
+            shell.publish((self.picker.on_select)(time));
+            self.state.is_open = false;
+        }
+    }
+
+    fn on_key(
+        &mut self,
+        key: key::Named,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let pending = self.state.pending;
+        let step = self.picker.step;
+
+        match key {
+            key::Named::ArrowLeft => self.state.column = Column::Hour,
+            key::Named::ArrowRight => self.state.column = Column::Minute,
+            key::Named::ArrowUp | key::Named::ArrowDown => {
+                let is_up = key == key::Named::ArrowUp;
+
+                self.state.pending = match self.state.column {
+                    Column::Hour => Time {
+                        hour: if is_up {
+                            pending.hour.saturating_sub(1)
+                        } else {
+                            (pending.hour + 1).min(23)
+                        },
+                        ..pending
+                    },
+                    Column::Minute => {
+                        let aligned = pending.minute - pending.minute % step;
+
+                        Time {
+                            minute: if is_up {
+                                if aligned < pending.minute {
+                                    aligned
+                                } else {
+                                    aligned.saturating_sub(step)
+                                }
+                            } else {
+                                (aligned + step).min(
+                                    self.picker.minutes().last().unwrap_or(0),
+                                )
+                            },
+                            ..pending
+                        }
+                    }
+                };
+            }
+            key::Named::Enter | key::Named::Space => {
+                self.select(pending, shell);
+            }
+            key::Named::Escape => self.state.is_open = false,
+            _ => return event::Status::Ignored,
+        }
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Popup<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let cell =
+            Size::new((text_size.0 * 2.5).round(), (text_size.0 * 2.0).round());
+
+        let grid = |count: usize, x: f32| {
+            (0..count).map(move |i| {
+                layout::Node::new(cell).move_to((
+                    x + (i % Self::COLUMNS) as f32 * cell.width,
+                    Self::MARGIN + (i / Self::COLUMNS) as f32 * cell.height,
+                ))
+            })
+        };
+
+        let minutes = self.picker.minutes().count();
+        let hours_width = cell.width * Self::COLUMNS as f32;
+        let minutes_x = Self::MARGIN * 3.0 + hours_width;
+
+        let size = Size::new(
+            minutes_x
+                + cell.width * minutes.min(Self::COLUMNS) as f32
+                + Self::MARGIN,
+            Self::MARGIN * 2.0
+                + cell.height
+                    * 4.0f32.max(minutes.div_ceil(Self::COLUMNS) as f32),
+        );
+
+        layout::Node::with_children(
+            size,
+            grid(24, Self::MARGIN)
+                .chain(grid(minutes, minutes_x))
+                .collect(),
+        )
+        .move_to(position_below(self.field, size, bounds))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        for (i, layout) in layout.children().enumerate() {
+            let time = self.time(i);
+
+            let label = if i < 24 {
+                format!("{:02}", time.hour)
+            } else {
+                format!(":{:02}", time.minute)
+            };
+
+            operation.accessible(
+                None,
+                layout.bounds(),
+                &accessibility::Node::new(accessibility::Role::Button)
+                    .label(label)
+                    .disabled(!self.is_enabled(i)),
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(layout.bounds()) {
+                    return event::Status::Ignored;
+                }
+
+                let hovered = layout
+                    .children()
+                    .position(|child| cursor.is_over(child.bounds()));
+
+                match hovered {
+                    Some(i) if !self.is_enabled(i) => {}
+                    Some(i) if i < 24 => {
+                        self.state.pending = self.time(i);
+                        self.state.column = Column::Minute;
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    Some(i) => {
+                        let time = self.time(i);
+
+                        self.state.pending = time;
+                        self.select(time, shell);
+                    }
+                    None => {}
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => self.on_key(key, shell),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_enabled =
+            layout.children().enumerate().any(|(i, child)| {
+                cursor.is_over(child.bounds()) && self.is_enabled(i)
+            });
+
+        if is_over_enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.picker.class, Status::Opened);
+        let bounds = layout.bounds();
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.with_layer(
+            bounds.expand(style.calendar_shadow.blur_radius),
+            |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.calendar_border,
+                        shadow: style.calendar_shadow,
+                    },
+                    style.calendar_background,
+                );
+
+                for (i, layout) in layout.children().enumerate() {
+                    let bounds = layout.bounds();
+                    let time = self.time(i);
+                    let is_enabled = self.is_enabled(i);
+                    let is_pending = self.is_pending(i);
+
+                    let column =
+                        if i < 24 { Column::Hour } else { Column::Minute };
+
+                    let background = if is_pending {
+                        Some(style.selected_background)
+                    } else if is_enabled && cursor.is_over(bounds) {
+                        Some(style.hovered_background)
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: bounds.shrink(1.0),
+                                border: if column == self.state.column {
+                                    Border {
+                                        color: style.calendar_text_color,
+                                        width: 1.0,
+                                        radius: style.border.radius,
+                                    }
+                                } else {
+                                    border::rounded(style.border.radius)
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    let content = if i < 24 {
+                        format!("{:02}", time.hour)
+                    } else {
+                        format!(":{:02}", time.minute)
+                    };
+
+                    renderer.fill_text(
+                        Text {
+                            content,
+                            bounds: bounds.size(),
+                            size: text_size,
+                            line_height: text::LineHeight::default(),
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                            wrapping: text::Wrapping::None,
+                        },
+                        bounds.center(),
+                        if !is_enabled {
+                            style.disabled_text_color
+                        } else if is_pending {
+                            style.selected_text_color
+                        } else {
+                            style.calendar_text_color
+                        },
+                        bounds,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// A time of the day, with minute precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Creates a new [`Time`] from its hour (from 0 to 23) and minute (from
+    /// 0 to 59).
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn from_hm(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
@@ -31,14 +31,17 @@
 //! }
 //! ```
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolate, Spring};
 use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
@@ -243,6 +246,33 @@ where
     }
 }
 
+struct State<P: text::Paragraph> {
+    text: widget::text::State<P>,
+    status: Option<Animation<Status>>,
+    toggle: Animation<bool>,
+    now: Instant,
+}
+
+impl<'a, Message, Theme, Renderer> Toggler<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: Catalog,
+{
+    fn status(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Status {
+        if self.on_toggle.is_none() {
+            Status::Disabled
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered {
+                is_toggled: self.is_toggled,
+            }
+        } else {
+            Status::Active {
+                is_toggled: self.is_toggled,
+            }
+        }
+    }
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Toggler<'a, Message, Theme, Renderer>
 where
@@ -250,11 +280,16 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            text: widget::text::State::default(),
+            status: None,
+            toggle: Animation::new(self.is_toggled).spring(Spring::DEFAULT),
+            now: Instant::now(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -278,12 +313,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.text,
                         renderer,
                         limits,
                         self.width,
@@ -306,7 +340,7 @@ where
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -315,6 +349,30 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            let status = self.status(layout, cursor);
+
+            state.now = now;
+            state.toggle.go_mut(self.is_toggled, now);
+
+            let animation =
+                state.status.get_or_insert_with(|| Animation::new(status));
+
+            animation.go_mut(status, now);
+
+            if let Some(request) = animation
+                .redraw_request(now)
+                .into_iter()
+                .chain(state.toggle.redraw_request(now))
+                .min()
+            {
+                shell.request_redraw(request);
+            }
+
+            return event::Status::Ignored;
+        }
+
         let Some(on_toggle) = &self.on_toggle else {
             return event::Status::Ignored;
         };
@@ -375,38 +433,33 @@ where
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         if self.label.is_some() {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 style,
                 label_layout,
-                state.0.raw(),
+                state.text.0.raw(),
                 crate::text::Style::default(),
                 viewport,
             );
         }
 
         let bounds = toggler_layout.bounds();
-        let is_mouse_over = cursor.is_over(layout.bounds());
-
-        let status = if self.on_toggle.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            Status::Hovered {
-                is_toggled: self.is_toggled,
-            }
-        } else {
-            Status::Active {
-                is_toggled: self.is_toggled,
-            }
+        let status = self.status(layout, cursor);
+
+        let style = match &state.status {
+            Some(animation) if *animation.target() == status => animation
+                .interpolate_with(
+                    |status| theme.style(&self.class, *status),
+                    state.now,
+                ),
+            _ => theme.style(&self.class, status),
         };
 
-        let style = theme.style(&self.class, status);
-
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height;
 
@@ -430,12 +483,20 @@ where
             style.background,
         );
 
+        let foreground_offset = |is_toggled: &bool| {
+            if *is_toggled {
+                bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
+            } else {
+                2.0 * space
+            }
+        };
+
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
+                + if *state.toggle.target() == self.is_toggled {
+                    state.toggle.interpolate_with(foreground_offset, state.now)
                 } else {
-                    2.0 * space
+                    foreground_offset(&self.is_toggled)
                 },
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
@@ -505,6 +566,27 @@ pub struct Style {
     pub foreground_border_color: Color,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Style {
+            background: self.background.interpolate(&other.background, amount),
+            background_border_width: self
+                .background_border_width
+                .interpolate(&other.background_border_width, amount),
+            background_border_color: self
+                .background_border_color
+                .interpolate(&other.background_border_color, amount),
+            foreground: self.foreground.interpolate(&other.foreground, amount),
+            foreground_border_width: self
+                .foreground_border_width
+                .interpolate(&other.foreground_border_width, amount),
+            foreground_border_color: self
+                .foreground_border_color
+                .interpolate(&other.foreground_border_color, amount),
+        }
+    }
+}
+
 /// The theme catalog of a [`Toggler`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -28,12 +28,16 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
     Vector,
 };
 
+use std::cell::Cell;
+
 /// An element to display a widget over another.
 ///
 /// # Example
@@ -57,6 +61,19 @@ use crate::core::{
 ///     ).into()
 /// }
 /// ```
+///
+/// # Delays
+/// By default, a [`Tooltip`] is shown as soon as its content is hovered and
+/// hidden as soon as the cursor leaves it. A [`delay`] and a [`hide_delay`]
+/// can be set to avoid flickering when moving the cursor across many
+/// tooltips.
+///
+/// Once a tooltip has been shown, any other tooltip hovered within its
+/// [`warm_up`] period is shown immediately.
+///
+/// [`delay`]: Self::delay
+/// [`hide_delay`]: Self::hide_delay
+/// [`warm_up`]: Self::warm_up
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<
     'a,
@@ -73,6 +90,9 @@ pub struct Tooltip<
     gap: f32,
     padding: f32,
     snap_within_viewport: bool,
+    timing: Timing,
+    is_interactive: bool,
+    visibility: Option<bool>,
     class: Theme::Class<'a>,
 }
 
@@ -99,6 +119,9 @@ where
             gap: 0.0,
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
+            timing: Timing::default(),
+            is_interactive: false,
+            visibility: None,
             class: Theme::default(),
         }
     }
@@ -121,6 +144,49 @@ where
         self
     }
 
+    /// Sets the time the cursor must rest over the content before the
+    /// [`Tooltip`] is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.timing.delay = delay;
+        self
+    }
+
+    /// Sets the time the [`Tooltip`] stays visible after the cursor leaves
+    /// the content.
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.timing.hide_delay = hide_delay;
+        self
+    }
+
+    /// Sets the time after any tooltip was last visible during which the
+    /// [`Tooltip`] is shown without its [`delay`](Self::delay).
+    ///
+    /// By default, this is 500 milliseconds.
+    pub fn warm_up(mut self, warm_up: Duration) -> Self {
+        self.timing.warm_up = warm_up;
+        self
+    }
+
+    /// Sets whether the cursor can move into the [`Tooltip`] and interact
+    /// with its contents; like clicking a link.
+    ///
+    /// An interactive [`Tooltip`] stays visible while the cursor is over it.
+    /// Consider setting a [`hide_delay`](Self::hide_delay) if there is a
+    /// [`gap`](Self::gap) between the content and the [`Tooltip`].
+    pub fn interactive(mut self, is_interactive: bool) -> Self {
+        self.is_interactive = is_interactive;
+        self
+    }
+
+    /// Sets the visibility of the [`Tooltip`] programmatically.
+    ///
+    /// If `None`, the visibility is driven by hovering the content;
+    /// which is the default.
+    pub fn visible(mut self, is_visible: impl Into<Option<bool>>) -> Self {
+        self.visibility = is_visible.into();
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     #[must_use]
     pub fn style(
@@ -190,6 +256,21 @@ where
             .layout(&mut tree.children[0], renderer, limits)
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
@@ -203,16 +284,34 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
 
-        let was_idle = *state == State::Idle;
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => now,
+            _ => Instant::now(),
+        };
 
-        *state = cursor
-            .position_over(layout.bounds())
-            .map(|cursor_position| State::Hovered { cursor_position })
-            .unwrap_or_default();
+        let hovered = cursor.position_over(layout.bounds());
 
-        let is_idle = *state == State::Idle;
+        if let Some(cursor_position) = hovered {
+            state.cursor_position = cursor_position;
+        }
+
+        let was_visible = state.is_visible;
+
+        if self.visibility.is_none() {
+            let is_hovered = hovered.is_some() || state.is_over_tooltip;
+
+            if let Some(deadline) = state.update(is_hovered, now, self.timing) {
+                shell.request_redraw(window::RedrawRequest::At(deadline));
+            }
+        } else {
+            state.deadline = None;
+        }
+
+        if !state.is_visible {
+            state.is_over_tooltip = false;
+        }
 
-        if was_idle != is_idle {
+        if was_visible != state.is_visible {
             shell.invalidate_layout();
         }
 
@@ -273,7 +372,7 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_mut::<State>();
 
         let mut children = tree.children.iter_mut();
 
@@ -284,12 +383,16 @@ where
             translation,
         );
 
-        let tooltip = if let State::Hovered { cursor_position } = *state {
+        let is_visible = self.visibility.unwrap_or(state.is_visible);
+
+        let tooltip = if is_visible {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
-                tooltip: &self.tooltip,
+                tooltip: &mut self.tooltip,
                 state: children.next().unwrap(),
-                cursor_position,
+                cursor_position: state.cursor_position,
+                is_interactive: self.is_interactive,
+                is_over_tooltip: &mut state.is_over_tooltip,
                 content_bounds: layout.bounds(),
                 snap_within_viewport: self.snap_within_viewport,
                 positioning: self.position,
@@ -344,13 +447,76 @@ pub enum Position {
     FollowCursor,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timing {
+    delay: Duration,
+    hide_delay: Duration,
+    warm_up: Duration,
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Self {
+            delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            warm_up: Duration::from_millis(500),
+        }
+    }
+}
+
+thread_local! {
+    /// The last time any [`Tooltip`] was visible.
+    static LAST_VISIBLE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-enum State {
-    #[default]
-    Idle,
-    Hovered {
-        cursor_position: Point,
-    },
+struct State {
+    cursor_position: Point,
+    is_visible: bool,
+    is_over_tooltip: bool,
+    deadline: Option<Instant>,
+}
+
+impl State {
+    /// Moves the visibility of the [`Tooltip`] towards the hover status,
+    /// returning the time when it must be updated again, if any.
+    fn update(
+        &mut self,
+        is_hovered: bool,
+        now: Instant,
+        timing: Timing,
+    ) -> Option<Instant> {
+        let was_visible = self.is_visible;
+
+        if is_hovered == self.is_visible {
+            self.deadline = None;
+        } else {
+            let is_warm = is_hovered
+                && LAST_VISIBLE.get().is_some_and(|last_visible| {
+                    now.saturating_duration_since(last_visible)
+                        <= timing.warm_up
+                });
+
+            let delay = if is_hovered {
+                timing.delay
+            } else {
+                timing.hide_delay
+            };
+
+            let deadline = *self.deadline.get_or_insert(now + delay);
+
+            if is_warm || now >= deadline {
+                self.is_visible = is_hovered;
+                self.deadline = None;
+            }
+        }
+
+        if was_visible || self.is_visible {
+            LAST_VISIBLE.set(Some(now));
+        }
+
+        self.deadline
+    }
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -359,9 +525,11 @@ where
     Renderer: text::Renderer,
 {
     position: Point,
-    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    tooltip: &'b mut Element<'a, Message, Theme, Renderer>,
     state: &'b mut widget::Tree,
     cursor_position: Point,
+    is_interactive: bool,
+    is_over_tooltip: &'b mut bool,
     content_bounds: Rectangle,
     snap_within_viewport: bool,
     positioning: Position,
@@ -475,6 +643,71 @@ where
         .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y))
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if !self.is_interactive {
+            return;
+        }
+
+        self.tooltip.as_widget().operate(
+            self.state,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_interactive {
+            return event::Status::Ignored;
+        }
+
+        *self.is_over_tooltip = cursor.is_over(layout.bounds());
+
+        self.tooltip.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_interactive {
+            return mouse::Interaction::None;
+        }
+
+        self.tooltip.as_widget().mouse_interaction(
+            self.state,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -504,10 +737,51 @@ where
 
     fn is_over(
         &self,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
-        _cursor_position: Point,
+        cursor_position: Point,
     ) -> bool {
-        false
+        self.is_interactive && layout.bounds().contains(cursor_position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_visibility() {
+        let timing = Timing {
+            delay: Duration::from_millis(500),
+            hide_delay: Duration::from_millis(100),
+            warm_up: Duration::from_millis(300),
+        };
+
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let mut first = State::default();
+        let mut second = State::default();
+
+        assert_eq!(first.update(true, at(0), timing), Some(at(500)));
+        assert!(!first.is_visible);
+
+        assert_eq!(first.update(true, at(500), timing), None);
+        assert!(first.is_visible);
+
+        assert_eq!(first.update(false, at(600), timing), Some(at(700)));
+        assert!(first.is_visible);
+
+        assert_eq!(first.update(false, at(700), timing), None);
+        assert!(!first.is_visible);
+
+        assert_eq!(second.update(true, at(900), timing), None);
+        assert!(second.is_visible);
+
+        assert_eq!(second.update(false, at(900), timing), Some(at(1000)));
+        assert_eq!(second.update(false, at(1000), timing), None);
+
+        assert_eq!(first.update(true, at(1400), timing), Some(at(1900)));
+        assert!(!first.is_visible);
     }
 }
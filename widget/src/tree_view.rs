@@ -0,0 +1,1211 @@
+//! Tree views display hierarchical data that can be expanded and collapsed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{text, tree_view};
+//! use iced::widget::tree_view::Node;
+//!
+//! struct File {
+//!     path: String,
+//!     children: Vec<File>,
+//!     is_expanded: bool,
+//!     is_selected: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggled(String, bool),
+//!     Selected(Vec<String>),
+//! }
+//!
+//! fn view(files: &[File]) -> Element<'_, Message> {
+//!     fn node(file: &File) -> Node<'_, String, Message> {
+//!         Node::new(file.path.clone(), text(&file.path))
+//!             .children(file.children.iter().map(node))
+//!             .expanded(file.is_expanded)
+//!             .selected(file.is_selected)
+//!     }
+//!
+//!     tree_view(files.iter().map(node))
+//!         .multiple(true)
+//!         .on_toggle(Message::Toggled)
+//!         .on_select(Message::Selected)
+//!         .into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
+
+/// A vertical list of nested nodes that can be expanded, collapsed, and
+/// selected.
+///
+/// The expansion and selection of every [`Node`] is owned by your
+/// application: a [`TreeView`] only produces messages when the user wants
+/// to change them. Since the children of a collapsed node are never shown,
+/// they can be loaded lazily once the node is expanded. See
+/// [`Node::expandable`].
+///
+/// A focused [`TreeView`] can be navigated with the keyboard:
+///
+/// - <kbd>↑</kbd> / <kbd>↓</kbd> / <kbd>Home</kbd> / <kbd>End</kbd> move the
+///   selection. Hold <kbd>Shift</kbd> to extend it or <kbd>Ctrl</kbd> to
+///   only move the cursor.
+/// - <kbd>→</kbd> expands the node under the cursor or moves to its first
+///   child.
+/// - <kbd>←</kbd> collapses the node under the cursor or moves to its
+///   parent.
+/// - <kbd>Space</kbd> selects the node under the cursor. Hold <kbd>Ctrl</kbd>
+///   to toggle it instead.
+/// - <kbd>Enter</kbd> expands or collapses the node under the cursor.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// struct File {
+///     path: String,
+///     children: Vec<File>,
+///     is_expanded: bool,
+///     is_selected: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(String, bool),
+///     Selected(Vec<String>),
+/// }
+///
+/// fn view(files: &[File]) -> Element<'_, Message> {
+///     fn node(file: &File) -> Node<'_, String, Message> {
+///         Node::new(file.path.clone(), text(&file.path))
+///             .children(file.children.iter().map(node))
+///             .expanded(file.is_expanded)
+///             .selected(file.is_selected)
+///     }
+///
+///     tree_view(files.iter().map(node))
+///         .multiple(true)
+///         .on_toggle(Message::Toggled)
+///         .on_select(Message::Selected)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    id: Option<Id>,
+    rows: Vec<Row<Key>>,
+    contents: Vec<Element<'a, Message, Theme, Renderer>>,
+    hidden_selection: Vec<Key>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    is_multiple: bool,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<Key>) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+/// A visible row of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+struct Row<Key> {
+    key: Key,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: Catalog,
+{
+    /// The default indentation of each level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 16.0;
+
+    /// The default [`Padding`] of the rows of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        bottom: 2.0,
+        right: 4.0,
+        left: 4.0,
+    };
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(
+        nodes: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut rows = Vec::new();
+        let mut contents = Vec::new();
+        let mut hidden_selection = Vec::new();
+
+        for node in nodes {
+            node.flatten(
+                0,
+                None,
+                &mut rows,
+                &mut contents,
+                &mut hidden_selection,
+            );
+        }
+
+        Self {
+            id: None,
+            rows,
+            contents,
+            hidden_selection,
+            width: Length::Fill,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            is_multiple: false,
+            on_toggle: None,
+            on_select: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`].
+    ///
+    /// The expander of a [`Node`] is drawn inside the indentation that
+    /// precedes its content.
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of every row of the [`TreeView`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether multiple nodes of the [`TreeView`] can be selected at
+    /// once, using <kbd>Shift</kbd> and <kbd>Ctrl</kbd>.
+    pub fn multiple(mut self, is_multiple: bool) -> Self {
+        self.is_multiple = is_multiple;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is expanded
+    /// or collapsed.
+    ///
+    /// The closure receives the key of the [`Node`] and whether it should be
+    /// expanded.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(Key, bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that should be produced when the selection of the
+    /// [`TreeView`] changes.
+    ///
+    /// The closure receives the keys of all the selected nodes, including
+    /// the ones hidden inside collapsed nodes.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Vec<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeView`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn position(&self, key: Option<&Key>) -> Option<usize> {
+        let key = key?;
+
+        self.rows.iter().position(|row| row.key == *key)
+    }
+
+    fn selection(&self) -> Vec<Key> {
+        self.rows
+            .iter()
+            .filter(|row| row.is_selected)
+            .map(|row| row.key.clone())
+            .chain(self.hidden_selection.iter().cloned())
+            .collect()
+    }
+
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        let row = &self.rows[index];
+
+        if let Some(on_toggle) = &self.on_toggle {
+            if row.is_expandable {
+                shell.publish(on_toggle(row.key.clone(), !row.is_expanded));
+            }
+        }
+    }
+
+    /// Selects the row at the given index, following the behavior of the
+    /// given [`keyboard::Modifiers`].
+    fn select(
+        &self,
+        state: &mut State<Key>,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let key = self.rows[index].key.clone();
+        let current = self.selection();
+
+        let selection = if self.is_multiple && modifiers.command() {
+            state.anchor = Some(key.clone());
+
+            if self.rows[index].is_selected {
+                current
+                    .iter()
+                    .filter(|selected| **selected != key)
+                    .cloned()
+                    .collect()
+            } else {
+                current.iter().cloned().chain(Some(key.clone())).collect()
+            }
+        } else if let Some(anchor) = self
+            .position(state.anchor.as_ref())
+            .filter(|_| self.is_multiple && modifiers.shift())
+        {
+            let range = anchor.min(index)..=anchor.max(index);
+
+            self.rows[range].iter().map(|row| row.key.clone()).collect()
+        } else {
+            state.anchor = Some(key.clone());
+
+            vec![key.clone()]
+        };
+
+        state.cursor = Some(key);
+
+        if selection != current {
+            if let Some(on_select) = &self.on_select {
+                shell.publish(on_select(selection));
+            }
+        }
+    }
+
+    /// Moves the cursor to the row at the given index.
+    fn move_to(
+        &self,
+        state: &mut State<Key>,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if modifiers.command() && self.is_multiple {
+            state.cursor = Some(self.rows[index].key.clone());
+        } else {
+            self.select(
+                state,
+                index,
+                keyboard::Modifiers::SHIFT & modifiers,
+                shell,
+            );
+        }
+    }
+
+    fn expander(&self, row: &Row<Key>, bounds: Rectangle) -> Rectangle {
+        let size = self.indent.min(bounds.height);
+
+        Rectangle {
+            x: bounds.x
+                + self.padding.left
+                + self.indent * row.depth as f32
+                + (self.indent - size) / 2.0,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    key: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+    children: Vec<Node<'a, Key, Message, Theme, Renderer>>,
+    is_expandable: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Node<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone,
+{
+    /// Creates a new [`Node`] with the given key and content.
+    ///
+    /// The key identifies the [`Node`] in the messages of a [`TreeView`] and
+    /// keeps the widget state of its content when other nodes are expanded
+    /// or collapsed.
+    pub fn new(
+        key: Key,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            key,
+            content: content.into(),
+            children: Vec::new(),
+            is_expandable: false,
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    /// Sets the children of the [`Node`].
+    ///
+    /// A [`Node`] with children can always be expanded.
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = Self>,
+    ) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded, even if it has no
+    /// children.
+    ///
+    /// This can be used to load the children of a [`Node`] lazily, once
+    /// the [`TreeView`] asks for it to be expanded.
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] is selected.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+
+    /// Flattens the visible nodes of the tree into rows.
+    fn flatten(
+        self,
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<Row<Key>>,
+        contents: &mut Vec<Element<'a, Message, Theme, Renderer>>,
+        hidden_selection: &mut Vec<Key>,
+    ) {
+        let index = rows.len();
+        let is_expandable = self.is_expandable || !self.children.is_empty();
+        let is_expanded = is_expandable && self.is_expanded;
+
+        rows.push(Row {
+            key: self.key,
+            depth,
+            parent,
+            is_expandable,
+            is_expanded,
+            is_selected: self.is_selected,
+        });
+        contents.push(self.content);
+
+        for child in self.children {
+            if is_expanded {
+                child.flatten(
+                    depth + 1,
+                    Some(index),
+                    rows,
+                    contents,
+                    hidden_selection,
+                );
+            } else {
+                child.hide(hidden_selection);
+            }
+        }
+    }
+
+    /// Collects the selected keys of a hidden subtree.
+    fn hide(self, hidden_selection: &mut Vec<Key>) {
+        if self.is_selected {
+            hidden_selection.push(self.key);
+        }
+
+        for child in self.children {
+            child.hide(hidden_selection);
+        }
+    }
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug)]
+struct State<Key> {
+    keys: Vec<Key>,
+    is_focused: bool,
+    cursor: Option<Key>,
+    anchor: Option<Key>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<Key> operation::Focusable for State<Key> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key> {
+            keys: self.rows.iter().map(|row| row.key.clone()).collect(),
+            is_focused: false,
+            cursor: None,
+            anchor: None,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.contents.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+
+        tree::diff_children_custom_with_search(
+            children,
+            &self.contents,
+            |tree, content| content.as_widget().diff(tree),
+            |index| {
+                self.rows
+                    .get(index)
+                    .or_else(|| self.rows.last())
+                    .map(|row| &row.key)
+                    != state.keys.get(index)
+            },
+            |content| Tree::new(content.as_widget()),
+        );
+
+        if !self.rows.iter().map(|row| &row.key).eq(state.keys.iter()) {
+            state.keys = self.rows.iter().map(|row| row.key.clone()).collect();
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let mut intrinsic_width: f32 = 0.0;
+
+        let contents: Vec<_> = self
+            .rows
+            .iter()
+            .zip(&self.contents)
+            .zip(&mut tree.children)
+            .map(|((row, content), tree)| {
+                let offset =
+                    self.padding.left + self.indent * (row.depth + 1) as f32;
+
+                let content_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (max_width - offset - self.padding.right).max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+
+                let node =
+                    content.as_widget().layout(tree, renderer, &content_limits);
+
+                intrinsic_width = intrinsic_width
+                    .max(offset + node.size().width + self.padding.right);
+
+                (offset, node)
+            })
+            .collect();
+
+        let height: f32 = contents
+            .iter()
+            .map(|(_, node)| node.size().height + self.padding.vertical())
+            .sum();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(intrinsic_width, height),
+        );
+
+        let mut y = 0.0;
+
+        let rows = contents
+            .into_iter()
+            .map(|(offset, node)| {
+                let row_height = node.size().height + self.padding.vertical();
+
+                let row = layout::Node::with_children(
+                    Size::new(size.width, row_height),
+                    vec![node.move_to((offset, self.padding.top))],
+                )
+                .move_to((0.0, y));
+
+                y += row_height;
+
+                row
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(size.width, height), rows)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+
+        operation.container(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &mut |operation| {
+                self.contents
+                    .iter()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                    .for_each(|((content, state), layout)| {
+                        content.as_widget().operate(
+                            state,
+                            layout.children().next().unwrap(),
+                            renderer,
+                            operation,
+                        );
+                    });
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self
+            .contents
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((content, state), layout)| {
+                content.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(index) = layout
+                    .children()
+                    .position(|row| cursor.is_over(row.bounds()))
+                else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let row = &self.rows[index];
+                let bounds = layout.children().nth(index).unwrap().bounds();
+
+                state.is_focused = true;
+
+                if row.is_expandable
+                    && cursor.is_over(self.expander(row, bounds))
+                {
+                    state.cursor = Some(row.key.clone());
+                    self.toggle(index, shell);
+                } else {
+                    self.select(state, index, state.modifiers, shell);
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) if state.is_focused && !self.rows.is_empty() => {
+                let current = self.position(state.cursor.as_ref());
+                let last = self.rows.len() - 1;
+
+                let keyboard::Key::Named(named) = key.as_ref() else {
+                    return event::Status::Ignored;
+                };
+
+                match named {
+                    key::Named::ArrowUp => {
+                        let index = current.map_or(0, |i| i.saturating_sub(1));
+
+                        self.move_to(state, index, modifiers, shell);
+                    }
+                    key::Named::ArrowDown => {
+                        let index = current.map_or(0, |i| (i + 1).min(last));
+
+                        self.move_to(state, index, modifiers, shell);
+                    }
+                    key::Named::Home => {
+                        self.move_to(state, 0, modifiers, shell);
+                    }
+                    key::Named::End => {
+                        self.move_to(state, last, modifiers, shell);
+                    }
+                    key::Named::ArrowRight => {
+                        let index = current.unwrap_or(0);
+                        let row = &self.rows[index];
+
+                        if row.is_expandable && !row.is_expanded {
+                            self.toggle(index, shell);
+                        } else if self
+                            .rows
+                            .get(index + 1)
+                            .is_some_and(|child| child.parent == Some(index))
+                        {
+                            self.move_to(state, index + 1, modifiers, shell);
+                        } else if current.is_none() {
+                            self.move_to(state, index, modifiers, shell);
+                        }
+                    }
+                    key::Named::ArrowLeft => {
+                        let index = current.unwrap_or(0);
+                        let row = &self.rows[index];
+
+                        if row.is_expanded {
+                            self.toggle(index, shell);
+                        } else if let Some(parent) = row.parent {
+                            self.move_to(state, parent, modifiers, shell);
+                        } else if current.is_none() {
+                            self.move_to(state, index, modifiers, shell);
+                        }
+                    }
+                    key::Named::Space => {
+                        let index = current.unwrap_or(0);
+
+                        self.select(
+                            state,
+                            index,
+                            keyboard::Modifiers::COMMAND & modifiers,
+                            shell,
+                        );
+                    }
+                    key::Named::Enter => {
+                        if let Some(index) = current {
+                            self.toggle(index, shell);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .contents
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((content, state), layout)| {
+                content.as_widget().mouse_interaction(
+                    state,
+                    layout.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::None {
+            return interaction;
+        }
+
+        let is_over_expander =
+            self.rows
+                .iter()
+                .zip(layout.children())
+                .any(|(row, layout)| {
+                    row.is_expandable
+                        && cursor.is_over(self.expander(row, layout.bounds()))
+                });
+
+        if is_over_expander {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let style = theme.style(&self.class);
+
+        for (((row, content), tree), layout) in self
+            .rows
+            .iter()
+            .zip(&self.contents)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let background = if row.is_selected {
+                Some(style.selected_background)
+            } else if cursor.is_over(bounds) {
+                style.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(style.focused_border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if state.is_focused && state.cursor.as_ref() == Some(&row.key) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.focused_border,
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            for level in 0..row.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (bounds.x
+                                + self.padding.left
+                                + self.indent * (level as f32 + 0.5))
+                                .floor(),
+                            y: bounds.y,
+                            width: 1.0,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.guide_color,
+                );
+            }
+
+            if row.is_expandable {
+                let expander = self.expander(row, bounds);
+                let size = (expander.width / 2.0).round();
+                let thickness = 1.5;
+
+                let center = Point::new(
+                    expander.center_x().round(),
+                    expander.center_y().round(),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - size / 2.0,
+                            y: center.y - thickness / 2.0,
+                            width: size,
+                            height: thickness,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.expander_color,
+                );
+
+                if !row.is_expanded {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x - thickness / 2.0,
+                                y: center.y - size / 2.0,
+                                width: thickness,
+                                height: size,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.expander_color,
+                    );
+                }
+            }
+
+            let text_color = if row.is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: text_color.unwrap_or(defaults.text_color),
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .contents
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((content, state), layout)| {
+                content.as_widget_mut().overlay(
+                    state,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<TreeView<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// The identifier of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+impl From<&'static str> for Id {
+    fn from(id: &'static str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for Id {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+/// Produces a [`Task`] that focuses the [`TreeView`] with the given [`Id`].
+pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus(id.into().0)))
+}
+
+/// The appearance of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of the nodes, if different from the default one.
+    pub text_color: Option<Color>,
+    /// The text [`Color`] of the selected nodes, if different from the
+    /// default one.
+    pub selected_text_color: Option<Color>,
+    /// The [`Background`] of the selected nodes.
+    pub selected_background: Background,
+    /// The [`Background`] of the hovered nodes, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Border`] of the node under the keyboard cursor, while focused.
+    pub focused_border: Border,
+    /// The [`Color`] of the indentation guides.
+    pub guide_color: Color,
+    /// The [`Color`] of the expanders.
+    pub expander_color: Color,
+}
+
+/// The theme catalog of a [`TreeView`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TreeView`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        text_color: None,
+        selected_text_color: Some(palette.primary.weak.text),
+        selected_background: palette.primary.weak.color.into(),
+        hovered_background: Some(palette.background.weak.color.into()),
+        focused_border: Border {
+            color: palette.primary.strong.color,
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+        guide_color: palette.background.strong.color,
+        expander_color: palette.background.strong.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Node = super::Node<'static, u32, (), crate::Theme, crate::Renderer>;
+
+    fn node(key: u32) -> Node {
+        Node::new(key, crate::Space::new(0, 0))
+    }
+
+    #[test]
+    fn flattens_visible_nodes() {
+        let tree_view = TreeView::new([
+            node(1)
+                .children([
+                    node(2).children([node(3).selected(true)]),
+                    node(4).selected(true),
+                ])
+                .expanded(true),
+            node(5).expandable(true).expanded(true),
+        ]);
+
+        let rows: Vec<_> = tree_view
+            .rows
+            .iter()
+            .map(|row| (row.key, row.depth, row.parent, row.is_expanded))
+            .collect();
+
+        assert_eq!(
+            rows,
+            [
+                (1, 0, None, true),
+                (2, 1, Some(0), false),
+                (4, 1, Some(0), false),
+                (5, 0, None, true),
+            ]
+        );
+
+        assert_eq!(tree_view.hidden_selection, [3]);
+        assert_eq!(tree_view.selection(), [4, 3]);
+    }
+}
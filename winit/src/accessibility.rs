@@ -0,0 +1,573 @@
+//! Expose the user interface to assistive technologies through [`accesskit`].
+use crate::core::accessibility::{self, Role, Tree, Value};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::window;
+use crate::runtime::{self, Action, UserInterface};
+use crate::Proxy;
+
+use std::sync::{Arc, Mutex};
+
+/// The [`accesskit`] adapter of a window.
+pub struct Adapter {
+    raw: platform::Adapter,
+    tree: Tree,
+}
+
+impl Adapter {
+    /// Creates a new [`Adapter`] for the given window.
+    ///
+    /// The window must not be visible yet.
+    pub fn new<Message>(
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        window: &winit::window::Window,
+        id: window::Id,
+        proxy: Proxy<Message>,
+    ) -> Self
+    where
+        Message: std::fmt::Debug + Send + 'static,
+    {
+        let raw = platform::Adapter::new(
+            event_loop,
+            window,
+            Activation {
+                id,
+                proxy: proxy.clone(),
+            },
+            Actions { id, proxy },
+            Deactivation,
+        );
+
+        Self {
+            raw,
+            tree: Tree::default(),
+        }
+    }
+
+    /// Returns the last accessibility [`Tree`] sent to the adapter.
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Processes a window event.
+    pub fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.raw.process_event(window, event);
+    }
+
+    /// Rebuilds the accessibility [`Tree`] of the given [`UserInterface`],
+    /// if an assistive technology is listening.
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        scale_factor: f64,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        let Self { raw, tree } = self;
+
+        raw.update_if_active(|| {
+            *tree = build(user_interface, renderer);
+
+            tree_update(tree, scale_factor)
+        });
+    }
+}
+
+impl std::fmt::Debug for Adapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Adapter")
+            .field("tree", &self.tree)
+            .finish_non_exhaustive()
+    }
+}
+
+fn build<Message, Theme, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+    renderer: &Renderer,
+) -> Tree
+where
+    Renderer: crate::core::Renderer,
+{
+    let tree = Arc::new(Mutex::new(Tree::default()));
+
+    let mut operation = operation::map(accessibility::tree(), {
+        let tree = tree.clone();
+
+        move |new_tree| {
+            *tree.lock().expect("Lock accessibility tree") = new_tree;
+        }
+    });
+
+    user_interface.operate(renderer, &mut operation);
+
+    let _ = operation.finish();
+
+    let tree = tree.lock().expect("Lock accessibility tree").clone();
+    tree
+}
+
+const ROOT: accesskit::NodeId = accesskit::NodeId(0);
+
+fn tree_update(tree: &Tree, scale_factor: f64) -> accesskit::TreeUpdate {
+    let mut root = accesskit::Node::new(accesskit::Role::Window);
+    root.set_children(
+        tree.roots()
+            .iter()
+            .map(|id| accesskit::NodeId(id.0))
+            .collect::<Vec<_>>(),
+    );
+
+    let nodes = std::iter::once((ROOT, root))
+        .chain(tree.iter().map(|entry| {
+            (accesskit::NodeId(entry.id.0), node(entry, scale_factor))
+        }))
+        .collect();
+
+    accesskit::TreeUpdate {
+        nodes,
+        tree: Some(accesskit::Tree::new(ROOT)),
+        focus: tree
+            .focus()
+            .map(|id| accesskit::NodeId(id.0))
+            .unwrap_or(ROOT),
+    }
+}
+
+fn node(entry: &accessibility::Entry, scale_factor: f64) -> accesskit::Node {
+    let accessibility::Node {
+        role,
+        label,
+        value,
+        toggled,
+        is_disabled,
+        ..
+    } = &entry.node;
+
+    let mut node = accesskit::Node::new(self::role(*role));
+
+    let bounds = entry.bounds;
+
+    node.set_bounds(accesskit::Rect {
+        x0: f64::from(bounds.x) * scale_factor,
+        y0: f64::from(bounds.y) * scale_factor,
+        x1: f64::from(bounds.x + bounds.width) * scale_factor,
+        y1: f64::from(bounds.y + bounds.height) * scale_factor,
+    });
+
+    node.set_children(
+        entry
+            .children
+            .iter()
+            .map(|id| accesskit::NodeId(id.0))
+            .collect::<Vec<_>>(),
+    );
+
+    if let Some(label) = label {
+        node.set_label(label.as_str());
+    }
+
+    match value {
+        Some(Value::Text(text)) => {
+            node.set_value(text.as_str());
+        }
+        Some(Value::Number {
+            value,
+            min,
+            max,
+            step,
+        }) => {
+            node.set_numeric_value(*value);
+            node.set_min_numeric_value(*min);
+            node.set_max_numeric_value(*max);
+            node.set_numeric_value_step(*step);
+        }
+        None => {}
+    }
+
+    if let Some(toggled) = toggled {
+        node.set_toggled(if *toggled {
+            accesskit::Toggled::True
+        } else {
+            accesskit::Toggled::False
+        });
+    }
+
+    if *is_disabled {
+        node.set_disabled();
+        return node;
+    }
+
+    match role {
+        Role::Button
+        | Role::CheckBox
+        | Role::Switch
+        | Role::RadioButton
+        | Role::ComboBox
+        | Role::MenuItem => {
+            node.add_action(accesskit::Action::Click);
+            node.add_action(accesskit::Action::Focus);
+        }
+        Role::TextInput | Role::MultilineTextInput => {
+            node.add_action(accesskit::Action::Focus);
+        }
+        Role::Slider => {
+            node.add_action(accesskit::Action::Increment);
+            node.add_action(accesskit::Action::Decrement);
+        }
+        Role::ScrollView => {
+            node.add_action(accesskit::Action::ScrollUp);
+            node.add_action(accesskit::Action::ScrollDown);
+            node.add_action(accesskit::Action::ScrollLeft);
+            node.add_action(accesskit::Action::ScrollRight);
+        }
+        Role::Group | Role::Label | Role::Image | Role::ProgressIndicator => {}
+    }
+
+    node
+}
+
+fn role(role: Role) -> accesskit::Role {
+    match role {
+        Role::Group => accesskit::Role::Group,
+        Role::Button => accesskit::Role::Button,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::Switch => accesskit::Role::Switch,
+        Role::RadioButton => accesskit::Role::RadioButton,
+        Role::Slider => accesskit::Role::Slider,
+        Role::TextInput => accesskit::Role::TextInput,
+        Role::MultilineTextInput => accesskit::Role::MultilineTextInput,
+        Role::ComboBox => accesskit::Role::ComboBox,
+        Role::ScrollView => accesskit::Role::ScrollView,
+        Role::Label => accesskit::Role::Label,
+        Role::Image => accesskit::Role::Image,
+        Role::ProgressIndicator => accesskit::Role::ProgressIndicator,
+        Role::MenuItem => accesskit::Role::MenuItem,
+    }
+}
+
+fn request(
+    request: accesskit::ActionRequest,
+) -> Option<accessibility::Request> {
+    let action = match request.action {
+        accesskit::Action::Click => accessibility::Action::Click,
+        accesskit::Action::Focus => accessibility::Action::Focus,
+        accesskit::Action::Increment => accessibility::Action::Increment,
+        accesskit::Action::Decrement => accessibility::Action::Decrement,
+        accesskit::Action::ScrollUp => {
+            accessibility::Action::ScrollBy(crate::core::Vector::new(0.0, 1.0))
+        }
+        accesskit::Action::ScrollDown => {
+            accessibility::Action::ScrollBy(crate::core::Vector::new(0.0, -1.0))
+        }
+        accesskit::Action::ScrollLeft => {
+            accessibility::Action::ScrollBy(crate::core::Vector::new(1.0, 0.0))
+        }
+        accesskit::Action::ScrollRight => {
+            accessibility::Action::ScrollBy(crate::core::Vector::new(-1.0, 0.0))
+        }
+        _ => return None,
+    };
+
+    Some(accessibility::Request {
+        target: accessibility::NodeId(request.target.0),
+        action,
+    })
+}
+
+struct Activation<Message: 'static> {
+    id: window::Id,
+    proxy: Proxy<Message>,
+}
+
+impl<Message> accesskit::ActivationHandler for Activation<Message>
+where
+    Message: std::fmt::Debug + Send,
+{
+    fn request_initial_tree(&mut self) -> Option<accesskit::TreeUpdate> {
+        // The tree is built on the next redraw
+        self.proxy.send_action(Action::Accessibility(
+            runtime::accessibility::Action::Activate(self.id),
+        ));
+
+        None
+    }
+}
+
+struct Actions<Message: 'static> {
+    id: window::Id,
+    proxy: Proxy<Message>,
+}
+
+impl<Message> accesskit::ActionHandler for Actions<Message>
+where
+    Message: std::fmt::Debug + Send,
+{
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        if let Some(request) = self::request(request) {
+            self.proxy.send_action(Action::Accessibility(
+                runtime::accessibility::Action::Perform(self.id, request),
+            ));
+        }
+    }
+}
+
+struct Deactivation;
+
+impl accesskit::DeactivationHandler for Deactivation {
+    fn deactivate_accessibility(&mut self) {}
+}
+
+/// The platform adapters of [`accesskit`], driven by our own [`winit`].
+///
+/// `accesskit_winit` depends on the published `winit` crate, which cannot
+/// share windows and events with the fork we use.
+#[cfg(target_os = "windows")]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use accesskit_windows::{SubclassingAdapter, HWND};
+    use winit::event::WindowEvent;
+    use winit::event_loop::ActiveEventLoop;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use winit::window::Window;
+
+    pub struct Adapter {
+        raw: SubclassingAdapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            _event_loop: &ActiveEventLoop,
+            window: &Window,
+            activation_handler: impl ActivationHandler + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + 'static,
+        ) -> Self {
+            let hwnd =
+                match window.window_handle().map(|handle| handle.as_raw()) {
+                    Ok(RawWindowHandle::Win32(handle)) => handle.hwnd.get(),
+                    _ => unreachable!("Windows must have a Win32 handle"),
+                };
+
+            Self {
+                raw: SubclassingAdapter::new(
+                    HWND(hwnd as _),
+                    activation_handler,
+                    action_handler,
+                ),
+            }
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent,
+        ) {
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            if let Some(events) = self.raw.update_if_active(update) {
+                events.raise();
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use accesskit_macos::SubclassingAdapter;
+    use winit::event::WindowEvent;
+    use winit::event_loop::ActiveEventLoop;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use winit::window::Window;
+
+    pub struct Adapter {
+        raw: SubclassingAdapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            _event_loop: &ActiveEventLoop,
+            window: &Window,
+            activation_handler: impl ActivationHandler + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + 'static,
+        ) -> Self {
+            let view = match window
+                .window_handle()
+                .map(|handle| handle.as_raw())
+            {
+                Ok(RawWindowHandle::AppKit(handle)) => handle.ns_view.as_ptr(),
+                _ => unreachable!("macOS windows must have an AppKit handle"),
+            };
+
+            // SAFETY: The view belongs to the window, which outlives the
+            // adapter stored next to it.
+            #[allow(unsafe_code)]
+            let raw = unsafe {
+                SubclassingAdapter::new(
+                    view,
+                    activation_handler,
+                    action_handler,
+                )
+            };
+
+            Self { raw }
+        }
+
+        pub fn process_event(&mut self, _window: &Window, event: &WindowEvent) {
+            if let WindowEvent::Focused(is_focused) = event {
+                if let Some(events) =
+                    self.raw.update_view_focus_state(*is_focused)
+                {
+                    events.raise();
+                }
+            }
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            if let Some(events) = self.raw.update_if_active(update) {
+                events.raise();
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, Rect, TreeUpdate,
+    };
+    use winit::event::WindowEvent;
+    use winit::event_loop::ActiveEventLoop;
+    use winit::window::Window;
+
+    pub struct Adapter {
+        raw: accesskit_unix::Adapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            _event_loop: &ActiveEventLoop,
+            _window: &Window,
+            activation_handler: impl ActivationHandler + Send + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            deactivation_handler: impl DeactivationHandler + Send + 'static,
+        ) -> Self {
+            Self {
+                raw: accesskit_unix::Adapter::new(
+                    activation_handler,
+                    action_handler,
+                    deactivation_handler,
+                ),
+            }
+        }
+
+        pub fn process_event(&mut self, window: &Window, event: &WindowEvent) {
+            match event {
+                WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                    let outer = window
+                        .outer_position()
+                        .map(|position| rect(position, window.outer_size()))
+                        .unwrap_or_default();
+
+                    let inner = window
+                        .inner_position()
+                        .map(|position| rect(position, window.inner_size()))
+                        .unwrap_or_default();
+
+                    self.raw.set_root_window_bounds(outer, inner);
+                }
+                WindowEvent::Focused(is_focused) => {
+                    self.raw.update_window_focus_state(*is_focused);
+                }
+                _ => {}
+            }
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            self.raw.update_if_active(update);
+        }
+    }
+
+    fn rect(
+        position: winit::dpi::PhysicalPosition<i32>,
+        size: winit::dpi::PhysicalSize<u32>,
+    ) -> Rect {
+        Rect {
+            x0: f64::from(position.x),
+            y0: f64::from(position.y),
+            x1: f64::from(position.x) + f64::from(size.width),
+            y1: f64::from(position.y) + f64::from(size.height),
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use winit::event::WindowEvent;
+    use winit::event_loop::ActiveEventLoop;
+    use winit::window::Window;
+
+    /// Assistive technologies are not supported in this platform.
+    pub struct Adapter;
+
+    impl Adapter {
+        pub fn new(
+            _event_loop: &ActiveEventLoop,
+            _window: &Window,
+            _activation_handler: impl ActivationHandler + 'static,
+            _action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + 'static,
+        ) -> Self {
+            Self
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent,
+        ) {
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            _update: impl FnOnce() -> TreeUpdate,
+        ) {
+        }
+    }
+}
@@ -1,11 +1,19 @@
 //! Access the clipboard.
 
-use crate::core::clipboard::Kind;
+use crate::core::clipboard::{Contents, Format, Image, Kind};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use winit::window::{Window, WindowId};
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// Contents with a custom MIME type are only shared within the
+/// application, and they are forgotten as soon as anything else is copied.
+/// Copying them leaves the system clipboard untouched, so other
+/// applications keep seeing its previous contents.
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
@@ -14,6 +22,8 @@ pub struct Clipboard {
 enum State {
     Connected {
         clipboard: window_clipboard::Clipboard,
+        rich: Option<rich::Clipboard>,
+        custom: Option<Custom>,
         // Held until drop to satisfy the safety invariants of
         // `window_clipboard::Clipboard`.
         //
@@ -24,6 +34,16 @@ enum State {
     Unavailable,
 }
 
+/// Contents with a custom MIME type copied within the application.
+struct Custom {
+    kind: Kind,
+    mime: String,
+    data: bytes::Bytes,
+    /// A hash of the contents of the system clipboard when copying, used
+    /// to notice when something else is copied.
+    fingerprint: u64,
+}
+
 impl Clipboard {
     /// Creates a new [`Clipboard`] for the given window.
     pub fn connect(window: Arc<Window>) -> Clipboard {
@@ -36,7 +56,12 @@ impl Clipboard {
             unsafe { window_clipboard::Clipboard::connect(&window) };
 
         let state = match clipboard {
-            Ok(clipboard) => State::Connected { clipboard, window },
+            Ok(clipboard) => State::Connected {
+                clipboard,
+                rich: rich::Clipboard::connect(),
+                custom: None,
+                window,
+            },
             Err(_) => State::Unavailable,
         };
 
@@ -65,7 +90,13 @@ impl Clipboard {
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, kind: Kind, contents: String) {
         match &mut self.state {
-            State::Connected { clipboard, .. } => {
+            State::Connected {
+                clipboard, custom, ..
+            } => {
+                if custom.as_ref().is_some_and(|custom| custom.kind == kind) {
+                    *custom = None;
+                }
+
                 let result = match kind {
                     Kind::Standard => clipboard.write(contents),
                     Kind::Primary => {
@@ -84,6 +115,103 @@ impl Clipboard {
         }
     }
 
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`Format`].
+    pub fn read_contents(
+        &self,
+        kind: Kind,
+        format: &Format,
+    ) -> Option<Contents> {
+        let State::Connected { rich, custom, .. } = &self.state else {
+            return None;
+        };
+
+        match (format, kind) {
+            (Format::Text, _) => self.read(kind).map(Contents::Text),
+            (Format::Custom(mime), _) => {
+                let custom = custom.as_ref()?;
+
+                let is_current = custom.kind == kind
+                    && &custom.mime == mime
+                    && self.fingerprint(kind) == custom.fingerprint;
+
+                is_current.then(|| Contents::Custom {
+                    mime: mime.clone(),
+                    data: custom.data.clone(),
+                })
+            }
+            (format, Kind::Standard) => rich.as_ref()?.read(format),
+            (_, Kind::Primary) => None,
+        }
+    }
+
+    /// Writes the given [`Contents`] to the [`Clipboard`].
+    pub fn write_contents(&mut self, kind: Kind, contents: Contents) {
+        let State::Connected { rich, custom, .. } = &mut self.state else {
+            return;
+        };
+
+        match (contents, kind) {
+            (Contents::Text(text), _) => self.write(kind, text),
+            (Contents::Custom { mime, data }, _) => {
+                // Custom contents cannot be shared with other applications,
+                // so the system clipboard is left alone and its contents are
+                // remembered to notice when something else is copied
+                let fingerprint = self.fingerprint(kind);
+
+                if let State::Connected { custom, .. } = &mut self.state {
+                    *custom = Some(Custom {
+                        kind,
+                        mime,
+                        data,
+                        fingerprint,
+                    });
+                }
+            }
+            (contents, Kind::Standard) => {
+                *custom = None;
+
+                let Some(rich) = rich else {
+                    log::warn!(
+                        "error writing to clipboard: {:?} is not supported",
+                        contents.format()
+                    );
+                    return;
+                };
+
+                if let Err(error) = rich.write(contents) {
+                    log::warn!("error writing to clipboard: {error}");
+                }
+            }
+            (contents, Kind::Primary) => {
+                log::warn!(
+                    "error writing to clipboard: {:?} is not supported \
+                    in the primary clipboard",
+                    contents.format()
+                );
+            }
+        }
+    }
+
+    /// Hashes the current contents of the system clipboard.
+    fn fingerprint(&self, kind: Kind) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.read(kind).hash(&mut hasher);
+
+        if let (
+            State::Connected {
+                rich: Some(rich), ..
+            },
+            Kind::Standard,
+        ) = (&self.state, kind)
+        {
+            rich.fingerprint(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
     /// Returns the identifier of the window used to create the [`Clipboard`], if any.
     pub fn window_id(&self) -> Option<WindowId> {
         match &self.state {
@@ -101,4 +229,118 @@ impl crate::core::Clipboard for Clipboard {
     fn write(&mut self, kind: Kind, contents: String) {
         self.write(kind, contents);
     }
+
+    fn read_contents(&self, kind: Kind, format: &Format) -> Option<Contents> {
+        self.read_contents(kind, format)
+    }
+
+    fn write_contents(&mut self, kind: Kind, contents: Contents) {
+        self.write_contents(kind, contents);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod rich {
+    use super::{Contents, Format, Image};
+
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use std::hash::{Hash, Hasher};
+
+    /// A clipboard supporting contents other than plain text.
+    pub struct Clipboard {
+        raw: RefCell<arboard::Clipboard>,
+    }
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            arboard::Clipboard::new()
+                .map(|raw| Self {
+                    raw: RefCell::new(raw),
+                })
+                .ok()
+        }
+
+        pub fn read(&self, format: &Format) -> Option<Contents> {
+            let mut raw = self.raw.borrow_mut();
+
+            match format {
+                Format::Html => {
+                    raw.get().html().ok().map(|html| Contents::Html {
+                        html,
+                        alt_text: None,
+                    })
+                }
+                Format::Image => {
+                    let image = raw.get_image().ok()?;
+
+                    Some(Contents::Image(Image {
+                        width: image.width as u32,
+                        height: image.height as u32,
+                        pixels: image.bytes.into_owned().into(),
+                    }))
+                }
+                Format::Files => {
+                    raw.get().file_list().ok().map(Contents::Files)
+                }
+                Format::Text | Format::Custom(_) => None,
+            }
+        }
+
+        pub fn fingerprint(&self, hasher: &mut impl Hasher) {
+            for format in [Format::Html, Format::Files, Format::Image] {
+                match self.read(&format) {
+                    Some(Contents::Html { html, .. }) => html.hash(hasher),
+                    Some(Contents::Files(files)) => files.hash(hasher),
+                    Some(Contents::Image(image)) => image.pixels.hash(hasher),
+                    _ => 0.hash(hasher),
+                }
+            }
+        }
+
+        pub fn write(
+            &mut self,
+            contents: Contents,
+        ) -> Result<(), arboard::Error> {
+            let raw = self.raw.get_mut();
+
+            match contents {
+                Contents::Html { html, alt_text } => {
+                    raw.set_html(html, alt_text)
+                }
+                Contents::Image(image) => raw.set_image(arboard::ImageData {
+                    width: image.width as usize,
+                    height: image.height as usize,
+                    bytes: Cow::Owned(image.pixels.to_vec()),
+                }),
+                Contents::Files(files) => raw.set().file_list(&files),
+                Contents::Text(text) => raw.set_text(text),
+                Contents::Custom { .. } => Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod rich {
+    use super::{Contents, Format};
+
+    /// A clipboard supporting contents other than plain text.
+    pub struct Clipboard;
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            None
+        }
+
+        pub fn read(&self, _format: &Format) -> Option<Contents> {
+            None
+        }
+
+        pub fn fingerprint(&self, _hasher: &mut impl std::hash::Hasher) {}
+
+        pub fn write(&mut self, _contents: Contents) -> Result<(), String> {
+            Ok(())
+        }
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.13/runtime
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
@@ -296,6 +297,19 @@ pub fn window_event(
 
             Some(Event::Window(window::Event::Moved(Point::new(x, y))))
         }
+        WindowEvent::Ime(ime) => Some(Event::InputMethod(match ime {
+            winit::event::Ime::Enabled => input_method::Event::Opened,
+            winit::event::Ime::Preedit(content, cursor) => {
+                input_method::Event::Preedit(
+                    content,
+                    cursor.map(|(start, end)| start..end),
+                )
+            }
+            winit::event::Ime::Commit(content) => {
+                input_method::Event::Commit(content)
+            }
+            winit::event::Ime::Disabled => input_method::Event::Closed,
+        })),
         _ => None,
     }
 }
@@ -418,6 +432,19 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts an [`input_method::Purpose`] to a [`winit`] IME purpose.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_purpose(
+    purpose: input_method::Purpose,
+) -> winit::window::ImePurpose {
+    match purpose {
+        input_method::Purpose::Normal => winit::window::ImePurpose::Normal,
+        input_method::Purpose::Secure => winit::window::ImePurpose::Password,
+        input_method::Purpose::Terminal => winit::window::ImePurpose::Terminal,
+    }
+}
+
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -41,8 +41,9 @@ use std::sync::Arc;
 /// A [`Program`] can execute asynchronous actions by returning a
 /// [`Task`] in some of its methods.
 ///
-/// When using a [`Program`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// When using a [`Program`] with the `debug` feature enabled, the time taken
+/// to view, lay out, and draw every window is recorded in the timeline of
+/// [`runtime::debug`](crate::runtime::debug).
 pub trait Program
 where
     Self: Sized,
@@ -248,6 +249,9 @@ where
         receiver: mpsc::UnboundedReceiver<Control>,
         error: Option<Error>,
 
+        #[cfg(feature = "accessibility")]
+        proxy: Proxy<Message>,
+
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc<std::cell::RefCell<bool>>,
         #[cfg(target_arch = "wasm32")]
@@ -273,6 +277,9 @@ where
         receiver: control_receiver,
         error: None,
 
+        #[cfg(feature = "accessibility")]
+        proxy: proxy.clone(),
+
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc::new(std::cell::RefCell::new(false)),
         #[cfg(target_arch = "wasm32")]
@@ -282,7 +289,7 @@ where
     impl<Message, F, C> winit::application::ApplicationHandler<Action<Message>>
         for Runner<Message, F, C>
     where
-        Message: std::fmt::Debug,
+        Message: std::fmt::Debug + Send,
         F: Future<Output = ()>,
         C: Compositor + 'static,
     {
@@ -466,6 +473,7 @@ where
 
     impl<Message, F, C> Runner<Message, F, C>
     where
+        Message: std::fmt::Debug + Send,
         F: Future<Output = ()>,
         C: Compositor,
     {
@@ -588,6 +596,15 @@ where
                                     };
                                 }
 
+                                #[cfg(feature = "accessibility")]
+                                let accessibility =
+                                    crate::accessibility::Adapter::new(
+                                        event_loop,
+                                        &window,
+                                        id,
+                                        self.proxy.clone(),
+                                    );
+
                                 self.process_event(
                                     event_loop,
                                     Event::WindowCreated {
@@ -596,6 +613,8 @@ where
                                         exit_on_close_request,
                                         make_visible: visible,
                                         on_open,
+                                        #[cfg(feature = "accessibility")]
+                                        accessibility,
                                     },
                                 );
                             }
@@ -645,6 +664,8 @@ enum Event<Message: 'static> {
         exit_on_close_request: bool,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
+        #[cfg(feature = "accessibility")]
+        accessibility: crate::accessibility::Adapter,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -713,6 +734,8 @@ async fn run_instance<P, C>(
                 exit_on_close_request,
                 make_visible,
                 on_open,
+                #[cfg(feature = "accessibility")]
+                accessibility,
             } => {
                 let window = window_manager.insert(
                     id,
@@ -737,6 +760,11 @@ async fn run_instance<P, C>(
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
+                #[cfg(feature = "accessibility")]
+                {
+                    window.accessibility = Some(accessibility);
+                }
+
                 if make_visible {
                     window.raw.set_visible(true);
                 }
@@ -839,7 +867,16 @@ async fn run_instance<P, C>(
                             },
                             cursor,
                         );
-                        debug.draw_finished();
+                        debug.draw_finished(Some(id));
+
+                        #[cfg(feature = "accessibility")]
+                        if let Some(adapter) = &mut window.accessibility {
+                            adapter.update(
+                                ui,
+                                &window.renderer,
+                                window.state.scale_factor(),
+                            );
+                        }
 
                         if new_mouse_interaction != window.mouse_interaction {
                             window.raw.set_cursor(
@@ -857,10 +894,19 @@ async fn run_instance<P, C>(
                             status: core::event::Status::Ignored,
                         });
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = ui_state
+                        {
+                            window.request_input_method(input_method);
+                        }
+
                         let _ = control_sender.start_send(Control::ChangeFlow(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -896,7 +942,7 @@ async fn run_instance<P, C>(
                                 id,
                                 ui.relayout(logical_size, &mut window.renderer),
                             );
-                            debug.layout_finished();
+                            debug.layout_finished(Some(id));
 
                             debug.draw_started();
                             let new_mouse_interaction = user_interfaces
@@ -910,7 +956,7 @@ async fn run_instance<P, C>(
                                     },
                                     window.state.cursor(),
                                 );
-                            debug.draw_finished();
+                            debug.draw_finished(Some(id));
 
                             if new_mouse_interaction != window.mouse_interaction
                             {
@@ -943,7 +989,7 @@ async fn run_instance<P, C>(
                             &debug.overlay(),
                         ) {
                             Ok(()) => {
-                                debug.render_finished();
+                                debug.render_finished(Some(id));
                             }
                             Err(error) => match error {
                                 // This is an unrecoverable error.
@@ -951,7 +997,7 @@ async fn run_instance<P, C>(
                                     panic!("{:?}", error);
                                 }
                                 _ => {
-                                    debug.render_finished();
+                                    debug.render_finished(Some(id));
 
                                     log::error!(
                                         "Error {error:?} when \
@@ -1015,11 +1061,13 @@ async fn run_instance<P, C>(
                                 &mut is_window_opening,
                             );
                         } else {
-                            window.state.update(
-                                &window.raw,
-                                &window_event,
-                                &mut debug,
-                            );
+                            #[cfg(feature = "accessibility")]
+                            if let Some(adapter) = &mut window.accessibility {
+                                adapter
+                                    .process_event(&window.raw, &window_event);
+                            }
+
+                            window.state.update(&window.raw, &window_event);
 
                             if let Some(event) = conversion::window_event(
                                 window_event,
@@ -1163,11 +1211,11 @@ where
 {
     debug.view_started();
     let view = program.view(id);
-    debug.view_finished();
+    debug.view_finished(Some(id));
 
     debug.layout_started();
     let user_interface = UserInterface::build(view, size, cache, renderer);
-    debug.layout_finished();
+    debug.layout_finished(Some(id));
 
     user_interface
 }
@@ -1217,6 +1265,7 @@ fn run_action<P, C>(
     C: Compositor<Renderer = P::Renderer> + 'static,
     P::Theme: DefaultStyle,
 {
+    use crate::runtime::accessibility;
     use crate::runtime::clipboard;
     use crate::runtime::system;
     use crate::runtime::window;
@@ -1232,6 +1281,16 @@ fn run_action<P, C>(
             clipboard::Action::Write { target, contents } => {
                 clipboard.write(target, contents);
             }
+            clipboard::Action::ReadContents {
+                target,
+                format,
+                channel,
+            } => {
+                let _ = channel.send(clipboard.read_contents(target, &format));
+            }
+            clipboard::Action::WriteContents { target, contents } => {
+                clipboard.write_contents(target, contents);
+            }
         },
         Action::Window(action) => match action {
             window::Action::Open(id, settings, channel) => {
@@ -1503,6 +1562,52 @@ fn run_action<P, C>(
                 }
             }
         }
+        Action::Accessibility(action) => match action {
+            accessibility::Action::Activate(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.request_redraw();
+                }
+            }
+            accessibility::Action::Perform(id, request) => {
+                #[cfg(feature = "accessibility")]
+                if let (Some(window), Some(ui)) =
+                    (window_manager.get_mut(id), interfaces.get_mut(&id))
+                {
+                    if request.action == core::accessibility::Action::Focus {
+                        ui.operate(
+                            &window.renderer,
+                            &mut core::accessibility::focus::<()>(
+                                request.target,
+                            ),
+                        );
+
+                        window.raw.request_redraw();
+                        return;
+                    }
+
+                    let Some((position, events)) = window
+                        .accessibility
+                        .as_ref()
+                        .and_then(|adapter| adapter.tree().events(&request))
+                    else {
+                        return;
+                    };
+
+                    let _ = ui.update(
+                        &events,
+                        core::mouse::Cursor::Available(position),
+                        &mut window.renderer,
+                        clipboard,
+                        messages,
+                    );
+
+                    window.raw.request_redraw();
+                }
+
+                #[cfg(not(feature = "accessibility"))]
+                let _ = (id, request);
+            }
+        },
         Action::LoadFont { bytes, channel } => {
             // TODO: Error handling (?)
             compositor.load_font(bytes.clone());
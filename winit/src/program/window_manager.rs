@@ -1,6 +1,7 @@
+use crate::conversion;
 use crate::core::mouse;
 use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::{InputMethod, Point, Size};
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
 
@@ -62,6 +63,9 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                input_method: InputMethod::Disabled,
+                #[cfg(feature = "accessibility")]
+                accessibility: None,
             },
         );
 
@@ -136,6 +140,9 @@ where
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
+    pub input_method: InputMethod,
+    #[cfg(feature = "accessibility")]
+    pub accessibility: Option<crate::accessibility::Adapter>,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
 }
@@ -162,4 +169,33 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    pub fn request_input_method(&mut self, input_method: InputMethod) {
+        if self.input_method == input_method {
+            return;
+        }
+
+        match input_method {
+            InputMethod::Disabled => {
+                self.raw.set_ime_allowed(false);
+            }
+            InputMethod::Enabled { cursor, purpose } => {
+                if !self.input_method.is_enabled() {
+                    self.raw.set_ime_allowed(true);
+                }
+
+                let scale_factor = self.state.scale_factor();
+
+                self.raw.set_ime_purpose(conversion::ime_purpose(purpose));
+                self.raw.set_ime_cursor_area(
+                    winit::dpi::LogicalPosition::new(cursor.x, cursor.y)
+                        .to_physical::<f64>(scale_factor),
+                    winit::dpi::LogicalSize::new(cursor.width, cursor.height)
+                        .to_physical::<f64>(scale_factor),
+                );
+            }
+        }
+
+        self.input_method = input_method;
+    }
 }